@@ -0,0 +1,44 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+/// Position within a rect that another rect is aligned to.
+#[derive(
+  Clone, Copy, Debug, Deserialize, PartialEq, Serialize, ValueEnum,
+)]
+#[clap(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum Anchor {
+  TopLeft,
+  Top,
+  TopRight,
+  Left,
+  Center,
+  Right,
+  BottomLeft,
+  Bottom,
+  BottomRight,
+}
+
+impl Anchor {
+  /// Gets the horizontal alignment as a fraction, where 0.0 is the left
+  /// edge and 1.0 is the right edge.
+  #[must_use]
+  pub fn x_fraction(&self) -> f32 {
+    match self {
+      Self::TopLeft | Self::Left | Self::BottomLeft => 0.0,
+      Self::Top | Self::Center | Self::Bottom => 0.5,
+      Self::TopRight | Self::Right | Self::BottomRight => 1.0,
+    }
+  }
+
+  /// Gets the vertical alignment as a fraction, where 0.0 is the top edge
+  /// and 1.0 is the bottom edge.
+  #[must_use]
+  pub fn y_fraction(&self) -> f32 {
+    match self {
+      Self::TopLeft | Self::Top | Self::TopRight => 0.0,
+      Self::Left | Self::Center | Self::Right => 0.5,
+      Self::BottomLeft | Self::Bottom | Self::BottomRight => 1.0,
+    }
+  }
+}
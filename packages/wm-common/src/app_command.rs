@@ -6,7 +6,8 @@ use tracing::Level;
 use uuid::Uuid;
 
 use crate::{
-  Delta, Direction, LengthValue, OpacityValue, TilingDirection,
+  Anchor, Delta, Direction, LengthValue, OpacityValue, OrdinalDirection,
+  Rect, TilingDirection,
 };
 
 const VERSION: &str = env!("VERSION_NUMBER");
@@ -22,6 +23,16 @@ pub enum AppCommand {
     #[clap(short = 'c', long = "config", value_hint = clap::ValueHint::FilePath)]
     config_path: Option<PathBuf>,
 
+    /// Path to record mutating commands to, e.g. for attaching to bug
+    /// reports.
+    #[clap(long = "command-log", value_hint = clap::ValueHint::FilePath)]
+    command_log_path: Option<PathBuf>,
+
+    /// Path to a command log to restore the last recorded layouts from
+    /// on startup.
+    #[clap(long = "restore-command-log", value_hint = clap::ValueHint::FilePath)]
+    restore_path: Option<PathBuf>,
+
     #[clap(flatten)]
     verbosity: Verbosity,
   },
@@ -43,6 +54,11 @@ pub enum AppCommand {
     #[clap(long = "id")]
     subject_container_id: Option<Uuid>,
 
+    /// Gets the rects that windows would have after running the command,
+    /// without applying it. Only supported for layout commands.
+    #[clap(long, action)]
+    preview: bool,
+
     #[clap(subcommand)]
     command: InvokeCommand,
   },
@@ -76,6 +92,8 @@ impl AppCommand {
     if args.len() == 1 {
       AppCommand::Start {
         config_path: None,
+        command_log_path: None,
+        restore_path: None,
         verbosity: Verbosity {
           verbose: false,
           quiet: false,
@@ -123,6 +141,12 @@ pub enum QueryCommand {
   Focused,
   /// Outputs the tiling direction of the focused container.
   TilingDirection,
+  /// Outputs the computed rect of a container.
+  ContainerRect {
+    /// ID of the container (e.g. as returned by the `windows` query).
+    #[clap(required = true)]
+    container_id: Uuid,
+  },
   /// Outputs all monitors.
   Monitors,
   /// Outputs all windows.
@@ -131,6 +155,9 @@ pub enum QueryCommand {
   Workspaces,
   /// Outputs whether the window manager is paused.
   Paused,
+  /// Outputs the split container selected with `focus --parent` (if
+  /// any).
+  Selection,
 }
 
 #[derive(Clone, Debug, PartialEq, ValueEnum)]
@@ -139,8 +166,10 @@ pub enum SubscribableEvent {
   All,
   ApplicationExiting,
   BindingModesChanged,
+  ContainerRectChanged,
   FocusChanged,
   FocusedContainerMoved,
+  FocusRingChanged,
   MonitorAdded,
   MonitorUpdated,
   MonitorRemoved,
@@ -154,19 +183,106 @@ pub enum SubscribableEvent {
   PauseChanged,
 }
 
+impl SubscribableEvent {
+  /// Whether a subscription to this event includes events of the given
+  /// type. Subscriptions to `All` include every event type.
+  #[must_use]
+  pub fn matches(&self, event_type: &SubscribableEvent) -> bool {
+    *self == SubscribableEvent::All || self == event_type
+  }
+}
+
 #[derive(Clone, Debug, Parser, PartialEq, Serialize)]
 pub enum InvokeCommand {
   AdjustBorders(InvokeAdjustBordersCommand),
+  /// Moves the edges of a floating window, where positive values move an
+  /// edge outwards (e.g. `--right 50px` grows the window to the right).
+  AdjustFloatingRect(InvokeAdjustFloatingRectCommand),
+  AdjustMasterStack {
+    /// Amount to change the master fraction by (e.g. `0.05`).
+    #[clap(long, allow_hyphen_values = true)]
+    master_fraction: Option<f32>,
+
+    /// Amount to change the number of master windows by (e.g. `-1`).
+    #[clap(long, allow_hyphen_values = true)]
+    master_count: Option<i32>,
+  },
+  /// Evenly sizes every container in the workspace, including those in
+  /// nested split containers.
+  BalanceWorkspace,
+  BindToMonitor {
+    /// Position of the monitor to bind the window to, counting from the
+    /// left. The window is unbound if not specified.
+    #[clap(long)]
+    monitor: Option<usize>,
+  },
   Close,
+  CycleSnap {
+    #[clap(long)]
+    direction: Direction,
+  },
+  EqualizeSizes {
+    /// Whether to also equalize the children of nested split containers.
+    #[clap(long, action)]
+    recursive: bool,
+  },
+  /// Gradually changes the opacity of the window to the given value.
+  FadeTransparency(FadeTransparencyCommand),
   Focus(InvokeFocusCommand),
+  /// Cycles focus through the windows of the workspace, in the order
+  /// they were most recently focused.
+  FocusMru,
+  /// Moves every window from the other workspaces on the monitor into
+  /// the focused workspace. Sticky windows are left in place.
+  GatherWindows,
+  GrowToFill,
   Ignore,
   Move(InvokeMoveCommand),
+  /// Moves the focused tiling window into the slot of the target window,
+  /// forming a new split container with the window placed on the given
+  /// side of the target.
+  MoveInto {
+    /// ID of the tiling window to share a slot with.
+    target_id: Uuid,
+
+    direction: Direction,
+  },
   MoveWorkspace {
     #[clap(long)]
     direction: Direction,
   },
+  /// Moves the window to the displayed workspace of the monitor at the
+  /// given position. Monitors are ordered from left-to-right, regardless
+  /// of the order that the OS enumerates them in.
+  MoveToMonitor {
+    #[clap(long)]
+    monitor: usize,
+  },
+  /// Moves the workspace to the monitor at the given position, counting
+  /// from the left.
+  MoveWorkspaceToMonitor {
+    #[clap(long)]
+    monitor: usize,
+  },
   Position(InvokePositionCommand),
   Resize(InvokeResizeCommand),
+  /// Resizes a tiling window from one of its edges, such that only the
+  /// neighbor across that edge is resized.
+  ResizeEdge {
+    #[clap(long)]
+    direction: Direction,
+
+    #[clap(long, allow_hyphen_values = true)]
+    amount: LengthValue,
+  },
+  RestoreWorkspaceLayout {
+    #[clap(long)]
+    path: PathBuf,
+  },
+  SaveWorkspaceLayout {
+    #[clap(long)]
+    path: PathBuf,
+  },
   SetFloating {
     #[clap(long, default_missing_value = "true", require_equals = true, num_args = 0..=1)]
     shown_on_top: Option<bool>,
@@ -174,6 +290,12 @@ pub enum InvokeCommand {
     #[clap(long, default_missing_value = "true", require_equals = true, num_args = 0..=1)]
     centered: Option<bool>,
 
+    #[clap(long, default_missing_value = "true", require_equals = true, num_args = 0..=1)]
+    sticky: Option<bool>,
+
+    #[clap(long, default_missing_value = "true", require_equals = true, num_args = 0..=1)]
+    preserve_geometry: Option<bool>,
+
     #[clap(long, allow_hyphen_values = true)]
     x_pos: Option<i32>,
 
@@ -194,12 +316,38 @@ pub enum InvokeCommand {
     maximized: Option<bool>,
   },
   SetMinimized,
+  SetStruts {
+    /// Position of the monitor to reserve edges on, counting from the
+    /// left. Defaults to the monitor of the subject container.
+    #[clap(long)]
+    monitor: Option<usize>,
+
+    /// Reserved edges in pixels. Edges that aren't specified keep their
+    /// current value.
+    #[clap(long)]
+    left: Option<i32>,
+
+    #[clap(long)]
+    top: Option<i32>,
+
+    #[clap(long)]
+    right: Option<i32>,
+
+    #[clap(long)]
+    bottom: Option<i32>,
+  },
   SetTiling,
   SetTitleBarVisibility {
     #[clap(required = true, value_enum)]
     visibility: TitleBarVisibility,
   },
   SetTransparency(SetTransparencyCommand),
+  /// Floats a window and moves it to the given rect, in the format
+  /// `x,y,width,height`. The rect is clamped within the window's monitor.
+  SetWindowRect {
+    #[clap(allow_hyphen_values = true)]
+    rect: Rect,
+  },
   ShellExec {
     #[clap(long, action)]
     hide_window: bool,
@@ -209,12 +357,23 @@ pub enum InvokeCommand {
   },
   // Reuse `InvokeResizeCommand` struct.
   Size(InvokeResizeCommand),
+  SwapWindows {
+    /// ID of the tiling window to swap places with.
+    #[clap(long)]
+    target_id: Uuid,
+  },
   ToggleFloating {
     #[clap(long, default_missing_value = "true", require_equals = true, num_args = 0..=1)]
     shown_on_top: Option<bool>,
 
     #[clap(long, default_missing_value = "true", require_equals = true, num_args = 0..=1)]
     centered: Option<bool>,
+
+    #[clap(long, default_missing_value = "true", require_equals = true, num_args = 0..=1)]
+    sticky: Option<bool>,
+
+    #[clap(long, default_missing_value = "true", require_equals = true, num_args = 0..=1)]
+    preserve_geometry: Option<bool>,
   },
   ToggleFullscreen {
     #[clap(long, default_missing_value = "true", require_equals = true, num_args = 0..=1)]
@@ -224,12 +383,27 @@ pub enum InvokeCommand {
     maximized: Option<bool>,
   },
   ToggleMinimized,
+  /// Toggles whether the focused window's parent shows its children as
+  /// tabs, where only the active tab is visible.
+  ToggleTabbed,
   ToggleTiling,
   ToggleTilingDirection,
+  /// Flips the tiling direction of the focused container's parent, while
+  /// preserving the sizes of its children.
+  #[clap(alias = "toggle-direction")]
+  ToggleParentDirection,
   SetTilingDirection {
     #[clap(required = true)]
     tiling_direction: TilingDirection,
   },
+  /// Checks the focused workspace for tiling windows that overlap or
+  /// extend beyond the workspace, and logs any issues found.
+  ValidateLayout {
+    /// Whether to normalize tiling sizes and redraw the workspace if any
+    /// issues are found.
+    #[clap(long, action)]
+    repair: bool,
+  },
   WmCycleFocus {
     #[clap(long, default_value_t = false)]
     omit_floating: bool,
@@ -257,6 +431,59 @@ pub enum InvokeCommand {
   WmTogglePause,
 }
 
+impl InvokeCommand {
+  /// Whether the command only changes the layout of the container tree,
+  /// such that it can be run with the `--preview` flag.
+  #[must_use]
+  pub fn is_previewable(&self) -> bool {
+    matches!(
+      self,
+      InvokeCommand::AdjustMasterStack { .. }
+        | InvokeCommand::BalanceWorkspace
+        | InvokeCommand::EqualizeSizes { .. }
+        | InvokeCommand::GrowToFill
+        | InvokeCommand::Move(_)
+        | InvokeCommand::MoveInto { .. }
+        | InvokeCommand::MoveToMonitor { .. }
+        | InvokeCommand::MoveWorkspace { .. }
+        | InvokeCommand::MoveWorkspaceToMonitor { .. }
+        | InvokeCommand::Position(_)
+        | InvokeCommand::Resize(_)
+        | InvokeCommand::ResizeEdge { .. }
+        | InvokeCommand::Size(_)
+        | InvokeCommand::SwapWindows { .. }
+        | InvokeCommand::SetTilingDirection { .. }
+        | InvokeCommand::SetWindowRect { .. }
+        | InvokeCommand::ToggleTabbed
+        | InvokeCommand::ToggleTilingDirection
+        | InvokeCommand::ToggleParentDirection
+    )
+  }
+
+  /// Whether the command can change the layout of windows. Mutating
+  /// commands are recorded in the command log.
+  #[must_use]
+  pub fn is_mutating(&self) -> bool {
+    !matches!(
+      self,
+      InvokeCommand::FadeTransparency(_)
+        | InvokeCommand::Focus(_)
+        | InvokeCommand::FocusMru
+        | InvokeCommand::SaveWorkspaceLayout { .. }
+        | InvokeCommand::SetTitleBarVisibility { .. }
+        | InvokeCommand::SetTransparency(_)
+        | InvokeCommand::ShellExec { .. }
+        | InvokeCommand::ValidateLayout { repair: false }
+        | InvokeCommand::WmCycleFocus { .. }
+        | InvokeCommand::WmDisableBindingMode { .. }
+        | InvokeCommand::WmEnableBindingMode { .. }
+        | InvokeCommand::WmExit
+        | InvokeCommand::WmRedraw
+        | InvokeCommand::WmTogglePause
+    )
+  }
+}
+
 impl<'de> Deserialize<'de> for InvokeCommand {
   fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
   where
@@ -300,6 +527,26 @@ pub struct InvokeAdjustBordersCommand {
   pub left: Option<LengthValue>,
 }
 
+#[derive(Args, Clone, Debug, PartialEq, Serialize)]
+#[group(required = true, multiple = true)]
+pub struct InvokeAdjustFloatingRectCommand {
+  /// Amount to move the top edge upwards by.
+  #[clap(long, allow_hyphen_values = true)]
+  pub top: Option<LengthValue>,
+
+  /// Amount to move the right edge to the right by.
+  #[clap(long, allow_hyphen_values = true)]
+  pub right: Option<LengthValue>,
+
+  /// Amount to move the bottom edge downwards by.
+  #[clap(long, allow_hyphen_values = true)]
+  pub bottom: Option<LengthValue>,
+
+  /// Amount to move the left edge to the left by.
+  #[clap(long, allow_hyphen_values = true)]
+  pub left: Option<LengthValue>,
+}
+
 #[derive(Args, Clone, Debug, PartialEq, Serialize)]
 #[group(required = true, multiple = false)]
 #[allow(clippy::struct_excessive_bools)]
@@ -339,6 +586,22 @@ pub struct InvokeFocusCommand {
 
   #[clap(long)]
   pub recent_workspace: bool,
+
+  #[clap(long)]
+  pub next_tab: bool,
+
+  #[clap(long)]
+  pub prev_tab: bool,
+
+  /// Selects the parent split container of the focused window (or of the
+  /// current selection), such that resize commands apply to it.
+  #[clap(long)]
+  pub parent: bool,
+
+  /// Selects the next child down from the current selection towards the
+  /// focused window.
+  #[clap(long)]
+  pub child: bool,
 }
 
 #[derive(Args, Clone, Debug, PartialEq, Serialize)]
@@ -399,6 +662,17 @@ pub struct SetTransparencyCommand {
   pub opacity_delta: Option<Delta<OpacityValue>>,
 }
 
+#[derive(Args, Clone, Debug, PartialEq, Serialize)]
+pub struct FadeTransparencyCommand {
+  /// Opacity to fade the window to.
+  #[clap(long)]
+  pub opacity: OpacityValue,
+
+  /// Duration of the fade in milliseconds.
+  #[clap(long, default_value_t = 200)]
+  pub duration: u64,
+}
+
 #[derive(Args, Clone, Debug, PartialEq, Serialize)]
 #[group(required = true, multiple = true)]
 pub struct InvokePositionCommand {
@@ -410,4 +684,147 @@ pub struct InvokePositionCommand {
 
   #[clap(long, allow_hyphen_values = true)]
   pub y_pos: Option<i32>,
+
+  /// Snaps the window to a half or quarter of the workspace (e.g.
+  /// `top_left` or `right`).
+  #[clap(long, value_enum)]
+  pub quadrant: Option<Anchor>,
+
+  /// Moves the window into a corner of the workspace (e.g. `up_left`),
+  /// keeping its size.
+  #[clap(long, value_enum)]
+  pub corner: Option<OrdinalDirection>,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::WmEvent;
+
+  #[test]
+  fn test_sub_filters_by_event_type() {
+    let AppCommand::Sub { events } = AppCommand::try_parse_from([
+      "",
+      "sub",
+      "--events",
+      "workspace_deactivated",
+    ])
+    .unwrap() else {
+      unreachable!();
+    };
+
+    let is_delivered = |event: &WmEvent| {
+      events
+        .iter()
+        .any(|subscribed| subscribed.matches(&event.event_type()))
+    };
+
+    assert!(is_delivered(&WmEvent::WorkspaceDeactivated {
+      deactivated_id: Uuid::nil(),
+      deactivated_name: "1".to_string(),
+    }));
+    assert!(!is_delivered(&WmEvent::PauseChanged { is_paused: true }));
+    assert!(!is_delivered(&WmEvent::ApplicationExiting));
+
+    // Subscribing to `all` includes every event type.
+    assert!(
+      SubscribableEvent::All.matches(&SubscribableEvent::PauseChanged)
+    );
+
+    // Unknown event names are rejected.
+    let err =
+      AppCommand::try_parse_from(["", "sub", "--events", "window_closed"])
+        .unwrap_err();
+
+    assert_eq!(err.kind(), clap::error::ErrorKind::InvalidValue);
+  }
+
+  #[test]
+  fn test_parse_set_window_rect() {
+    let command = InvokeCommand::try_parse_from([
+      "",
+      "set-window-rect",
+      "-100,50,800,600",
+    ])
+    .unwrap();
+
+    assert_eq!(
+      command,
+      InvokeCommand::SetWindowRect {
+        rect: Rect::from_xy(-100, 50, 800, 600),
+      }
+    );
+
+    // The applied rect is clamped within the window's monitor.
+    let InvokeCommand::SetWindowRect { rect } = command else {
+      unreachable!();
+    };
+
+    assert_eq!(
+      rect.clamp_within_bounds(&Rect::from_xy(0, 0, 1920, 1080)),
+      Rect::from_xy(0, 50, 800, 600)
+    );
+
+    assert!(InvokeCommand::try_parse_from([
+      "",
+      "set-window-rect",
+      "10,20"
+    ])
+    .is_err());
+  }
+
+  #[test]
+  fn test_parse_toggle_floating_preserve_geometry() {
+    let parse = |args: &[&str]| {
+      InvokeCommand::try_parse_from(
+        ["", "toggle-floating"].iter().chain(args),
+      )
+      .unwrap()
+    };
+
+    assert!(matches!(
+      parse(&["--preserve-geometry"]),
+      InvokeCommand::ToggleFloating {
+        preserve_geometry: Some(true),
+        ..
+      }
+    ));
+
+    assert!(matches!(
+      parse(&["--preserve-geometry=false"]),
+      InvokeCommand::ToggleFloating {
+        preserve_geometry: Some(false),
+        ..
+      }
+    ));
+
+    // Falls back to the floating state defaults when omitted.
+    assert!(matches!(
+      parse(&[]),
+      InvokeCommand::ToggleFloating {
+        preserve_geometry: None,
+        ..
+      }
+    ));
+  }
+
+  #[test]
+  fn test_parse_move_into() {
+    let target_id = Uuid::new_v4();
+    let command = InvokeCommand::try_parse_from([
+      "",
+      "move-into",
+      &target_id.to_string(),
+      "down",
+    ])
+    .unwrap();
+
+    assert_eq!(
+      command,
+      InvokeCommand::MoveInto {
+        target_id,
+        direction: Direction::Down,
+      }
+    );
+  }
 }
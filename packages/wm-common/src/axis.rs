@@ -0,0 +1,9 @@
+use serde::Serialize;
+
+/// Axis along which a direction points.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Axis {
+  Horizontal,
+  Vertical,
+}
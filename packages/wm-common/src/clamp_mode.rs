@@ -0,0 +1,14 @@
+/// How a rect is constrained within outer bounds (see
+/// `Rect::clamp_within_bounds_with_mode`).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ClampMode {
+  /// Rects larger than the bounds are shrunk to fit, after which they're
+  /// repositioned to lie entirely within the bounds.
+  #[default]
+  ResizeToFit,
+
+  /// Rects are only repositioned and are never resized. Rects larger than
+  /// the bounds are aligned to the top-left corner and hang off the
+  /// bottom and right edges.
+  RepositionOnly,
+}
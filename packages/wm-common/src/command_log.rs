@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+
+use crate::WorkspaceLayout;
+
+/// Entry in a command log, which is recorded each time mutating commands
+/// are run.
+///
+/// The layouts recorded in a command log can be restored with
+/// `latest_layouts` to reproduce the layout that the commands resulted
+/// in. The commands themselves are only recorded for reference.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandLogEntry {
+  /// Time that the command was run, in milliseconds since the Unix
+  /// epoch.
+  pub timestamp: u64,
+
+  /// The commands that were run, formatted with `Debug`. Only used for
+  /// reference when reading the log.
+  pub commands: Vec<String>,
+
+  /// Layouts of the displayed workspaces after the command was run.
+  ///
+  /// Window nodes include the rect of the window, so the layout can be
+  /// reconstructed regardless of the gaps and monitors on replay.
+  pub layouts: Vec<WorkspaceLayout>,
+}
+
+/// Gets the last recorded layout of each workspace in a command log.
+///
+/// Entries are ordered by their timestamps, where each layout replaces
+/// the previous layout of the same workspace. Workspaces are returned in
+/// the order that they first appear in the log.
+#[must_use]
+pub fn latest_layouts(
+  entries: &[CommandLogEntry],
+) -> Vec<WorkspaceLayout> {
+  let mut sorted_entries = entries.iter().collect::<Vec<_>>();
+  sorted_entries.sort_by_key(|entry| entry.timestamp);
+
+  let mut layouts = Vec::<WorkspaceLayout>::new();
+
+  for layout in sorted_entries.into_iter().flat_map(|entry| &entry.layouts)
+  {
+    match layouts
+      .iter_mut()
+      .find(|existing| existing.workspace_name == layout.workspace_name)
+    {
+      Some(existing) => *existing = layout.clone(),
+      None => layouts.push(layout.clone()),
+    }
+  }
+
+  layouts
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::TilingDirection;
+
+  fn entry(
+    timestamp: u64,
+    workspace_names: &[&str],
+    tiling_direction: &TilingDirection,
+  ) -> CommandLogEntry {
+    CommandLogEntry {
+      timestamp,
+      commands: vec!["ToggleTilingDirection".to_string()],
+      layouts: workspace_names
+        .iter()
+        .map(|workspace_name| WorkspaceLayout {
+          workspace_name: (*workspace_name).to_string(),
+          tiling_direction: tiling_direction.clone(),
+          children: Vec::new(),
+        })
+        .collect(),
+    }
+  }
+
+  #[test]
+  fn test_latest_layouts_ordered_by_timestamp() {
+    let entries = vec![
+      entry(100, &["1"], &TilingDirection::Horizontal),
+      entry(300, &["1", "2"], &TilingDirection::Vertical),
+      entry(200, &["2"], &TilingDirection::Horizontal),
+    ];
+
+    let layouts = latest_layouts(&entries)
+      .into_iter()
+      .map(|layout| (layout.workspace_name, layout.tiling_direction))
+      .collect::<Vec<_>>();
+
+    assert_eq!(
+      layouts,
+      vec![
+        ("1".to_string(), TilingDirection::Vertical),
+        ("2".to_string(), TilingDirection::Vertical),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_latest_layouts_empty_log() {
+    assert!(latest_layouts(&[]).is_empty());
+  }
+}
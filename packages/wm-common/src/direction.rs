@@ -3,6 +3,8 @@ use std::str::FromStr;
 use anyhow::bail;
 use serde::Serialize;
 
+use super::Axis;
+
 #[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Direction {
@@ -30,6 +32,35 @@ impl Direction {
       Direction::Down => Direction::Up,
     }
   }
+
+  /// Gets the opposite of a given direction. Alias for `inverse`.
+  ///
+  /// Example:
+  /// ```
+  /// # use wm_common::Direction;
+  /// let dir = Direction::Up.opposite();
+  /// assert_eq!(dir, Direction::Down);
+  /// ```
+  #[must_use]
+  pub fn opposite(&self) -> Direction {
+    self.inverse()
+  }
+
+  /// Gets the axis that a given direction points along.
+  ///
+  /// Example:
+  /// ```
+  /// # use wm_common::{Axis, Direction};
+  /// let axis = Direction::Left.axis();
+  /// assert_eq!(axis, Axis::Horizontal);
+  /// ```
+  #[must_use]
+  pub fn axis(&self) -> Axis {
+    match self {
+      Direction::Left | Direction::Right => Axis::Horizontal,
+      Direction::Up | Direction::Down => Axis::Vertical,
+    }
+  }
 }
 
 impl FromStr for Direction {
@@ -54,3 +85,24 @@ impl FromStr for Direction {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_opposite() {
+    assert_eq!(Direction::Left.opposite(), Direction::Right);
+    assert_eq!(Direction::Right.opposite(), Direction::Left);
+    assert_eq!(Direction::Up.opposite(), Direction::Down);
+    assert_eq!(Direction::Down.opposite(), Direction::Up);
+  }
+
+  #[test]
+  fn test_axis() {
+    assert_eq!(Direction::Left.axis(), Axis::Horizontal);
+    assert_eq!(Direction::Right.axis(), Axis::Horizontal);
+    assert_eq!(Direction::Up.axis(), Axis::Vertical);
+    assert_eq!(Direction::Down.axis(), Axis::Vertical);
+  }
+}
@@ -0,0 +1,12 @@
+/// Region of a rect that a dragged window is dropped over (see
+/// `Rect::drop_region`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DropRegion {
+  Left,
+  Right,
+  Top,
+  Bottom,
+
+  /// Not within the band along any of the edges.
+  Center,
+}
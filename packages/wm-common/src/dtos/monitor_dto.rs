@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use super::ContainerDto;
-use crate::Rect;
+use crate::{Rect, Struts};
 
 /// User-friendly representation of a monitor.
 ///
@@ -26,4 +26,5 @@ pub struct MonitorDto {
   pub device_path: Option<String>,
   pub hardware_id: Option<String>,
   pub working_rect: Rect,
+  pub struts: Struts,
 }
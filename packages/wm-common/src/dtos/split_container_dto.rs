@@ -21,4 +21,5 @@ pub struct SplitContainerDto {
   pub x: i32,
   pub y: i32,
   pub tiling_direction: TilingDirection,
+  pub is_tabbed: bool,
 }
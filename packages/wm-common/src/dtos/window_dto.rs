@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::{ActiveDrag, DisplayState, Rect, RectDelta, WindowState};
+use crate::{
+  ActiveDrag, DisplayState, OpacityValue, Rect, RectDelta, WindowState,
+};
 
 /// User-friendly representation of a tiling or non-tiling window.
 ///
@@ -27,4 +29,5 @@ pub struct WindowDto {
   pub class_name: String,
   pub process_name: String,
   pub active_drag: Option<ActiveDrag>,
+  pub opacity: Option<OpacityValue>,
 }
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+/// Easing curve for animated transitions.
+#[derive(
+  Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Easing {
+  #[default]
+  Linear,
+  EaseInCubic,
+  EaseOutCubic,
+  EaseInOutCubic,
+}
+
+impl Easing {
+  /// Samples the easing curve at the given progress. `t` is clamped to
+  /// the range 0.0 to 1.0.
+  ///
+  /// Example:
+  /// ```
+  /// # use wm_common::Easing;
+  /// assert_eq!(Easing::EaseOutCubic.sample(1.0), 1.0);
+  /// ```
+  #[must_use]
+  pub fn sample(&self, t: f32) -> f32 {
+    let t = t.clamp(0.0, 1.0);
+
+    match self {
+      Self::Linear => t,
+      Self::EaseInCubic => t.powi(3),
+      Self::EaseOutCubic => 1.0 - (1.0 - t).powi(3),
+      Self::EaseInOutCubic => {
+        if t < 0.5 {
+          4.0 * t.powi(3)
+        } else {
+          1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  #[allow(clippy::float_cmp)]
+  fn test_sample_endpoints() {
+    for easing in [
+      Easing::Linear,
+      Easing::EaseInCubic,
+      Easing::EaseOutCubic,
+      Easing::EaseInOutCubic,
+    ] {
+      assert_eq!(easing.sample(0.0), 0.0);
+      assert_eq!(easing.sample(1.0), 1.0);
+      assert_eq!(easing.sample(-1.0), 0.0);
+      assert_eq!(easing.sample(2.0), 1.0);
+    }
+  }
+
+  #[test]
+  #[allow(clippy::float_cmp)]
+  fn test_sample_midpoint() {
+    assert_eq!(Easing::Linear.sample(0.5), 0.5);
+    assert_eq!(Easing::EaseInCubic.sample(0.5), 0.125);
+    assert_eq!(Easing::EaseOutCubic.sample(0.5), 0.875);
+    assert_eq!(Easing::EaseInOutCubic.sample(0.5), 0.5);
+  }
+}
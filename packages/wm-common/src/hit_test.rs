@@ -0,0 +1,94 @@
+use crate::{Point, Rect};
+
+/// Finds the deepest node in a tree whose rect contains the given point.
+///
+/// Siblings are expected in z-order (topmost first), such that
+/// overlapping siblings resolve to the one shown on top. Nodes without a
+/// rect are skipped.
+///
+/// Returns the containing node itself if none of its children contain
+/// the point (e.g. when the point lies within a gap between windows).
+pub fn hit_test<T, I, R, C>(
+  nodes: I,
+  point: &Point,
+  rect_of: &R,
+  children_of: &C,
+) -> Option<T>
+where
+  I: IntoIterator<Item = T>,
+  R: Fn(&T) -> Option<Rect>,
+  C: Fn(&T) -> Vec<T>,
+{
+  let node = nodes.into_iter().find(|node| {
+    rect_of(node).is_some_and(|rect| rect.contains_point(point))
+  })?;
+
+  hit_test(children_of(&node), point, rect_of, children_of).or(Some(node))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  struct Node {
+    id: u32,
+    rect: Rect,
+    children: Vec<Node>,
+  }
+
+  fn leaf(id: u32, rect: Rect) -> Node {
+    Node {
+      id,
+      rect,
+      children: Vec::new(),
+    }
+  }
+
+  fn hit(root: &Node, x: i32, y: i32) -> Option<u32> {
+    hit_test(
+      [root],
+      &Point { x, y },
+      &|node: &&Node| Some(node.rect.clone()),
+      &|node: &&Node| node.children.iter().collect(),
+    )
+    .map(|node| node.id)
+  }
+
+  #[test]
+  fn test_hit_test_tree() {
+    // Workspace with the layout H[1 V[2 3]], plus a floating window 4
+    // that overlaps windows 1 and 2. Floating windows come first, since
+    // they're shown above tiling windows.
+    let workspace = Node {
+      id: 0,
+      rect: Rect::from_xy(0, 0, 1000, 800),
+      children: vec![
+        leaf(4, Rect::from_xy(400, 300, 200, 200)),
+        leaf(1, Rect::from_xy(0, 0, 495, 800)),
+        Node {
+          id: 10,
+          rect: Rect::from_xy(505, 0, 495, 800),
+          children: vec![
+            leaf(2, Rect::from_xy(505, 0, 495, 395)),
+            leaf(3, Rect::from_xy(505, 405, 495, 395)),
+          ],
+        },
+      ],
+    };
+
+    assert_eq!(hit(&workspace, 100, 100), Some(1));
+    assert_eq!(hit(&workspace, 700, 100), Some(2));
+    assert_eq!(hit(&workspace, 700, 700), Some(3));
+
+    // Floating window is on top of the tiling windows it overlaps.
+    assert_eq!(hit(&workspace, 450, 350), Some(4));
+    assert_eq!(hit(&workspace, 550, 350), Some(4));
+
+    // Gap between windows 2 and 3 resolves to their split container.
+    assert_eq!(hit(&workspace, 700, 400), Some(10));
+
+    // Right and bottom edges are exclusive.
+    assert_eq!(hit(&workspace, 495, 100), Some(0));
+    assert_eq!(hit(&workspace, 1000, 100), None);
+  }
+}
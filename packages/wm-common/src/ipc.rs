@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::{BindingModeConfig, ContainerDto, TilingDirection, WmEvent};
+use crate::{
+  BindingModeConfig, ContainerDto, Rect, TilingDirection, WmEvent,
+};
 
 pub const DEFAULT_IPC_PORT: u32 = 6123;
 
@@ -26,6 +28,10 @@ pub struct ClientResponseMessage {
 pub enum ClientResponseData {
   AppMetadata(AppMetadataData),
   BindingModes(BindingModesData),
+  ContainerRect(ContainerRectData),
+  // Needs to precede `Command`, since `CommandData` is a subset of
+  // `CommandPreviewData`.
+  CommandPreview(CommandPreviewData),
   Command(CommandData),
   EventSubscribe(EventSubscribeData),
   EventUnsubscribe,
@@ -35,6 +41,9 @@ pub enum ClientResponseData {
   Windows(WindowsData),
   Workspaces(WorkspacesData),
   Paused(bool),
+  // Needs to be last, since `SelectionData` has no required fields and
+  // would otherwise match when deserializing.
+  Selection(SelectionData),
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -55,6 +64,42 @@ pub struct CommandData {
   pub subject_container_id: Uuid,
 }
 
+/// Rects that windows would have after running a command with the
+/// `--preview` flag. The command itself isn't applied.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommandPreviewData {
+  pub subject_container_id: Uuid,
+  pub changed_rects: Vec<ContainerRectData>,
+}
+
+impl CommandPreviewData {
+  /// Creates the preview from the rects of windows before and after
+  /// running the command. Only rects that differ from the live layout
+  /// (or belong to windows absent from it) are kept.
+  #[must_use]
+  pub fn new(
+    subject_container_id: Uuid,
+    live_rects: &[ContainerRectData],
+    preview_rects: Vec<ContainerRectData>,
+  ) -> Self {
+    let changed_rects = preview_rects
+      .into_iter()
+      .filter(|preview| {
+        !live_rects.iter().any(|live| {
+          live.container_id == preview.container_id
+            && live.rect == preview.rect
+        })
+      })
+      .collect();
+
+    Self {
+      subject_container_id,
+      changed_rects,
+    }
+  }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EventSubscribeData {
@@ -73,6 +118,21 @@ pub struct MonitorsData {
   pub monitors: Vec<ContainerDto>,
 }
 
+/// Computed rect of a container. Serialized as:
+///
+/// ```json
+/// {
+///   "containerId": "<uuid>",
+///   "rect": { "left": 0, "top": 0, "right": 1920, "bottom": 1080 }
+/// }
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContainerRectData {
+  pub container_id: Uuid,
+  pub rect: Rect,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TilingDirectionData {
@@ -92,6 +152,14 @@ pub struct WorkspacesData {
   pub workspaces: Vec<ContainerDto>,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectionData {
+  /// Split container selected with `focus --parent`. `None` if no
+  /// container is selected.
+  pub selected: Option<ContainerDto>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EventSubscriptionMessage {
@@ -100,3 +168,82 @@ pub struct EventSubscriptionMessage {
   pub subscription_id: Uuid,
   pub success: bool,
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_container_rect_response_shape() {
+    let data = ClientResponseData::ContainerRect(ContainerRectData {
+      container_id: Uuid::nil(),
+      rect: Rect::from_xy(10, 20, 300, 400),
+    });
+
+    let json = serde_json::to_value(&data).unwrap();
+    assert_eq!(
+      json,
+      serde_json::json!({
+        "containerId": "00000000-0000-0000-0000-000000000000",
+        "rect": { "left": 10, "top": 20, "right": 310, "bottom": 420 },
+      })
+    );
+
+    // Untagged deserialization resolves back to the same variant.
+    let parsed: ClientResponseData = serde_json::from_value(json).unwrap();
+    assert!(matches!(
+      parsed,
+      ClientResponseData::ContainerRect(ContainerRectData { rect, .. })
+        if rect == Rect::from_xy(10, 20, 300, 400)
+    ));
+  }
+
+  #[test]
+  fn test_command_preview_keeps_changed_rects() {
+    let (id_a, id_b) = (Uuid::new_v4(), Uuid::new_v4());
+
+    let rect_data =
+      |container_id, rect| ContainerRectData { container_id, rect };
+
+    let live_rects = vec![
+      rect_data(id_a, Rect::from_xy(0, 0, 500, 800)),
+      rect_data(id_b, Rect::from_xy(500, 0, 500, 800)),
+    ];
+
+    // Resizing window A shrinks it, while B grows to fill the space.
+    let preview = CommandPreviewData::new(
+      id_a,
+      &live_rects,
+      vec![
+        rect_data(id_a, Rect::from_xy(0, 0, 400, 800)),
+        rect_data(id_b, Rect::from_xy(400, 0, 600, 800)),
+      ],
+    );
+
+    assert_eq!(preview.changed_rects.len(), 2);
+    assert_eq!(
+      preview.changed_rects[0].rect,
+      Rect::from_xy(0, 0, 400, 800)
+    );
+
+    // A no-op command has no changed rects.
+    let preview =
+      CommandPreviewData::new(id_a, &live_rects, live_rects.clone());
+    assert!(preview.changed_rects.is_empty());
+
+    // Response is distinguishable from a plain command response.
+    let json = serde_json::to_value(ClientResponseData::CommandPreview(
+      CommandPreviewData::new(id_a, &[], live_rects),
+    ))
+    .unwrap();
+
+    let parsed: ClientResponseData = serde_json::from_value(json).unwrap();
+    assert!(matches!(
+      parsed,
+      ClientResponseData::CommandPreview(CommandPreviewData {
+        changed_rects,
+        ..
+      }) if changed_rects.len() == 2
+    ));
+  }
+}
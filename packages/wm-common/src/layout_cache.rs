@@ -0,0 +1,114 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use uuid::Uuid;
+
+/// Cache of values computed for containers (e.g. the rects of their
+/// children), keyed by container ID.
+///
+/// Cached values aren't tracked against the container tree, so the
+/// cache has to be invalidated whenever the tree is changed.
+#[derive(Debug, Default)]
+pub struct LayoutCache<T> {
+  entries: RefCell<HashMap<Uuid, T>>,
+}
+
+impl<T: Clone> LayoutCache<T> {
+  /// Gets the cached value for the given ID, or computes and caches it
+  /// if there is none.
+  ///
+  /// The cache isn't borrowed while computing, such that `compute` can
+  /// recursively get values for other IDs (e.g. a container's parent).
+  pub fn get_or_try_compute<E>(
+    &self,
+    id: Uuid,
+    compute: impl FnOnce() -> Result<T, E>,
+  ) -> Result<T, E> {
+    let cached = self.entries.borrow().get(&id).cloned();
+
+    if let Some(value) = cached {
+      return Ok(value);
+    }
+
+    let value = compute()?;
+    self.entries.borrow_mut().insert(id, value.clone());
+
+    Ok(value)
+  }
+
+  /// Discards all cached values.
+  pub fn invalidate(&mut self) {
+    self.entries.get_mut().clear();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::cell::Cell;
+
+  use super::*;
+  use crate::{Rect, TilingDirection};
+
+  /// Deeply nested layout, where each node splits its parent's rect in
+  /// half and is the first child of the next node.
+  struct NestedLayout {
+    ids: Vec<Uuid>,
+    cache: LayoutCache<Vec<Rect>>,
+    compute_count: Cell<usize>,
+  }
+
+  impl NestedLayout {
+    fn new(depth: usize) -> Self {
+      Self {
+        ids: (0..depth).map(|_| Uuid::new_v4()).collect(),
+        cache: LayoutCache::default(),
+        compute_count: Cell::new(0),
+      }
+    }
+
+    /// Gets the rects of the children of the node at the given depth.
+    fn layout_rects(&self, depth: usize) -> Result<Vec<Rect>, ()> {
+      self.cache.get_or_try_compute(self.ids[depth], || {
+        self.compute_count.set(self.compute_count.get() + 1);
+
+        let rect = match depth {
+          0 => Rect::from_xy(0, 0, 1 << 20, 1 << 20),
+          _ => self.layout_rects(depth - 1)?[0].clone(),
+        };
+
+        let tiling_direction = if depth.is_multiple_of(2) {
+          TilingDirection::Horizontal
+        } else {
+          TilingDirection::Vertical
+        };
+
+        Ok(rect.split_weighted(&[0.5, 0.5], &tiling_direction, 0))
+      })
+    }
+
+    /// Gets the rect of every node, similar to redrawing all containers.
+    fn all_rects(&self) -> Vec<Rect> {
+      (0..self.ids.len())
+        .map(|depth| self.layout_rects(depth).unwrap()[1].clone())
+        .collect()
+    }
+  }
+
+  #[test]
+  fn test_nested_layout_computed_once_per_pass() {
+    let mut layout = NestedLayout::new(16);
+
+    // Each node is computed once, even though computing a node requires
+    // the rects of all of its ancestors.
+    let rects = layout.all_rects();
+    assert_eq!(layout.compute_count.get(), 16);
+
+    layout.compute_count.set(0);
+    assert_eq!(layout.all_rects(), rects);
+    assert_eq!(layout.compute_count.get(), 0);
+
+    // Values are recomputed once invalidated.
+    layout.cache.invalidate();
+    assert_eq!(layout.all_rects(), rects);
+    assert_eq!(layout.compute_count.get(), 16);
+  }
+}
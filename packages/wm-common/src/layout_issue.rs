@@ -0,0 +1,107 @@
+use uuid::Uuid;
+
+use crate::Rect;
+
+/// Problem found when validating the layout of a workspace's tiling
+/// windows.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LayoutIssue {
+  /// Two windows overlap by a non-zero area (in pixels).
+  Overlap {
+    window_ids: (Uuid, Uuid),
+    overlap_area: i64,
+  },
+
+  /// A window extends beyond the work area.
+  OutOfBounds { window_id: Uuid, rect: Rect },
+}
+
+impl LayoutIssue {
+  /// Finds all issues amongst the given window rects, where every rect
+  /// should lie within `work_area` without overlapping another.
+  ///
+  /// Overlaps are reported once per pair of windows, in the order the
+  /// windows are given.
+  #[must_use]
+  pub fn find_all(
+    window_rects: &[(Uuid, Rect)],
+    work_area: &Rect,
+  ) -> Vec<LayoutIssue> {
+    let mut issues = Vec::new();
+
+    for (index, (window_id, rect)) in window_rects.iter().enumerate() {
+      if !work_area.contains_rect(rect) {
+        issues.push(LayoutIssue::OutOfBounds {
+          window_id: *window_id,
+          rect: rect.clone(),
+        });
+      }
+
+      for (other_id, other_rect) in &window_rects[index + 1..] {
+        let overlap_area = rect.overlap_area(other_rect);
+
+        if overlap_area > 0 {
+          issues.push(LayoutIssue::Overlap {
+            window_ids: (*window_id, *other_id),
+            overlap_area,
+          });
+        }
+      }
+    }
+
+    issues
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::TilingDirection;
+
+  #[test]
+  fn test_valid_layout_has_no_issues() {
+    let work_area = Rect::from_xy(0, 0, 1920, 1080);
+    let window_rects = work_area
+      .split_weighted(&[0.25, 0.5, 0.25], &TilingDirection::Horizontal, 10)
+      .into_iter()
+      .map(|rect| (Uuid::new_v4(), rect))
+      .collect::<Vec<_>>();
+
+    assert!(LayoutIssue::find_all(&window_rects, &work_area).is_empty());
+  }
+
+  #[test]
+  fn test_broken_layout_reports_overlap_and_bounds() {
+    let work_area = Rect::from_xy(0, 0, 1920, 1080);
+    let (first_id, second_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+    // Tiling sizes of 0.6 each, such that the second window overlaps the
+    // first and spills past the work area.
+    let window_rects = [
+      (first_id, Rect::from_xy(0, 0, 1152, 1080)),
+      (second_id, Rect::from_xy(960, 0, 1152, 1080)),
+    ];
+
+    assert_eq!(
+      LayoutIssue::find_all(&window_rects, &work_area),
+      vec![
+        LayoutIssue::Overlap {
+          window_ids: (first_id, second_id),
+          overlap_area: 192 * 1080,
+        },
+        LayoutIssue::OutOfBounds {
+          window_id: second_id,
+          rect: Rect::from_xy(960, 0, 1152, 1080),
+        },
+      ]
+    );
+
+    // Windows that only share an edge don't overlap.
+    let window_rects = [
+      (first_id, Rect::from_xy(0, 0, 960, 1080)),
+      (second_id, Rect::from_xy(960, 0, 960, 1080)),
+    ];
+
+    assert!(LayoutIssue::find_all(&window_rects, &work_area).is_empty());
+  }
+}
@@ -1,4 +1,7 @@
-use std::str::FromStr;
+use std::{
+  ops::{Add, Mul, Neg, Sub},
+  str::FromStr,
+};
 
 use anyhow::{bail, Context};
 use regex::Regex;
@@ -15,6 +18,9 @@ pub struct LengthValue {
 pub enum LengthUnit {
   Percentage,
   Pixel,
+  /// Percentage of the shorter side (i.e. the minimum of the width and
+  /// height) of the reference rect.
+  MinAxisPercent,
 }
 
 impl LengthValue {
@@ -29,30 +35,126 @@ impl LengthValue {
 
   #[must_use]
   pub fn to_px(&self, total_px: i32, scale_factor: Option<f32>) -> i32 {
+    self.to_px_with_min_axis(total_px, None, scale_factor)
+  }
+
+  /// Converts the length to pixels, where `min_axis_px` is the length of
+  /// the shorter side of the reference rect. Min-axis percentages are
+  /// resolved against `total_px` if `min_axis_px` isn't provided.
+  #[must_use]
+  pub fn to_px_with_min_axis(
+    &self,
+    total_px: i32,
+    min_axis_px: Option<i32>,
+    scale_factor: Option<f32>,
+  ) -> i32 {
     let scale_factor = scale_factor.unwrap_or(1.0);
 
     #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
     match self.unit {
       LengthUnit::Percentage => (self.amount * total_px as f32) as i32,
       LengthUnit::Pixel => (self.amount * scale_factor) as i32,
+      LengthUnit::MinAxisPercent => {
+        (self.amount * min_axis_px.unwrap_or(total_px) as f32) as i32
+      }
+    }
+  }
+
+  /// Adds two lengths together.
+  ///
+  /// Returns `None` if the units can't be combined (e.g. px + %), unless
+  /// either of the lengths is zero.
+  #[must_use]
+  pub fn checked_add(&self, other: &LengthValue) -> Option<LengthValue> {
+    if other.amount == 0. {
+      return Some(self.clone());
     }
+
+    if self.amount == 0. {
+      return Some(other.clone());
+    }
+
+    (self.unit == other.unit).then(|| LengthValue {
+      amount: self.amount + other.amount,
+      unit: self.unit.clone(),
+    })
   }
 
+  /// Subtracts `other` from the length.
+  ///
+  /// Returns `None` if the units can't be combined (e.g. px - %), unless
+  /// either of the lengths is zero.
+  #[must_use]
+  pub fn checked_sub(&self, other: &LengthValue) -> Option<LengthValue> {
+    self.checked_add(&-other.clone())
+  }
+
+  /// Converts the length to a fraction of `total_px`. Min-axis
+  /// percentages are treated as a fraction of `total_px`, since the other
+  /// axis isn't known.
   #[must_use]
   pub fn to_percentage(&self, total_px: i32) -> f32 {
     match self.unit {
-      LengthUnit::Percentage => self.amount,
+      LengthUnit::Percentage | LengthUnit::MinAxisPercent => self.amount,
       #[allow(clippy::cast_precision_loss)]
       LengthUnit::Pixel => self.amount / total_px as f32,
     }
   }
 }
 
+/// Errors if the units can't be combined (see `LengthValue::checked_add`).
+impl Add for LengthValue {
+  type Output = anyhow::Result<LengthValue>;
+
+  fn add(self, other: LengthValue) -> Self::Output {
+    self.checked_add(&other).with_context(|| {
+      format!(
+        "Cannot add lengths with different units ({:?} and {:?}).",
+        self.unit, other.unit
+      )
+    })
+  }
+}
+
+/// Errors if the units can't be combined (see `LengthValue::checked_sub`).
+impl Sub for LengthValue {
+  type Output = anyhow::Result<LengthValue>;
+
+  fn sub(self, other: LengthValue) -> Self::Output {
+    self.checked_sub(&other).with_context(|| {
+      format!(
+        "Cannot subtract lengths with different units ({:?} and {:?}).",
+        self.unit, other.unit
+      )
+    })
+  }
+}
+
+/// Scales the amount of the length, keeping its unit.
+impl Mul<f32> for LengthValue {
+  type Output = LengthValue;
+
+  fn mul(self, factor: f32) -> Self::Output {
+    LengthValue {
+      amount: self.amount * factor,
+      unit: self.unit,
+    }
+  }
+}
+
+impl Neg for LengthValue {
+  type Output = LengthValue;
+
+  fn neg(self) -> Self::Output {
+    self * -1.
+  }
+}
+
 impl FromStr for LengthValue {
   type Err = anyhow::Error;
 
-  /// Parses a string containing a number followed by a unit (`px`, `%`).
-  /// Allows for negative numbers.
+  /// Parses a string containing a number followed by a unit (`px`, `%`,
+  /// `vmin`). Allows for negative numbers.
   ///
   /// Example:
   /// ```
@@ -66,10 +168,10 @@ impl FromStr for LengthValue {
   /// assert_eq!(parsed.unwrap(), check);
   /// ```
   fn from_str(unparsed: &str) -> anyhow::Result<Self> {
-    let units_regex = Regex::new(r"([+-]?\d+)(%|px)?")?;
+    let units_regex = Regex::new(r"([+-]?\d+)(%|px|vmin)?")?;
 
     let err_msg = format!(
-      "Not a valid length value '{unparsed}'. Must be of format '10px' or '10%'."
+      "Not a valid length value '{unparsed}'. Must be of format '10px', '10%' or '10vmin'."
     );
 
     let captures = units_regex
@@ -80,6 +182,7 @@ impl FromStr for LengthValue {
     let unit = match unit_str {
       "px" | "" => LengthUnit::Pixel,
       "%" => LengthUnit::Percentage,
+      "vmin" => LengthUnit::MinAxisPercent,
       _ => bail!(err_msg),
     };
 
@@ -89,7 +192,9 @@ impl FromStr for LengthValue {
       // Store percentage units as a fraction of 1.
       .map(|amount| match unit {
         LengthUnit::Pixel => amount,
-        LengthUnit::Percentage => amount / 100.0,
+        LengthUnit::Percentage | LengthUnit::MinAxisPercent => {
+          amount / 100.0
+        }
       })
       .context(err_msg.to_string())?;
 
@@ -118,3 +223,44 @@ impl<'de> Deserialize<'de> for LengthValue {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn percent(amount: f32) -> LengthValue {
+    LengthValue {
+      amount,
+      unit: LengthUnit::Percentage,
+    }
+  }
+
+  #[test]
+  fn test_px_arithmetic() -> anyhow::Result<()> {
+    let gap = LengthValue::from_px(10);
+    let border = LengthValue::from_px(2);
+
+    assert_eq!((gap.clone() + border.clone())?, LengthValue::from_px(12));
+    assert_eq!((gap.clone() - border.clone())?, LengthValue::from_px(8));
+    assert_eq!((border - gap.clone())?, LengthValue::from_px(-8));
+    assert_eq!(gap.clone() * 1.5, LengthValue::from_px(15));
+    assert_eq!(-gap, LengthValue::from_px(-10));
+
+    Ok(())
+  }
+
+  #[test]
+  fn test_percent_add_is_guarded() -> anyhow::Result<()> {
+    assert_eq!((percent(0.25) + percent(0.5))?, percent(0.75));
+    assert_eq!(percent(0.5) * 0.5, percent(0.25));
+
+    // Mixed units can't be combined without a reference length.
+    assert!((percent(0.25) + LengthValue::from_px(10)).is_err());
+    assert!((LengthValue::from_px(10) - percent(0.25)).is_err());
+
+    // Zero lengths are compatible with any unit.
+    assert_eq!((percent(0.25) + LengthValue::from_px(0))?, percent(0.25));
+
+    Ok(())
+  }
+}
@@ -2,39 +2,77 @@
 #![allow(clippy::missing_errors_doc)]
 
 mod active_drag;
+mod anchor;
 mod app_command;
+mod axis;
+mod clamp_mode;
 mod color;
+mod command_log;
 mod delta;
 mod direction;
 mod display_state;
+mod drop_region;
 mod dtos;
+mod easing;
+mod hit_test;
 mod ipc;
+mod layout_cache;
+mod layout_issue;
 mod length_value;
+mod monitor_bindings;
+mod monitor_matching;
+mod mru_stack;
 mod opacity_value;
+mod ordinal_direction;
 mod parsed_config;
 mod point;
 mod rect;
 mod rect_delta;
+mod snap_cycle;
+mod struts;
 mod tiling_direction;
+mod tiling_layout;
+mod tiling_size;
 mod utils;
+mod window_insertion;
 mod window_state;
 mod wm_event;
+mod workspace_layout;
 
 pub use active_drag::*;
+pub use anchor::*;
 pub use app_command::*;
+pub use axis::*;
+pub use clamp_mode::*;
 pub use color::*;
+pub use command_log::*;
 pub use delta::*;
 pub use direction::*;
 pub use display_state::*;
+pub use drop_region::*;
 pub use dtos::*;
+pub use easing::*;
+pub use hit_test::*;
 pub use ipc::*;
+pub use layout_cache::*;
+pub use layout_issue::*;
 pub use length_value::*;
+pub use monitor_bindings::*;
+pub use monitor_matching::*;
+pub use mru_stack::*;
 pub use opacity_value::*;
+pub use ordinal_direction::*;
 pub use parsed_config::*;
 pub use point::*;
 pub use rect::*;
 pub use rect_delta::*;
+pub use snap_cycle::*;
+pub use struts::*;
 pub use tiling_direction::*;
+pub use tiling_layout::*;
+pub use tiling_size::*;
 pub use utils::*;
+pub use window_insertion::*;
 pub use window_state::*;
 pub use wm_event::*;
+pub use workspace_layout::*;
@@ -0,0 +1,194 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use uuid::Uuid;
+
+use crate::Rect;
+
+/// Identifies a monitor across reconnects, by its hardware ID and its
+/// bounds.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonitorIdentity {
+  pub hardware_id: Option<String>,
+  pub rect: Rect,
+}
+
+impl MonitorIdentity {
+  /// Gets the index of the monitor in `monitors` that this identity
+  /// refers to.
+  ///
+  /// Monitors are matched by their hardware ID if exactly one of
+  /// `monitors` has it. Hardware IDs can be missing, and are shared by
+  /// monitors of the same model, so any monitors that can't be told apart
+  /// by their ID are matched by geometry instead. The candidate that
+  /// overlaps the most with the identity's bounds is picked, or the
+  /// nearest one if none of them overlap.
+  #[must_use]
+  pub fn find_in(&self, monitors: &[MonitorIdentity]) -> Option<usize> {
+    let candidates = monitors
+      .iter()
+      .enumerate()
+      .filter(|(_, monitor)| monitor.hardware_id == self.hardware_id)
+      .collect::<Vec<_>>();
+
+    if let [(index, _)] = candidates[..] {
+      if self.hardware_id.is_some() {
+        return Some(index);
+      }
+    }
+
+    let center = self.rect.center_point();
+
+    candidates
+      .into_iter()
+      .min_by_key(|(_, monitor)| {
+        (
+          Reverse(monitor.rect.overlap_area(&self.rect)),
+          monitor.rect.center_point().manhattan_distance(&center),
+        )
+      })
+      .map(|(index, _)| index)
+  }
+}
+
+/// Windows that are bound to a monitor, regardless of the workspace
+/// they're on.
+///
+/// Bindings are kept while the bound monitor is disconnected, such that
+/// windows can be moved back once it's reconnected.
+#[derive(Clone, Debug, Default)]
+pub struct MonitorBindings(HashMap<Uuid, MonitorIdentity>);
+
+impl MonitorBindings {
+  /// Binds a window to the given monitor. Replaces any existing binding
+  /// for the window.
+  pub fn bind(&mut self, window_id: Uuid, monitor: MonitorIdentity) {
+    self.0.insert(window_id, monitor);
+  }
+
+  /// Removes the binding for a window, returning the monitor it was
+  /// bound to.
+  pub fn unbind(&mut self, window_id: &Uuid) -> Option<MonitorIdentity> {
+    self.0.remove(window_id)
+  }
+
+  /// Gets the monitor that a window is bound to.
+  #[must_use]
+  pub fn get(&self, window_id: &Uuid) -> Option<&MonitorIdentity> {
+    self.0.get(window_id)
+  }
+
+  /// Gets the index of the monitor in `monitors` that a window should be
+  /// placed on.
+  ///
+  /// Falls back to `fallback_index` if the bound monitor isn't connected.
+  /// Returns `None` if the window isn't bound to a monitor.
+  #[must_use]
+  pub fn target_monitor(
+    &self,
+    window_id: &Uuid,
+    monitors: &[MonitorIdentity],
+    fallback_index: usize,
+  ) -> Option<usize> {
+    self
+      .get(window_id)
+      .map(|monitor| monitor.find_in(monitors).unwrap_or(fallback_index))
+  }
+
+  /// Gets the windows that are bound to the monitor at the given index
+  /// in `monitors`.
+  #[must_use]
+  pub fn windows_for_monitor(
+    &self,
+    monitor_index: usize,
+    monitors: &[MonitorIdentity],
+  ) -> Vec<Uuid> {
+    self
+      .0
+      .iter()
+      .filter(|(_, monitor)| {
+        monitor.find_in(monitors) == Some(monitor_index)
+      })
+      .map(|(window_id, _)| *window_id)
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn monitor(hardware_id: Option<&str>, left: i32) -> MonitorIdentity {
+    MonitorIdentity {
+      hardware_id: hardware_id.map(ToString::to_string),
+      rect: Rect::from_xy(left, 0, 1920, 1080),
+    }
+  }
+
+  #[test]
+  fn test_monitor_reconnect() {
+    let mut bindings = MonitorBindings::default();
+    let window_id = Uuid::new_v4();
+    let unbound_id = Uuid::new_v4();
+
+    let laptop = monitor(Some("BOE0900"), 0);
+    let external = monitor(Some("DEL4088"), 1920);
+    bindings.bind(window_id, external.clone());
+
+    // External monitor is connected.
+    let monitors = [laptop.clone(), external.clone()];
+    assert_eq!(bindings.target_monitor(&window_id, &monitors, 0), Some(1));
+    assert_eq!(bindings.target_monitor(&unbound_id, &monitors, 0), None);
+
+    // External monitor is disconnected, so the window falls back to the
+    // primary monitor while the binding is kept.
+    let monitors = [laptop.clone()];
+    assert_eq!(bindings.target_monitor(&window_id, &monitors, 0), Some(0));
+    assert_eq!(bindings.get(&window_id), Some(&external));
+
+    // Window returns once the external monitor is reconnected, even if
+    // it's now enumerated first and positioned on the left.
+    let monitors = [monitor(Some("DEL4088"), -1920), laptop];
+    assert_eq!(
+      bindings.windows_for_monitor(0, &monitors),
+      vec![window_id]
+    );
+    assert_eq!(bindings.target_monitor(&window_id, &monitors, 1), Some(0));
+
+    assert_eq!(bindings.unbind(&window_id), Some(external));
+    assert!(bindings.windows_for_monitor(0, &monitors).is_empty());
+  }
+
+  #[test]
+  fn test_shared_hardware_id_matched_by_geometry() {
+    let left = monitor(Some("DEL4088"), 0);
+    let right = monitor(Some("DEL4088"), 1920);
+
+    // Both monitors are the same model, so the bound monitor is told apart
+    // by its position.
+    assert_eq!(right.find_in(&[right.clone(), left.clone()]), Some(0));
+    assert_eq!(right.find_in(&[left.clone(), right.clone()]), Some(1));
+
+    // Monitors without a hardware ID are only matched by geometry.
+    let unknown = monitor(None, 1920);
+    assert_eq!(unknown.find_in(&[left, monitor(None, 1920)]), Some(1));
+  }
+
+  #[test]
+  fn test_nearest_monitor_matched_by_geometry() {
+    // None of the candidates have the bound monitor's exact bounds, so the
+    // nearest one is picked rather than the leftmost.
+    let bound = monitor(None, 3800);
+    let monitors =
+      [monitor(None, 0), monitor(None, 1920), monitor(None, 3840)];
+    assert_eq!(bound.find_in(&monitors), Some(2));
+
+    let bound = monitor(None, 2000);
+    let monitors =
+      [monitor(None, 3840), monitor(None, 0), monitor(None, 1920)];
+    assert_eq!(bound.find_in(&monitors), Some(2));
+
+    // Non-overlapping candidates are ranked by distance.
+    let bound = monitor(None, 9000);
+    assert_eq!(bound.find_in(&monitors), Some(0));
+  }
+}
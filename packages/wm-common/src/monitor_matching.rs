@@ -0,0 +1,142 @@
+use crate::Rect;
+
+/// Matches newly detected monitors to previously connected monitors by
+/// their geometry. Used when monitors can't be matched by their IDs, such
+/// as when their IDs change on reconnection.
+///
+/// Monitors with an identical rect are matched first. The rest are then
+/// paired up in order of their position from left-to-right (and
+/// top-to-bottom for monitors with the same left coordinate).
+///
+/// Returns the index of the matching previous monitor for each of the new
+/// monitors, or `None` if there are more new monitors than previous ones.
+#[must_use]
+pub fn match_monitors_by_geometry(
+  prev_rects: &[Rect],
+  new_rects: &[Rect],
+) -> Vec<Option<usize>> {
+  let mut matches = vec![None; new_rects.len()];
+  let mut unmatched_prev = (0..prev_rects.len()).collect::<Vec<_>>();
+
+  for (new_index, new_rect) in new_rects.iter().enumerate() {
+    if let Some(position) = unmatched_prev
+      .iter()
+      .position(|&prev_index| prev_rects[prev_index] == *new_rect)
+    {
+      matches[new_index] = Some(unmatched_prev.remove(position));
+    }
+  }
+
+  let mut unmatched_new = (0..new_rects.len())
+    .filter(|&new_index| matches[new_index].is_none())
+    .collect::<Vec<_>>();
+
+  unmatched_prev
+    .sort_by_key(|&index| (prev_rects[index].left, prev_rects[index].top));
+  unmatched_new
+    .sort_by_key(|&index| (new_rects[index].left, new_rects[index].top));
+
+  for (new_index, prev_index) in
+    unmatched_new.into_iter().zip(unmatched_prev)
+  {
+    matches[new_index] = Some(prev_index);
+  }
+
+  matches
+}
+
+/// Gets the indices of the given monitor rects, ordered by their position
+/// from left-to-right (and top-to-bottom for monitors with the same left
+/// coordinate).
+///
+/// Used for addressing monitors by position (e.g. "monitor 1" is always
+/// the leftmost), regardless of the order that the OS enumerates them in.
+#[must_use]
+pub fn monitors_by_position(rects: &[Rect]) -> Vec<usize> {
+  let mut indices = (0..rects.len()).collect::<Vec<_>>();
+  indices.sort_by_key(|&index| (rects[index].left, rects[index].top));
+  indices
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_reconnect_in_swapped_order() {
+    let prev_rects = [
+      Rect::from_xy(0, 0, 1920, 1080),
+      Rect::from_xy(1920, 0, 2560, 1440),
+    ];
+
+    // Monitors are reported in swapped order with new IDs, but are still
+    // at the same positions.
+    let new_rects = [
+      Rect::from_xy(1920, 0, 2560, 1440),
+      Rect::from_xy(0, 0, 1920, 1080),
+    ];
+
+    assert_eq!(
+      match_monitors_by_geometry(&prev_rects, &new_rects),
+      vec![Some(1), Some(0)]
+    );
+
+    // Resolution of the right monitor changed while disconnected, so it's
+    // matched by its left-to-right position instead.
+    let new_rects = [
+      Rect::from_xy(1920, 0, 3840, 2160),
+      Rect::from_xy(0, 0, 1920, 1080),
+    ];
+
+    assert_eq!(
+      match_monitors_by_geometry(&prev_rects, &new_rects),
+      vec![Some(1), Some(0)]
+    );
+  }
+
+  #[test]
+  fn test_fallback_order_by_left_coordinate() {
+    let prev_rects = [
+      Rect::from_xy(-1920, 0, 1920, 1080),
+      Rect::from_xy(0, 0, 1920, 1080),
+    ];
+
+    // Primary monitor changed, so all positions shifted. An extra
+    // monitor is also connected.
+    let new_rects = [
+      Rect::from_xy(3840, 0, 1920, 1080),
+      Rect::from_xy(1920, 0, 1920, 1080),
+      Rect::from_xy(0, 0, 1280, 1024),
+    ];
+
+    assert_eq!(
+      match_monitors_by_geometry(&prev_rects, &new_rects),
+      vec![None, Some(1), Some(0)]
+    );
+  }
+
+  #[test]
+  fn test_monitors_by_position_in_shuffled_order() {
+    // Monitors are enumerated in a different order than they're laid out,
+    // with the primary monitor in the middle.
+    let rects = [
+      Rect::from_xy(0, 0, 2560, 1440),
+      Rect::from_xy(2560, -200, 1080, 1920),
+      Rect::from_xy(-1920, 0, 1920, 1080),
+    ];
+
+    let positions = monitors_by_position(&rects);
+    assert_eq!(positions, vec![2, 0, 1]);
+
+    // Index 0 is the leftmost monitor.
+    assert_eq!(rects[positions[0]], Rect::from_xy(-1920, 0, 1920, 1080));
+
+    // Stacked monitors with the same left coordinate are ordered
+    // top-to-bottom.
+    let rects = [
+      Rect::from_xy(0, 1080, 1920, 1080),
+      Rect::from_xy(0, 0, 1920, 1080),
+    ];
+    assert_eq!(monitors_by_position(&rects), vec![1, 0]);
+  }
+}
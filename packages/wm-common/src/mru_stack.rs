@@ -0,0 +1,149 @@
+use uuid::Uuid;
+
+/// Windows in the order they were most recently used (i.e. focused).
+///
+/// Used for cycling focus similar to alt-tab, where consecutive cycles
+/// move further back in the history instead of toggling between the two
+/// most recent windows.
+#[derive(Clone, Debug, Default)]
+pub struct MruStack {
+  /// Window IDs, with the most recently used first.
+  ids: Vec<Uuid>,
+
+  /// Index in `ids` of the window that was last cycled to. `None` if not
+  /// currently cycling.
+  cycle_index: Option<usize>,
+}
+
+impl MruStack {
+  /// Window IDs, with the most recently used first.
+  #[must_use]
+  pub fn ids(&self) -> &[Uuid] {
+    &self.ids
+  }
+
+  /// Marks a window as the most recently used.
+  ///
+  /// Focusing the window that was last cycled to doesn't change the
+  /// order, such that cycling can continue. Focusing any other window
+  /// ends the cycle, and the cycled-to window is moved to the front
+  /// before the given window.
+  pub fn push(&mut self, window_id: Uuid) {
+    let is_cycled_window = self
+      .cycle_index
+      .is_some_and(|index| self.ids.get(index) == Some(&window_id));
+
+    if is_cycled_window {
+      return;
+    }
+
+    self.end_cycle();
+    self.ids.retain(|id| *id != window_id);
+    self.ids.insert(0, window_id);
+  }
+
+  /// Removes a window from the stack (e.g. when it's closed).
+  pub fn remove(&mut self, window_id: &Uuid) {
+    let Some(position) = self.ids.iter().position(|id| id == window_id)
+    else {
+      return;
+    };
+
+    self.ids.remove(position);
+
+    self.cycle_index = match self.cycle_index {
+      Some(index) if index == position => None,
+      Some(index) if index > position => Some(index - 1),
+      cycle_index => cycle_index,
+    };
+  }
+
+  /// Removes windows for which the predicate returns `false`.
+  pub fn retain(&mut self, predicate: impl Fn(&Uuid) -> bool) {
+    let removed_ids = self
+      .ids
+      .iter()
+      .filter(|id| !predicate(id))
+      .copied()
+      .collect::<Vec<_>>();
+
+    for id in &removed_ids {
+      self.remove(id);
+    }
+  }
+
+  /// Gets the next window to focus when cycling, starting from the
+  /// second most recently used window. Wraps around to the most recently
+  /// used window after reaching the end of the stack.
+  ///
+  /// Returns `None` if there are fewer than two windows.
+  pub fn cycle(&mut self) -> Option<Uuid> {
+    if self.ids.len() < 2 {
+      return None;
+    }
+
+    let next_index = self
+      .cycle_index
+      .map_or(1, |index| (index + 1) % self.ids.len());
+
+    self.cycle_index = Some(next_index);
+    Some(self.ids[next_index])
+  }
+
+  /// Moves the window that was last cycled to, to the front of the stack.
+  fn end_cycle(&mut self) {
+    if let Some(index) = self.cycle_index.take() {
+      let id = self.ids.remove(index);
+      self.ids.insert(0, id);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_cycle_focus_history() {
+    let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+    let mut stack = MruStack::default();
+
+    stack.push(a);
+    stack.push(b);
+    stack.push(c);
+    assert_eq!(stack.ids(), [c, b, a]);
+
+    // Focus events for the cycled-to window don't reset the cycle.
+    assert_eq!(stack.cycle(), Some(b));
+    stack.push(b);
+    assert_eq!(stack.cycle(), Some(a));
+    stack.push(a);
+
+    // Focusing another window ends the cycle, with the cycled-to window
+    // becoming the most recently used.
+    stack.push(c);
+    assert_eq!(stack.ids(), [c, a, b]);
+    assert_eq!(stack.cycle(), Some(a));
+  }
+
+  #[test]
+  fn test_remove_closed_window() {
+    let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+    let mut stack = MruStack::default();
+
+    stack.push(a);
+    stack.push(b);
+    stack.push(c);
+
+    assert_eq!(stack.cycle(), Some(b));
+    stack.remove(&c);
+    assert_eq!(stack.ids(), [b, a]);
+
+    // Cycle continues from the same window after the removal.
+    assert_eq!(stack.cycle(), Some(a));
+
+    stack.retain(|id| *id != a);
+    assert_eq!(stack.ids(), [b]);
+    assert_eq!(stack.cycle(), None);
+  }
+}
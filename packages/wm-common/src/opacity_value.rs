@@ -3,6 +3,8 @@ use std::str::FromStr;
 use anyhow::Context;
 use serde::{Deserialize, Deserializer, Serialize};
 
+use crate::Delta;
+
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct OpacityValue(f32);
 
@@ -18,6 +20,26 @@ impl OpacityValue {
   pub fn from_alpha(alpha: u8) -> Self {
     Self(f32::from(alpha) / 255.0)
   }
+
+  /// Adjusts the opacity by the given delta. Saturates at fully
+  /// transparent and fully opaque.
+  #[must_use]
+  pub fn apply_delta(&self, delta: &Delta<OpacityValue>) -> Self {
+    let alpha = if delta.is_negative {
+      self.to_alpha().saturating_sub(delta.inner.to_alpha())
+    } else {
+      self.to_alpha().saturating_add(delta.inner.to_alpha())
+    };
+
+    Self::from_alpha(alpha)
+  }
+
+  /// Linearly interpolates between this opacity and another, where `t` is
+  /// clamped to the range 0.0 to 1.0. Used for fading windows.
+  #[must_use]
+  pub fn lerp(&self, other: &OpacityValue, t: f32) -> Self {
+    Self(self.0 + (other.0 - self.0) * t.clamp(0.0, 1.0))
+  }
 }
 
 impl Default for OpacityValue {
@@ -80,3 +102,19 @@ impl<'de> Deserialize<'de> for OpacityValue {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_lerp_fades_between_alphas() {
+    let from = OpacityValue::from_alpha(255);
+    let to = OpacityValue::from_alpha(51);
+
+    let alphas = [0.0, 0.25, 0.5, 1.0, 2.0]
+      .map(|progress| from.lerp(&to, progress).to_alpha());
+
+    assert_eq!(alphas, [255, 204, 153, 51, 51]);
+  }
+}
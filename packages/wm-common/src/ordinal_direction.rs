@@ -0,0 +1,42 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::Direction;
+
+/// Diagonal direction between two cardinal directions.
+///
+/// Only used for snapping floating windows to corners. Tiling commands
+/// are limited to the cardinal directions of `Direction`.
+#[derive(
+  Clone, Copy, Debug, Deserialize, PartialEq, Serialize, ValueEnum,
+)]
+#[clap(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum OrdinalDirection {
+  UpLeft,
+  UpRight,
+  DownLeft,
+  DownRight,
+}
+
+impl OrdinalDirection {
+  /// Gets the horizontal and vertical directions that make up the
+  /// diagonal.
+  ///
+  /// Example:
+  /// ```
+  /// # use wm_common::{Direction, OrdinalDirection};
+  /// let (horizontal, vertical) = OrdinalDirection::UpLeft.components();
+  /// assert_eq!(horizontal, Direction::Left);
+  /// assert_eq!(vertical, Direction::Up);
+  /// ```
+  #[must_use]
+  pub fn components(&self) -> (Direction, Direction) {
+    match self {
+      Self::UpLeft => (Direction::Left, Direction::Up),
+      Self::UpRight => (Direction::Right, Direction::Up),
+      Self::DownLeft => (Direction::Left, Direction::Down),
+      Self::DownRight => (Direction::Right, Direction::Down),
+    }
+  }
+}
@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-  app_command::InvokeCommand, Color, LengthValue, OpacityValue, RectDelta,
+  app_command::InvokeCommand, Color, LengthValue, OpacityValue, Rect,
+  RectDelta, TilingDirection, TilingLayout, WindowInsertion,
 };
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -47,6 +48,11 @@ pub struct GapsConfig {
   /// Gap between window and the screen edge if there is only one window
   /// in the workspace
   pub single_window_outer_gap: Option<RectDelta>,
+
+  /// Minimum size of a tiling window along the tiling direction of its
+  /// parent. Resizes that would shrink a window below this are instead
+  /// taken from its siblings.
+  pub min_tiling_size: LengthValue,
 }
 
 impl Default for GapsConfig {
@@ -61,10 +67,60 @@ impl Default for GapsConfig {
         LengthValue::from_px(0),
       ),
       single_window_outer_gap: None,
+      min_tiling_size: LengthValue::from_px(0),
     }
   }
 }
 
+impl GapsConfig {
+  /// Gets the factor to scale pixel gaps by on a monitor with the given
+  /// scale factor. Gaps are only scaled if `scale_with_dpi` is enabled.
+  #[must_use]
+  pub fn scale_factor(&self, monitor_scale_factor: f32) -> f32 {
+    if self.scale_with_dpi {
+      monitor_scale_factor
+    } else {
+      1.
+    }
+  }
+
+  /// Gets the horizontal and vertical gaps between windows in pixels.
+  ///
+  /// Percentage gaps are resolved against the monitor's width for the
+  /// horizontal gap, and against its height for the vertical gap.
+  /// Workspace-specific gaps take precedence over the global inner gap.
+  /// With `scale_with_dpi` enabled, pixel gaps are treated as logical
+  /// pixels and are multiplied by the monitor's scale factor.
+  #[must_use]
+  pub fn inner_gaps(
+    &self,
+    workspace_gaps: Option<&WorkspaceGapsConfig>,
+    monitor_rect: &Rect,
+    monitor_scale_factor: f32,
+  ) -> (i32, i32) {
+    let (horizontal_inner_gap, vertical_inner_gap) = workspace_gaps
+      .cloned()
+      .unwrap_or_default()
+      .inner_gaps(&self.inner_gap);
+
+    let scale_factor = Some(self.scale_factor(monitor_scale_factor));
+    let min_axis = Some(monitor_rect.width().min(monitor_rect.height()));
+
+    (
+      horizontal_inner_gap.to_px_with_min_axis(
+        monitor_rect.width(),
+        min_axis,
+        scale_factor,
+      ),
+      vertical_inner_gap.to_px_with_min_axis(
+        monitor_rect.height(),
+        min_axis,
+        scale_factor,
+      ),
+    )
+  }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(default, rename_all(serialize = "camelCase"))]
 pub struct GeneralConfig {
@@ -74,10 +130,26 @@ pub struct GeneralConfig {
   /// Whether to automatically focus windows underneath the cursor.
   pub focus_follows_cursor: bool,
 
+  /// Distance in pixels that the cursor needs to be inside of a window
+  /// before it gets focused by `focus_follows_cursor`. Prevents focus
+  /// from flickering when the cursor is on the border between windows.
+  pub focus_follows_cursor_margin: i32,
+
   /// Whether to switch back and forth between the previously focused
   /// workspace when focusing the current workspace.
   pub toggle_workspace_on_refocus: bool,
 
+  /// What to do when focusing in a direction past the edge of a
+  /// workspace.
+  pub focus_edge_behavior: FocusEdgeBehavior,
+
+  /// Tiling direction of newly created workspaces, based on the
+  /// orientation of their monitor.
+  pub default_tiling_direction: DefaultTilingDirectionConfig,
+
+  /// Where new tiling windows are inserted in the container tree.
+  pub window_insertion: WindowInsertion,
+
   /// Commands to run when the WM has started (e.g. to run a script or
   /// launch another application).
   pub startup_commands: Vec<InvokeCommand>,
@@ -100,7 +172,11 @@ impl Default for GeneralConfig {
     GeneralConfig {
       cursor_jump: CursorJumpConfig::default(),
       focus_follows_cursor: false,
+      focus_follows_cursor_margin: 0,
       toggle_workspace_on_refocus: true,
+      focus_edge_behavior: FocusEdgeBehavior::default(),
+      default_tiling_direction: DefaultTilingDirectionConfig::default(),
+      window_insertion: WindowInsertion::default(),
       startup_commands: vec![],
       shutdown_commands: vec![],
       config_reload_commands: vec![],
@@ -110,6 +186,39 @@ impl Default for GeneralConfig {
   }
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all(serialize = "camelCase"))]
+pub struct DefaultTilingDirectionConfig {
+  /// Tiling direction on monitors that are wider than they are tall.
+  pub landscape: TilingDirection,
+
+  /// Tiling direction on monitors that are taller than they are wide
+  /// (e.g. rotated monitors).
+  pub portrait: TilingDirection,
+}
+
+impl Default for DefaultTilingDirectionConfig {
+  fn default() -> Self {
+    DefaultTilingDirectionConfig {
+      landscape: TilingDirection::Horizontal,
+      portrait: TilingDirection::Vertical,
+    }
+  }
+}
+
+impl DefaultTilingDirectionConfig {
+  /// Gets the tiling direction for a workspace on a monitor with the
+  /// given rect.
+  #[must_use]
+  pub fn for_monitor(&self, monitor_rect: &Rect) -> TilingDirection {
+    if monitor_rect.is_portrait() {
+      self.portrait.clone()
+    } else {
+      self.landscape.clone()
+    }
+  }
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[serde(default, rename_all(serialize = "camelCase"))]
 pub struct CursorJumpConfig {
@@ -128,6 +237,22 @@ pub enum CursorJumpTrigger {
   WindowFocus,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FocusEdgeBehavior {
+  /// Focus stays on the window at the edge.
+  Stop,
+
+  /// Focus wraps around to the window at the opposite edge of the
+  /// workspace.
+  Wrap,
+
+  /// Focus moves to the workspace on the next monitor in the given
+  /// direction.
+  #[default]
+  NextMonitor,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum HideMethod {
@@ -173,21 +298,43 @@ pub struct WindowStateDefaultsConfig {
   pub fullscreen: FullscreenStateConfig,
 }
 
-#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
 #[serde(default, rename_all(serialize = "camelCase"))]
 pub struct FloatingStateConfig {
+  /// Whether to show floating windows as always on top.
+  pub shown_on_top: bool,
+
+  /// Whether floating windows follow focus to other workspaces.
+  pub sticky: bool,
+
+  /// Where floating windows are placed. Flattened, such that its options
+  /// are set alongside the other floating options.
+  #[serde(flatten)]
+  pub placement: FloatingPlacementConfig,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, rename_all(serialize = "camelCase"))]
+pub struct FloatingPlacementConfig {
   /// Whether to center new floating windows.
   pub centered: bool,
 
-  /// Whether to show floating windows as always on top.
-  pub shown_on_top: bool,
+  /// Whether to center new floating windows on the cursor instead of the
+  /// workspace. Takes precedence over `centered` when the cursor is
+  /// within the window's workspace.
+  pub centered_on_cursor: bool,
+
+  /// Whether tiling windows keep their current rect when floated, rather
+  /// than using their previous floating placement.
+  pub preserve_geometry: bool,
 }
 
-impl Default for FloatingStateConfig {
+impl Default for FloatingPlacementConfig {
   fn default() -> Self {
-    FloatingStateConfig {
+    FloatingPlacementConfig {
       centered: true,
-      shown_on_top: false,
+      centered_on_cursor: false,
+      preserve_geometry: false,
     }
   }
 }
@@ -219,6 +366,59 @@ pub struct WindowEffectsConfig {
 
   /// Visual effects to apply to non-focused windows.
   pub other_windows: WindowEffectConfig,
+
+  /// Highlight around the focused window, which is drawn by a companion
+  /// overlay process listening for `focus_ring_changed` events.
+  pub focus_ring: FocusRingEffectConfig,
+}
+
+impl WindowEffectsConfig {
+  /// Gets the opacity to apply to a window on focus changes.
+  ///
+  /// An opacity set on the window itself takes precedence, but is further
+  /// dimmed when the window isn't focused and transparency is enabled for
+  /// non-focused windows. Returns `None` if the window's opacity shouldn't
+  /// be changed.
+  #[must_use]
+  pub fn opacity(
+    &self,
+    is_focused: bool,
+    window_opacity: Option<&OpacityValue>,
+  ) -> Option<OpacityValue> {
+    let dimmed_opacity = (!is_focused
+      && self.other_windows.transparency.enabled)
+      .then_some(&self.other_windows.transparency.opacity);
+
+    if let Some(window_opacity) = window_opacity {
+      return Some(match dimmed_opacity {
+        Some(dimmed_opacity)
+          if dimmed_opacity.to_alpha() < window_opacity.to_alpha() =>
+        {
+          dimmed_opacity.clone()
+        }
+        _ => window_opacity.clone(),
+      });
+    }
+
+    if !self.focused_window.transparency.enabled
+      && !self.other_windows.transparency.enabled
+    {
+      return None;
+    }
+
+    let effect_config = if is_focused {
+      &self.focused_window
+    } else {
+      &self.other_windows
+    };
+
+    Some(if effect_config.transparency.enabled {
+      effect_config.transparency.opacity.clone()
+    } else {
+      // Reset the transparency to default.
+      OpacityValue::from_alpha(u8::MAX)
+    })
+  }
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -276,6 +476,35 @@ pub struct CornerEffectConfig {
 
   /// Style of the window corners.
   pub style: CornerStyle,
+
+  /// Pixels to outset tiling windows by, such that the visible gaps at
+  /// rounded corners shrink. Capped at half the inner gap, so that
+  /// adjacent windows never overlap.
+  pub compensation: i32,
+}
+
+impl CornerEffectConfig {
+  /// Gets the rect to draw a tiling window at, outset by the corner
+  /// compensation. Windows with square corners are left unchanged.
+  #[must_use]
+  pub fn compensated_rect(
+    &self,
+    rect: &Rect,
+    horizontal_gap: i32,
+    vertical_gap: i32,
+  ) -> Rect {
+    if self.enabled && self.style == CornerStyle::Square {
+      return rect.clone();
+    }
+
+    let outset = self
+      .compensation
+      .min(horizontal_gap / 2)
+      .min(vertical_gap / 2)
+      .max(0);
+
+    rect.outset(outset)
+  }
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
@@ -288,6 +517,43 @@ pub enum CornerStyle {
   SmallRounded,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, rename_all(serialize = "camelCase"))]
+pub struct FocusRingEffectConfig {
+  /// Whether to enable the effect.
+  pub enabled: bool,
+
+  /// Thickness of the ring in pixels. The ring is drawn outside of the
+  /// window's rect.
+  pub width: i32,
+
+  /// Color of the ring.
+  pub color: Color,
+}
+
+impl FocusRingEffectConfig {
+  /// Gets the rect of the overlay for the focused window's rect.
+  #[must_use]
+  pub fn overlay_rect(&self, window_rect: &Rect) -> Rect {
+    window_rect.outset(self.width)
+  }
+}
+
+impl Default for FocusRingEffectConfig {
+  fn default() -> Self {
+    FocusRingEffectConfig {
+      enabled: false,
+      width: 2,
+      color: Color {
+        r: 140,
+        g: 190,
+        b: 255,
+        a: 255,
+      },
+    }
+  }
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[serde(default, rename_all(serialize = "camelCase"))]
 pub struct TransparencyEffectConfig {
@@ -321,6 +587,60 @@ pub struct WindowMatchConfig {
   pub window_title: Option<MatchType>,
 }
 
+impl WindowRuleConfig {
+  /// Whether the rule ignores matching windows when they're managed.
+  /// Such windows are ignored before being added to the tree, so that
+  /// they don't affect the layout of other windows.
+  #[must_use]
+  pub fn is_ignore_rule(&self) -> bool {
+    self.on.contains(&WindowRuleEvent::Manage)
+      && self.commands.contains(&InvokeCommand::Ignore)
+  }
+
+  /// Whether a window with the given process name, class name and title
+  /// matches any of the rule's match configs.
+  #[must_use]
+  pub fn is_match(
+    &self,
+    window_process: &str,
+    window_class: &str,
+    window_title: &str,
+  ) -> bool {
+    self.match_window.iter().any(|match_config| {
+      match_config.is_match(window_process, window_class, window_title)
+    })
+  }
+}
+
+impl WindowMatchConfig {
+  /// Whether a window with the given process name, class name and title
+  /// matches all of the properties that are set.
+  #[must_use]
+  pub fn is_match(
+    &self,
+    window_process: &str,
+    window_class: &str,
+    window_title: &str,
+  ) -> bool {
+    let is_process_match = self
+      .window_process
+      .as_ref()
+      .is_none_or(|match_type| match_type.is_match(window_process));
+
+    let is_class_match = self
+      .window_class
+      .as_ref()
+      .is_none_or(|match_type| match_type.is_match(window_class));
+
+    let is_title_match = self
+      .window_title
+      .as_ref()
+      .is_none_or(|match_type| match_type.is_match(window_title));
+
+    is_process_match && is_class_match && is_title_match
+  }
+}
+
 /// Due to limitations in `serde_yaml`, we need to use an untagged enum
 /// instead of a regular enum for serialization. Using a regular enum
 /// causes issues with flow-style objects in YAML.
@@ -378,6 +698,47 @@ pub struct WorkspaceConfig {
 
   #[serde(default = "default_bool::<false>")]
   pub keep_alive: bool,
+
+  /// Overrides for the global gaps config in this workspace.
+  #[serde(default)]
+  pub gaps: Option<WorkspaceGapsConfig>,
+
+  /// Strategy for arranging the tiling windows in this workspace.
+  #[serde(default)]
+  pub tiling_layout: TilingLayout,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, rename_all(serialize = "camelCase"))]
+pub struct WorkspaceGapsConfig {
+  /// Gap between horizontally adjacent windows. Falls back to the global
+  /// inner gap if unset.
+  pub horizontal_inner_gap: Option<LengthValue>,
+
+  /// Gap between vertically adjacent windows. Falls back to the global
+  /// inner gap if unset.
+  pub vertical_inner_gap: Option<LengthValue>,
+}
+
+impl WorkspaceGapsConfig {
+  /// Gets the horizontal and vertical inner gaps, using the given global
+  /// inner gap for any that aren't overridden.
+  #[must_use]
+  pub fn inner_gaps(
+    &self,
+    global_inner_gap: &LengthValue,
+  ) -> (LengthValue, LengthValue) {
+    (
+      self
+        .horizontal_inner_gap
+        .clone()
+        .unwrap_or_else(|| global_inner_gap.clone()),
+      self
+        .vertical_inner_gap
+        .clone()
+        .unwrap_or_else(|| global_inner_gap.clone()),
+    )
+  }
 }
 
 /// Helper function for setting a default value for a boolean field.
@@ -389,3 +750,266 @@ const fn default_bool<const V: bool>() -> bool {
 fn default_window_rule_on() -> Vec<WindowRuleEvent> {
   vec![WindowRuleEvent::Manage, WindowRuleEvent::TitleChange]
 }
+
+#[cfg(test)]
+mod tests {
+  use std::str::FromStr;
+
+  use super::*;
+  use crate::Delta;
+
+  #[test]
+  fn test_corner_compensation_never_overlaps() {
+    let workspace_rect = Rect::from_xy(0, 0, 1920, 1080);
+
+    for gap in [0, 1, 5, 10, 15] {
+      for compensation in [0, 2, 4, 8, 20] {
+        let corner_config = CornerEffectConfig {
+          enabled: true,
+          style: CornerStyle::Rounded,
+          compensation,
+        };
+
+        // Layout of H[1 V[2 3] 4].
+        let columns = workspace_rect.split_weighted(
+          &[0.3, 0.4, 0.3],
+          &TilingDirection::Horizontal,
+          gap,
+        );
+        let rows = columns[1].split_weighted(
+          &[0.5, 0.5],
+          &TilingDirection::Vertical,
+          gap,
+        );
+
+        let tiles = [&columns[0], &rows[0], &rows[1], &columns[2]]
+          .map(|tile| corner_config.compensated_rect(tile, gap, gap));
+
+        for (index, tile) in tiles.iter().enumerate() {
+          for other in &tiles[index + 1..] {
+            assert_eq!(tile.overlap_area(other), 0);
+          }
+        }
+      }
+    }
+
+    // Square corners don't need compensating.
+    let corner_config = CornerEffectConfig {
+      enabled: true,
+      style: CornerStyle::Square,
+      compensation: 4,
+    };
+
+    assert_eq!(
+      corner_config.compensated_rect(&workspace_rect, 10, 10),
+      workspace_rect
+    );
+  }
+
+  #[test]
+  fn test_workspace_gaps_override() {
+    let workspace_config: WorkspaceConfig = serde_json::from_str(
+      r#"{ "name": "code", "gaps": { "horizontal_inner_gap": "5px" } }"#,
+    )
+    .unwrap();
+
+    let global_inner_gap = LengthValue::from_px(20);
+    let (horizontal_gap, vertical_gap) = workspace_config
+      .gaps
+      .unwrap_or_default()
+      .inner_gaps(&global_inner_gap);
+
+    // Horizontal gap comes from the override, while the vertical gap
+    // falls through to the global config.
+    assert_eq!(horizontal_gap, LengthValue::from_px(5));
+    assert_eq!(vertical_gap, global_inner_gap);
+  }
+
+  #[test]
+  fn test_percent_inner_gaps_on_non_square_monitor() {
+    let gaps_config = GapsConfig {
+      inner_gap: LengthValue::from_str("1%").unwrap(),
+      ..GapsConfig::default()
+    };
+
+    let monitor_rect = Rect::from_xy(0, 0, 2000, 1000);
+
+    assert_eq!(gaps_config.inner_gaps(None, &monitor_rect, 1.), (20, 10));
+  }
+
+  #[test]
+  fn test_floating_placement_options_set_alongside_state_options() {
+    let floating_config: FloatingStateConfig = serde_json::from_str(
+      r#"{ "sticky": true, "centered": false, "preserve_geometry": true }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+      floating_config,
+      FloatingStateConfig {
+        shown_on_top: false,
+        sticky: true,
+        placement: FloatingPlacementConfig {
+          centered: false,
+          centered_on_cursor: false,
+          preserve_geometry: true,
+        },
+      }
+    );
+  }
+
+  #[test]
+  fn test_workspace_gaps_unset() {
+    let workspace_config: WorkspaceConfig =
+      serde_json::from_str(r#"{ "name": "media" }"#).unwrap();
+
+    let global_inner_gap = LengthValue::from_px(20);
+
+    assert_eq!(workspace_config.gaps, None);
+    assert_eq!(
+      WorkspaceGapsConfig::default().inner_gaps(&global_inner_gap),
+      (global_inner_gap.clone(), global_inner_gap)
+    );
+  }
+
+  #[test]
+  fn test_default_tiling_direction_by_orientation() {
+    let config = DefaultTilingDirectionConfig::default();
+
+    assert_eq!(
+      config.for_monitor(&Rect::from_xy(0, 0, 1080, 1920)),
+      TilingDirection::Vertical
+    );
+    assert_eq!(
+      config.for_monitor(&Rect::from_xy(0, 0, 1920, 1080)),
+      TilingDirection::Horizontal
+    );
+
+    let config: DefaultTilingDirectionConfig =
+      serde_json::from_str(r#"{ "portrait": "horizontal" }"#).unwrap();
+
+    assert_eq!(
+      config.for_monitor(&Rect::from_xy(0, 0, 1080, 1920)),
+      TilingDirection::Horizontal
+    );
+  }
+
+  #[test]
+  fn test_window_effects_opacity_on_focus_change() {
+    let window_effects: WindowEffectsConfig = serde_json::from_str(
+      r#"{
+        "other_windows": {
+          "transparency": { "enabled": true, "opacity": "60%" }
+        }
+      }"#,
+    )
+    .unwrap();
+
+    let alpha = |is_focused, window_opacity: Option<&OpacityValue>| {
+      window_effects
+        .opacity(is_focused, window_opacity)
+        .map(|opacity| opacity.to_alpha())
+    };
+
+    // Window is reset to opaque on focus, and dimmed on losing focus.
+    assert_eq!(alpha(true, None), Some(255));
+    assert_eq!(alpha(false, None), Some(153));
+
+    // Opacity set on the window is used while focused, and is dimmed
+    // when it's more opaque than non-focused windows.
+    let window_opacity = OpacityValue::from_alpha(204);
+    assert_eq!(alpha(true, Some(&window_opacity)), Some(204));
+    assert_eq!(alpha(false, Some(&window_opacity)), Some(153));
+
+    let window_opacity = OpacityValue::from_alpha(102);
+    assert_eq!(alpha(false, Some(&window_opacity)), Some(102));
+
+    // Opacity is left untouched when transparency effects are disabled.
+    let window_effects = WindowEffectsConfig::default();
+    assert_eq!(window_effects.opacity(false, None), None);
+    assert_eq!(
+      window_effects
+        .opacity(false, Some(&window_opacity))
+        .map(|opacity| opacity.to_alpha()),
+      Some(102)
+    );
+  }
+
+  #[test]
+  fn test_focus_ring_overlay_rect() {
+    let window_effects: WindowEffectsConfig = serde_json::from_str(
+      r#"{ "focus_ring": { "enabled": true, "width": 4 } }"#,
+    )
+    .unwrap();
+
+    let window_rect = Rect::from_xy(100, 50, 800, 600);
+
+    assert_eq!(
+      window_effects.focus_ring.overlay_rect(&window_rect),
+      Rect::from_xy(96, 46, 808, 608)
+    );
+
+    assert_eq!(
+      FocusRingEffectConfig::default().overlay_rect(&window_rect),
+      window_rect.outset(2)
+    );
+  }
+
+  #[test]
+  fn test_opacity_apply_delta() {
+    let opacity = OpacityValue::from_alpha(200);
+
+    let delta = Delta {
+      inner: OpacityValue::from_alpha(100),
+      is_negative: false,
+    };
+    assert_eq!(opacity.apply_delta(&delta).to_alpha(), 255);
+
+    let delta = Delta {
+      inner: OpacityValue::from_alpha(100),
+      is_negative: true,
+    };
+    assert_eq!(opacity.apply_delta(&delta).to_alpha(), 100);
+  }
+
+  #[test]
+  fn test_inner_gaps_scale_with_dpi() {
+    let mut gaps_config = GapsConfig {
+      inner_gap: LengthValue::from_px(8),
+      ..GapsConfig::default()
+    };
+
+    let monitor_rect = Rect::from_xy(0, 0, 2560, 1440);
+
+    // Gap is treated as logical pixels at 150% scaling.
+    assert_eq!(gaps_config.inner_gaps(None, &monitor_rect, 1.5), (12, 12));
+
+    // Workspace overrides are scaled the same way.
+    let workspace_gaps = WorkspaceGapsConfig {
+      horizontal_inner_gap: Some(LengthValue::from_px(4)),
+      vertical_inner_gap: None,
+    };
+    assert_eq!(
+      gaps_config.inner_gaps(Some(&workspace_gaps), &monitor_rect, 1.5),
+      (6, 12)
+    );
+
+    gaps_config.scale_with_dpi = false;
+    assert_eq!(gaps_config.inner_gaps(None, &monitor_rect, 1.5), (8, 8));
+  }
+
+  #[test]
+  fn test_ignore_rule_matches_window() {
+    let ignore_rule: WindowRuleConfig = serde_json::from_str(
+      r#"{
+        "commands": ["ignore"],
+        "match": [{ "window_class": { "equals": "IME" } }]
+      }"#,
+    )
+    .unwrap();
+
+    assert!(ignore_rule.is_ignore_rule());
+    assert!(ignore_rule.is_match("ctfmon", "IME", "Default IME"));
+    assert!(!ignore_rule.is_match("code", "Chrome_WidgetWin_1", "a.rs"));
+  }
+}
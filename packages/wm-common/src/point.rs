@@ -1,6 +1,141 @@
+use std::ops::{Add, Sub};
+
+use crate::Rect;
+
 /// Represents an x-y coordinate.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Point {
   pub x: i32,
   pub y: i32,
 }
+
+impl Point {
+  /// Gets the Euclidean distance between this point and another point.
+  #[must_use]
+  pub fn distance_to(&self, other: &Point) -> f32 {
+    let dx = f64::from(self.x - other.x);
+    let dy = f64::from(self.y - other.y);
+
+    #[allow(clippy::cast_possible_truncation)]
+    {
+      dx.hypot(dy) as f32
+    }
+  }
+
+  /// Gets the Manhattan distance (i.e. sum of the horizontal and vertical
+  /// distances) between this point and another point.
+  #[must_use]
+  pub fn manhattan_distance(&self, other: &Point) -> i32 {
+    (self.x - other.x).abs() + (self.y - other.y).abs()
+  }
+
+  /// Gets the closest point that lies within the given rect.
+  ///
+  /// The right and bottom edges of the rect are exclusive (same as
+  /// `Rect::contains_point`), so the clamped point is at most one pixel
+  /// inside of them.
+  #[must_use]
+  pub fn clamp(&self, within: &Rect) -> Point {
+    Point {
+      x: self
+        .x
+        .clamp(within.left, (within.right - 1).max(within.left)),
+      y: self
+        .y
+        .clamp(within.top, (within.bottom - 1).max(within.top)),
+    }
+  }
+}
+
+impl Add for Point {
+  type Output = Point;
+
+  fn add(self, other: Point) -> Self::Output {
+    Point {
+      x: self.x + other.x,
+      y: self.y + other.y,
+    }
+  }
+}
+
+impl Sub for Point {
+  type Output = Point;
+
+  fn sub(self, other: Point) -> Self::Output {
+    Point {
+      x: self.x - other.x,
+      y: self.y - other.y,
+    }
+  }
+}
+
+impl From<(i32, i32)> for Point {
+  fn from((x, y): (i32, i32)) -> Self {
+    Point { x, y }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  #[allow(clippy::float_cmp)]
+  fn test_distance_to_same_point() {
+    let point = Point { x: -100, y: 250 };
+
+    assert_eq!(point.distance_to(&point.clone()), 0.0);
+    assert_eq!(point.manhattan_distance(&point.clone()), 0);
+  }
+
+  #[test]
+  #[allow(clippy::float_cmp)]
+  fn test_distance_to_right_triangle() {
+    let point = Point { x: 10, y: 20 };
+    let other = Point { x: 13, y: 16 };
+
+    assert_eq!(point.distance_to(&other), 5.0);
+    assert_eq!(other.distance_to(&point), 5.0);
+    assert_eq!(point.manhattan_distance(&other), 7);
+  }
+
+  #[test]
+  fn test_add_and_sub() {
+    let point = Point { x: 100, y: -20 };
+    let offset = Point { x: -30, y: 50 };
+
+    assert_eq!(point.clone() + offset.clone(), Point { x: 70, y: 30 });
+    assert_eq!(point.clone() - offset.clone(), Point { x: 130, y: -70 });
+    assert_eq!(point.clone() + offset.clone() - offset, point);
+  }
+
+  #[test]
+  fn test_from_tuple() {
+    let point: Point = (-5, 12).into();
+
+    assert_eq!(point, Point { x: -5, y: 12 });
+    assert_eq!(Point::from((0, 0)), Point { x: 0, y: 0 });
+  }
+
+  #[test]
+  fn test_clamp_within_rect() {
+    let rect = Rect::from_xy(100, 100, 800, 600);
+
+    // Points outside the rect are snapped to its nearest edge.
+    assert_eq!(
+      Point::from((0, 300)).clamp(&rect),
+      Point::from((100, 300))
+    );
+    assert_eq!(
+      Point::from((2000, 2000)).clamp(&rect),
+      Point::from((899, 699))
+    );
+    assert!(rect.contains_point(&Point::from((2000, 2000)).clamp(&rect)));
+
+    // Points inside the rect are unchanged.
+    assert_eq!(
+      Point::from((500, 400)).clamp(&rect),
+      Point::from((500, 400))
+    );
+  }
+}
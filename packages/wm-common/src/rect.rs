@@ -1,8 +1,14 @@
-use serde::{Deserialize, Serialize};
+use std::{cmp::Reverse, str::FromStr};
 
-use super::{Direction, LengthValue, Point, RectDelta};
+use anyhow::{bail, Context};
+use serde::{Deserialize, Deserializer, Serialize};
 
-#[derive(Debug, Deserialize, Clone, Serialize, Eq, PartialEq)]
+use super::{
+  Anchor, ClampMode, Direction, DropRegion, LengthValue, OrdinalDirection,
+  Point, RectDelta, TilingDirection,
+};
+
+#[derive(Debug, Clone, Serialize, Eq, Hash, PartialEq)]
 pub struct Rect {
   /// X-coordinate of the left edge of the rectangle.
   pub left: i32,
@@ -61,17 +67,136 @@ impl Rect {
     self.bottom - self.top
   }
 
+  /// Returns a copy of the rect with the given width. The top-left
+  /// corner stays in place.
+  #[must_use]
+  pub fn with_width(&self, width: i32) -> Rect {
+    Self::from_xy(self.x(), self.y(), width, self.height())
+  }
+
+  /// Returns a copy of the rect with the given height. The top-left
+  /// corner stays in place.
+  #[must_use]
+  pub fn with_height(&self, height: i32) -> Rect {
+    Self::from_xy(self.x(), self.y(), self.width(), height)
+  }
+
+  /// Returns a copy of the rect moved to the given x-coordinate, without
+  /// resizing it.
+  #[must_use]
+  pub fn with_x(&self, x: i32) -> Rect {
+    Self::from_xy(x, self.y(), self.width(), self.height())
+  }
+
+  /// Returns a copy of the rect moved to the given y-coordinate, without
+  /// resizing it.
+  #[must_use]
+  pub fn with_y(&self, y: i32) -> Rect {
+    Self::from_xy(self.x(), y, self.width(), self.height())
+  }
+
+  /// Gets the area of the rect. Rects with a negative width or height
+  /// have an area of 0.
+  ///
+  /// Uses `i64` to avoid overflowing on large rects.
+  #[must_use]
+  pub fn area(&self) -> i64 {
+    i64::from(self.width().max(0)) * i64::from(self.height().max(0))
+  }
+
+  /// Whether the rect has no area (i.e. its width or height is zero or
+  /// negative).
+  #[must_use]
+  pub fn is_empty(&self) -> bool {
+    self.width() <= 0 || self.height() <= 0
+  }
+
+  /// Returns a copy of the rect with its edges swapped where needed, so
+  /// that the width and height are non-negative.
+  #[must_use]
+  pub fn normalized(&self) -> Rect {
+    Self::from_ltrb(
+      self.left.min(self.right),
+      self.top.min(self.bottom),
+      self.left.max(self.right),
+      self.top.max(self.bottom),
+    )
+  }
+
+  /// Gets the ratio of width to height.
+  ///
+  /// Returns `0.0` if the rect has no height.
+  #[must_use]
+  pub fn aspect_ratio(&self) -> f32 {
+    if self.height() <= 0 {
+      return 0.0;
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    {
+      self.width() as f32 / self.height() as f32
+    }
+  }
+
+  /// Resizes the rect while preserving its aspect ratio. The missing
+  /// dimension is derived from the current ratio.
+  ///
+  /// If both a width and height are given, the width is honored and the
+  /// height is derived from it. The top-left corner stays in place.
+  #[must_use]
+  pub fn resize_keeping_aspect(
+    &self,
+    target_width: Option<i32>,
+    target_height: Option<i32>,
+  ) -> Rect {
+    let aspect_ratio = self.aspect_ratio();
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    let (width, height) = match (target_width, target_height) {
+      (None, None) => return self.clone(),
+      // Ratio can't be derived for a degenerate rect, so resize the given
+      // dimension only.
+      _ if aspect_ratio == 0.0 => (
+        target_width.unwrap_or(self.width()),
+        target_height.unwrap_or(self.height()),
+      ),
+      (Some(width), _) => {
+        (width, (width as f32 / aspect_ratio).round() as i32)
+      }
+      (None, Some(height)) => {
+        ((height as f32 * aspect_ratio).round() as i32, height)
+      }
+    };
+
+    Self::from_xy(self.x(), self.y(), width, height)
+  }
+
   #[must_use]
   pub fn translate_to_coordinates(&self, x: i32, y: i32) -> Self {
     Self::from_xy(x, y, self.width(), self.height())
   }
 
+  /// Moves the rect to the center of the outer rect. The size is
+  /// unchanged.
+  ///
+  /// When the leftover space can't be split evenly, the extra pixel is
+  /// always placed after the rect (i.e. on its right or bottom side).
   #[must_use]
   pub fn translate_to_center(&self, outer_rect: &Rect) -> Self {
     Self::translate_to_coordinates(
       self,
-      outer_rect.left + (outer_rect.width() / 2) - (self.width() / 2),
-      outer_rect.top + (outer_rect.height() / 2) - (self.height() / 2),
+      outer_rect.left + (outer_rect.width() - self.width()).div_euclid(2),
+      outer_rect.top + (outer_rect.height() - self.height()).div_euclid(2),
+    )
+  }
+
+  /// Moves the rect so that its center is at the given point. The size
+  /// is unchanged.
+  #[must_use]
+  pub fn center_on_point(&self, point: &Point) -> Self {
+    self.translate_to_coordinates(
+      point.x - (self.width() / 2),
+      point.y - (self.height() / 2),
     )
   }
 
@@ -96,220 +221,1536 @@ impl Rect {
     )
   }
 
-  /// Returns a new `Rect` that is clamped within the bounds of the given
-  /// outer rectangle. Attempts to preserve the width and height of the
-  /// original rectangle.
+  /// Translates the rect diagonally, by moving it the given distance
+  /// along both the horizontal and vertical component of the direction.
   #[must_use]
-  pub fn clamp(&self, outer_rect: &Rect) -> Self {
-    Self::from_xy(
-      self.left.max(outer_rect.left),
-      self.top.max(outer_rect.top),
-      self.width().min(outer_rect.width()),
-      self.height().min(outer_rect.height()),
+  pub fn translate_in_ordinal_direction(
+    &self,
+    direction: &OrdinalDirection,
+    distance: i32,
+  ) -> Rect {
+    let (horizontal, vertical) = direction.components();
+
+    self
+      .translate_in_direction(&horizontal, distance)
+      .translate_in_direction(&vertical, distance)
+  }
+
+  /// Moves the rect into a corner of the outer rect, keeping its size.
+  #[must_use]
+  pub fn snap_to_corner(
+    &self,
+    corner: &OrdinalDirection,
+    outer_rect: &Rect,
+  ) -> Rect {
+    let (horizontal, vertical) = corner.components();
+
+    let distance = |direction: &Direction| match direction {
+      Direction::Left => self.left - outer_rect.left,
+      Direction::Right => outer_rect.right - self.right,
+      Direction::Up => self.top - outer_rect.top,
+      Direction::Down => outer_rect.bottom - self.bottom,
+    };
+
+    self
+      .translate_in_direction(&horizontal, distance(&horizontal))
+      .translate_in_direction(&vertical, distance(&vertical))
+  }
+
+  /// Same as `translate_in_direction`, but with a distance that can be
+  /// relative. Percentages are resolved against the width of the
+  /// reference rect for horizontal moves, and its height for vertical
+  /// moves.
+  #[must_use]
+  pub fn translate_in_direction_by(
+    &self,
+    direction: &Direction,
+    amount: &LengthValue,
+    reference: &Rect,
+  ) -> Rect {
+    let reference_length = match direction {
+      Direction::Left | Direction::Right => reference.width(),
+      Direction::Up | Direction::Down => reference.height(),
+    };
+
+    self.translate_in_direction(
+      direction,
+      amount.to_px(reference_length, None),
     )
   }
 
-  /// Returns a new `Rect` that is strictly constrained within the bounds
-  /// of the given outer rectangle. If the window would extend beyond the
-  /// bounds, it is repositioned to fit entirely within the outer rectangle.
-  /// Preserves the original width and height when possible.
+  /// Converts the rect to coordinates relative to the top-left corner of
+  /// the given monitor rect.
   #[must_use]
-  pub fn clamp_within_bounds(&self, outer_rect: &Rect) -> Self {
-    let clamped_width = self.width().min(outer_rect.width());
-    let clamped_height = self.height().min(outer_rect.height());
-    
-    let mut x = self.x();
-    let mut y = self.y();
-    
-    // Ensure window doesn't overflow right edge
-    if x + clamped_width > outer_rect.right {
-      x = outer_rect.right - clamped_width;
-    }
-    
-    // Ensure window doesn't overflow bottom edge  
-    if y + clamped_height > outer_rect.bottom {
-      y = outer_rect.bottom - clamped_height;
-    }
-    
-    // Ensure window doesn't underflow left/top edges
-    x = x.max(outer_rect.left);
-    y = y.max(outer_rect.top);
-    
-    Self::from_xy(x, y, clamped_width, clamped_height)
+  pub fn to_monitor_relative(&self, monitor_rect: &Rect) -> Rect {
+    self.translate_to_coordinates(
+      self.x() - monitor_rect.x(),
+      self.y() - monitor_rect.y(),
+    )
   }
 
+  /// Converts the rect from coordinates relative to the given monitor
+  /// rect back to absolute coordinates.
   #[must_use]
-  pub fn clamp_size(&self, width: i32, height: i32) -> Self {
-    Self::from_xy(
-      self.x(),
-      self.y(),
-      self.width().min(width),
-      self.height().min(height),
+  pub fn from_monitor_relative(&self, monitor_rect: &Rect) -> Rect {
+    self.translate_to_coordinates(
+      self.x() + monitor_rect.x(),
+      self.y() + monitor_rect.y(),
     )
   }
 
+  /// Reflects the rect across the vertical centerline of the given rect,
+  /// such that its left and right edges swap sides.
   #[must_use]
-  pub fn center_point(&self) -> Point {
-    Point {
-      x: self.left + (self.width() / 2),
-      y: self.top + (self.height() / 2),
-    }
+  pub fn mirror_horizontal(&self, within: &Rect) -> Rect {
+    Rect::from_ltrb(
+      within.left + within.right - self.right,
+      self.top,
+      within.left + within.right - self.left,
+      self.bottom,
+    )
   }
 
-  /// Gets the delta between this rect and another rect.
+  /// Reflects the rect across the horizontal centerline of the given
+  /// rect, such that its top and bottom edges swap sides.
   #[must_use]
-  pub fn delta(&self, other: &Rect) -> RectDelta {
-    RectDelta {
-      left: LengthValue::from_px(other.left - self.left),
-      top: LengthValue::from_px(other.top - self.top),
-      right: LengthValue::from_px(self.right - other.right),
-      bottom: LengthValue::from_px(self.bottom - other.bottom),
-    }
+  pub fn mirror_vertical(&self, within: &Rect) -> Rect {
+    Rect::from_ltrb(
+      self.left,
+      within.top + within.bottom - self.bottom,
+      self.right,
+      within.top + within.bottom - self.top,
+    )
   }
 
+  /// Gets the index of the nearest rect that lies strictly in the given
+  /// direction from this rect.
+  ///
+  /// Rects that overlap this rect on the perpendicular axis are preferred
+  /// over ones that don't, and the longest overlap wins. Remaining ties
+  /// are broken by the gap between the rects along the direction, then by
+  /// the offset between their centers on the perpendicular axis.
   #[must_use]
-  pub fn apply_delta(
+  pub fn in_direction_from(
     &self,
-    delta: &RectDelta,
-    scale_factor: Option<f32>,
-  ) -> Self {
-    Self::from_ltrb(
-      self.left - delta.left.to_px(self.width(), scale_factor),
-      self.top - delta.top.to_px(self.height(), scale_factor),
-      self.right + delta.right.to_px(self.width(), scale_factor),
-      self.bottom + delta.bottom.to_px(self.height(), scale_factor),
-    )
+    direction: &Direction,
+    others: &[Rect],
+  ) -> Option<usize> {
+    let center = self.center_point();
+
+    others
+      .iter()
+      .enumerate()
+      .filter_map(|(index, other)| {
+        let other_center = other.center_point();
+
+        let (gap, overlap_length, center_offset) = match direction {
+          Direction::Up => (
+            self.top - other.bottom,
+            self.overlap_length_x(other),
+            (center.x - other_center.x).abs(),
+          ),
+          Direction::Down => (
+            other.top - self.bottom,
+            self.overlap_length_x(other),
+            (center.x - other_center.x).abs(),
+          ),
+          Direction::Left => (
+            self.left - other.right,
+            self.overlap_length_y(other),
+            (center.y - other_center.y).abs(),
+          ),
+          Direction::Right => (
+            other.left - self.right,
+            self.overlap_length_y(other),
+            (center.y - other_center.y).abs(),
+          ),
+        };
+
+        // Ignore rects that aren't entirely past the edge in the given
+        // direction.
+        (gap >= 0).then_some((
+          index,
+          (
+            overlap_length == 0,
+            Reverse(overlap_length),
+            gap,
+            center_offset,
+          ),
+        ))
+      })
+      .min_by_key(|(_, sort_key)| *sort_key)
+      .map(|(index, _)| index)
   }
 
+  /// Gets the index of the rect to wrap around to when there's no rect in
+  /// the given direction (i.e. the farthest rect in the opposite
+  /// direction).
+  ///
+  /// Rects are preferred by their perpendicular overlap in the same way
+  /// as `in_direction_from`. Remaining ties are broken by the gap between
+  /// the rects (farthest first), then by the offset between their centers
+  /// on the perpendicular axis.
   #[must_use]
-  pub fn apply_inverse_delta(
+  pub fn wrapped_in_direction_from(
     &self,
-    delta: &RectDelta,
-    scale_factor: Option<f32>,
-  ) -> Self {
+    direction: &Direction,
+    others: &[Rect],
+  ) -> Option<usize> {
+    let center = self.center_point();
+
+    others
+      .iter()
+      .enumerate()
+      .filter_map(|(index, other)| {
+        let other_center = other.center_point();
+
+        let (gap, overlap_length, center_offset) = match direction {
+          Direction::Up => (
+            other.top - self.bottom,
+            self.overlap_length_x(other),
+            (center.x - other_center.x).abs(),
+          ),
+          Direction::Down => (
+            self.top - other.bottom,
+            self.overlap_length_x(other),
+            (center.x - other_center.x).abs(),
+          ),
+          Direction::Left => (
+            other.left - self.right,
+            self.overlap_length_y(other),
+            (center.y - other_center.y).abs(),
+          ),
+          Direction::Right => (
+            self.left - other.right,
+            self.overlap_length_y(other),
+            (center.y - other_center.y).abs(),
+          ),
+        };
+
+        // Only consider rects that are entirely past the opposite edge.
+        (gap >= 0).then_some((
+          index,
+          (
+            overlap_length == 0,
+            Reverse(overlap_length),
+            Reverse(gap),
+            center_offset,
+          ),
+        ))
+      })
+      .min_by_key(|(_, sort_key)| *sort_key)
+      .map(|(index, _)| index)
+  }
+
+  /// Scales the rect by the given factor about the `origin` point.
+  ///
+  /// Each edge is scaled and rounded independently, so that scaling by a
+  /// factor and then by its inverse yields the original rect.
+  #[must_use]
+  pub fn scale(&self, factor: f32, origin: &Point) -> Rect {
+    let factor = f64::from(factor);
+
+    #[allow(clippy::cast_possible_truncation)]
+    let scale_coord = |coord: i32, origin: i32| {
+      origin + (f64::from(coord - origin) * factor).round() as i32
+    };
+
     Self::from_ltrb(
-      self.left + delta.left.to_px(self.width(), scale_factor),
-      self.top + delta.top.to_px(self.height(), scale_factor),
-      self.right - delta.right.to_px(self.width(), scale_factor),
-      self.bottom - delta.bottom.to_px(self.height(), scale_factor),
+      scale_coord(self.left, origin.x),
+      scale_coord(self.top, origin.y),
+      scale_coord(self.right, origin.x),
+      scale_coord(self.bottom, origin.y),
     )
   }
 
-  // Gets whether the x-coordinate overlaps with the x-coordinate of the
-  // other rect.
+  /// Scales the rect by the given factor while keeping it centered on its
+  /// current center point.
   #[must_use]
-  pub fn has_overlap_x(&self, other: &Rect) -> bool {
-    !(self.x() + self.width() <= other.x()
-      || other.x() + other.width() <= self.x())
+  pub fn scale_about_center(&self, factor: f32) -> Rect {
+    self.scale(factor, &self.center_point())
   }
 
-  // Gets whether the y-coordinate overlaps with the y-coordinate of the
-  // other rect.
+  /// Gets the largest rect with the given aspect ratio (width / height)
+  /// that fits inside the outer rect, aligned to the given anchor.
+  ///
+  /// Returns the outer rect unchanged if the ratio isn't positive.
   #[must_use]
-  pub fn has_overlap_y(&self, other: &Rect) -> bool {
-    !(self.y() + self.height() <= other.y()
-      || other.y() + other.height() <= self.y())
+  pub fn fit_aspect_within(
+    outer: &Rect,
+    ratio: f32,
+    anchor: Anchor,
+  ) -> Rect {
+    if ratio <= 0.0 || outer.is_empty() {
+      return outer.clone();
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    let (width, height) = if ratio > outer.aspect_ratio() {
+      (
+        outer.width(),
+        (f64::from(outer.width()) / f64::from(ratio)).round() as i32,
+      )
+    } else {
+      (
+        (f64::from(outer.height()) * f64::from(ratio)).round() as i32,
+        outer.height(),
+      )
+    };
+
+    #[allow(clippy::cast_possible_truncation)]
+    let align = |free_space: i32, fraction: f32| {
+      (f64::from(free_space) * f64::from(fraction)).round() as i32
+    };
+
+    Self::from_xy(
+      outer.x() + align(outer.width() - width, anchor.x_fraction()),
+      outer.y() + align(outer.height() - height, anchor.y_fraction()),
+      width,
+      height,
+    )
   }
 
+  /// Whether the rect is taller than it is wide.
   #[must_use]
-  pub fn contains_point(&self, point: &Point) -> bool {
-    let is_in_x = point.x >= self.left && point.x <= self.right;
-    let is_in_y = point.y >= self.top && point.y <= self.bottom;
-    is_in_x && is_in_y
+  pub fn is_portrait(&self) -> bool {
+    self.height() > self.width()
   }
 
+  /// Gets the half or quarter of the rect at the given anchor. For
+  /// example, `Anchor::TopLeft` gives the top-left quarter and
+  /// `Anchor::Right` gives the right half. `Anchor::Center` gives the
+  /// whole rect.
+  ///
+  /// `horizontal_gap` is left between side-by-side halves, and
+  /// `vertical_gap` between stacked halves.
   #[must_use]
-  pub fn distance_to_point(&self, point: &Point) -> f32 {
-    let dx = (self.x() - point.x)
-      .abs()
-      .max((self.x() + self.width() - point.x).abs());
+  pub fn quadrant(
+    &self,
+    anchor: Anchor,
+    horizontal_gap: i32,
+    vertical_gap: i32,
+  ) -> Rect {
+    let column = match anchor {
+      Anchor::TopLeft | Anchor::Left | Anchor::BottomLeft => Some(0),
+      Anchor::TopRight | Anchor::Right | Anchor::BottomRight => Some(1),
+      Anchor::Top | Anchor::Center | Anchor::Bottom => None,
+    };
 
-    let dy = (self.y() - point.y)
-      .abs()
-      .max((self.y() + self.height() - point.y).abs());
+    let row = match anchor {
+      Anchor::TopLeft | Anchor::Top | Anchor::TopRight => Some(0),
+      Anchor::BottomLeft | Anchor::Bottom | Anchor::BottomRight => Some(1),
+      Anchor::Left | Anchor::Center | Anchor::Right => None,
+    };
 
-    #[allow(clippy::cast_precision_loss)]
-    ((dx * dx + dy * dy) as f32).sqrt()
-  }
-}
+    let (left, right) = column.map_or((self.left, self.right), |index| {
+      let column =
+        &self.split_n(2, &Direction::Right, horizontal_gap)[index];
+      (column.left, column.right)
+    });
 
-#[cfg(test)]
-mod tests {
-  use super::*;
+    let (top, bottom) = row.map_or((self.top, self.bottom), |index| {
+      let row = &self.split_n(2, &Direction::Down, vertical_gap)[index];
+      (row.top, row.bottom)
+    });
 
-  #[test]
-  fn test_clamp_within_bounds_no_overflow() {
-    let window_rect = Rect::from_xy(100, 100, 200, 150);
-    let monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
-    
-    let result = window_rect.clamp_within_bounds(&monitor_rect);
-    
-    // Window should remain unchanged as it fits within bounds
-    assert_eq!(result, window_rect);
+    Self::from_ltrb(left, top, right, bottom)
   }
 
-  #[test]
-  fn test_clamp_within_bounds_right_overflow() {
-    let window_rect = Rect::from_xy(1800, 100, 200, 150);
-    let monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
-    
-    let result = window_rect.clamp_within_bounds(&monitor_rect);
-    
-    // Window should be repositioned to fit entirely within monitor
-    assert_eq!(result, Rect::from_xy(1720, 100, 200, 150));
-  }
+  /// Linearly interpolates each edge between this rect and the target,
+  /// where `t` of 0.0 gives this rect and 1.0 gives the target. `t` is
+  /// clamped to the range 0.0 to 1.0.
+  ///
+  /// Combine with `Easing::sample` for non-linear transitions.
+  #[must_use]
+  pub fn lerp(&self, target: &Rect, t: f32) -> Rect {
+    let t = f64::from(t.clamp(0.0, 1.0));
 
-  #[test]
-  fn test_clamp_within_bounds_bottom_overflow() {
-    let window_rect = Rect::from_xy(100, 1000, 200, 150);
-    let monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
-    
-    let result = window_rect.clamp_within_bounds(&monitor_rect);
-    
-    // Window should be repositioned to fit entirely within monitor
-    assert_eq!(result, Rect::from_xy(100, 930, 200, 150));
-  }
+    #[allow(clippy::cast_possible_truncation)]
+    let lerp = |from: i32, to: i32| {
+      (f64::from(from) + f64::from(to - from) * t).round() as i32
+    };
 
-  #[test]
-  fn test_clamp_within_bounds_mixed_resolution_scenario() {
-    // Simulate Task Manager spilling from 4K monitor to smaller monitor
-    // 4K monitor: 0,0 to 3840,2160
-    // 1920x1200 monitor: 3840,0 to 5760,1200
-    let window_rect = Rect::from_xy(3700, 100, 300, 400); // Window near edge of 4K monitor
-    let small_monitor_rect = Rect::from_xy(3840, 0, 1920, 1200);
-    
-    let result = window_rect.clamp_within_bounds(&small_monitor_rect);
-    
-    // Should be fully contained within the small monitor
-    assert_eq!(result, Rect::from_xy(3840, 100, 300, 400));
-    assert!(result.right <= small_monitor_rect.right);
-    assert!(result.bottom <= small_monitor_rect.bottom);
+    Self::from_ltrb(
+      lerp(self.left, target.left),
+      lerp(self.top, target.top),
+      lerp(self.right, target.right),
+      lerp(self.bottom, target.bottom),
+    )
   }
 
-  #[test]
-  fn test_clamp_within_bounds_oversized_window() {
-    let window_rect = Rect::from_xy(100, 100, 2000, 1500);
-    let monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
-    
-    let result = window_rect.clamp_within_bounds(&monitor_rect);
-    
-    // Window should be resized and positioned at origin
-    assert_eq!(result, Rect::from_xy(0, 0, 1920, 1080));
-  }
+  /// Splits the rect into `count` equally sized cells along the axis of
+  /// the given direction, with `gap` pixels between adjacent cells.
+  ///
+  /// Leftover pixels are given one each to the first cells, so that cell
+  /// lengths differ by at most 1px.
+  #[must_use]
+  pub fn split_n(
+    &self,
+    count: usize,
+    direction: &Direction,
+    gap: i32,
+  ) -> Vec<Rect> {
+    let count = match i32::try_from(count) {
+      Ok(count) if count > 0 => count,
+      _ => return Vec::new(),
+    };
 
-  #[test]
-  fn test_clamp_within_bounds_negative_coordinates() {
-    let window_rect = Rect::from_xy(-100, -50, 200, 150);
-    let monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
-    
-    let result = window_rect.clamp_within_bounds(&monitor_rect);
-    
-    // Window should be repositioned to monitor origin
-    assert_eq!(result, Rect::from_xy(0, 0, 200, 150));
+    let tiling_direction = TilingDirection::from_direction(direction);
+
+    let length = match tiling_direction {
+      TilingDirection::Horizontal => self.width(),
+      TilingDirection::Vertical => self.height(),
+    };
+
+    let available_length = length - gap * (count - 1);
+    let base_length = available_length / count;
+    let leftover = available_length % count;
+
+    let mut offset = 0;
+
+    (0..count)
+      .map(|index| {
+        let length = base_length + i32::from(index < leftover);
+
+        let rect = match tiling_direction {
+          TilingDirection::Horizontal => Self::from_xy(
+            self.x() + offset,
+            self.y(),
+            length,
+            self.height(),
+          ),
+          TilingDirection::Vertical => Self::from_xy(
+            self.x(),
+            self.y() + offset,
+            self.width(),
+            length,
+          ),
+        };
+
+        offset += length + gap;
+        rect
+      })
+      .collect()
   }
 
-  #[test]
-  fn test_clamp_within_bounds_multi_monitor_boundary() {
+  /// Splits the rect in two along the axis of the given direction, with
+  /// `gap` pixels between the halves. The first (left or top) half gets
+  /// `ratio` of the available length.
+  ///
+  /// The ratio is clamped between 0.0 and 1.0, and the gap to the length
+  /// of the rect, such that both halves always lie within the rect.
+  #[must_use]
+  pub fn split_at_ratio(
+    &self,
+    direction: &Direction,
+    ratio: f32,
+    gap: i32,
+  ) -> (Rect, Rect) {
+    let tiling_direction = TilingDirection::from_direction(direction);
+
+    let length = match tiling_direction {
+      TilingDirection::Horizontal => self.width(),
+      TilingDirection::Vertical => self.height(),
+    };
+
+    let gap = gap.clamp(0, length.max(0));
+    let ratio = if ratio.is_nan() {
+      0.5
+    } else {
+      ratio.clamp(0., 1.)
+    };
+
+    #[allow(clippy::cast_possible_truncation)]
+    let first_length =
+      (f64::from(length - gap) * f64::from(ratio)).round() as i32;
+
+    match tiling_direction {
+      TilingDirection::Horizontal => (
+        Self::from_ltrb(
+          self.left,
+          self.top,
+          self.left + first_length,
+          self.bottom,
+        ),
+        Self::from_ltrb(
+          self.left + first_length + gap,
+          self.top,
+          self.right,
+          self.bottom,
+        ),
+      ),
+      TilingDirection::Vertical => (
+        Self::from_ltrb(
+          self.left,
+          self.top,
+          self.right,
+          self.top + first_length,
+        ),
+        Self::from_ltrb(
+          self.left,
+          self.top + first_length + gap,
+          self.right,
+          self.bottom,
+        ),
+      ),
+    }
+  }
+
+  /// Splits the rect into `count` rects by binary space partitioning.
+  ///
+  /// The rect is halved along its longer axis, with the first half
+  /// holding `count / 2` rects and the second half holding the rest. Both
+  /// halves are then split recursively in the same way.
+  ///
+  /// `horizontal_gap` is inserted between side-by-side rects, and
+  /// `vertical_gap` between stacked rects.
+  #[must_use]
+  pub fn tile_bsp(
+    &self,
+    count: usize,
+    horizontal_gap: i32,
+    vertical_gap: i32,
+  ) -> Vec<Rect> {
+    if count <= 1 {
+      return vec![self.clone(); count];
+    }
+
+    let (tiling_direction, gap) = if self.width() >= self.height() {
+      (TilingDirection::Horizontal, horizontal_gap)
+    } else {
+      (TilingDirection::Vertical, vertical_gap)
+    };
+
+    let halves = self.split_weighted(&[0.5, 0.5], &tiling_direction, gap);
+    let first_count = count / 2;
+
+    let mut rects =
+      halves[0].tile_bsp(first_count, horizontal_gap, vertical_gap);
+
+    rects.extend(halves[1].tile_bsp(
+      count - first_count,
+      horizontal_gap,
+      vertical_gap,
+    ));
+
+    rects
+  }
+
+  /// Splits the rect into cells along the given tiling direction, where
+  /// each cell's share of the available length is given by its weight.
+  /// `gap` pixels are inserted between adjacent cells.
+  ///
+  /// Weights are normalized by their sum, so the cells always fill the
+  /// rect exactly even if the weights don't add up to 1.0. Negative
+  /// weights are treated as 0, and the rect is split evenly if no weight
+  /// is positive.
+  ///
+  /// Leftover pixels from rounding are distributed to the cells with the
+  /// largest fractional remainders, so that cell lengths differ from their
+  /// exact values by at most 1px.
+  #[must_use]
+  pub fn split_weighted(
+    &self,
+    weights: &[f32],
+    tiling_direction: &TilingDirection,
+    gap: i32,
+  ) -> Vec<Rect> {
+    let length = match tiling_direction {
+      TilingDirection::Horizontal => self.width(),
+      TilingDirection::Vertical => self.height(),
+    };
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    let available_length = length - gap * (weights.len() as i32 - 1);
+
+    let total_weight = weights
+      .iter()
+      .map(|weight| f64::from(weight.max(0.)))
+      .sum::<f64>();
+
+    #[allow(clippy::cast_precision_loss)]
+    let exact_lengths = weights
+      .iter()
+      .map(|weight| {
+        let share = if total_weight > 0. {
+          f64::from(weight.max(0.)) / total_weight
+        } else {
+          1. / weights.len() as f64
+        };
+
+        share * f64::from(available_length)
+      })
+      .collect::<Vec<_>>();
+
+    #[allow(clippy::cast_possible_truncation)]
+    let mut lengths = exact_lengths
+      .iter()
+      .map(|exact| exact.floor() as i32)
+      .collect::<Vec<_>>();
+
+    // Hand out the pixels lost to flooring, largest remainder first.
+    #[allow(clippy::cast_possible_truncation)]
+    let leftover = exact_lengths.iter().sum::<f64>().round() as i32
+      - lengths.iter().sum::<i32>();
+
+    let mut indices_by_remainder = (0..lengths.len()).collect::<Vec<_>>();
+    indices_by_remainder.sort_by(|&a, &b| {
+      let remainder_a = exact_lengths[a] - exact_lengths[a].floor();
+      let remainder_b = exact_lengths[b] - exact_lengths[b].floor();
+      remainder_b.total_cmp(&remainder_a)
+    });
+
+    #[allow(clippy::cast_sign_loss)]
+    for &index in
+      indices_by_remainder.iter().take(leftover.max(0) as usize)
+    {
+      lengths[index] += 1;
+    }
+
+    let mut offset = 0;
+
+    lengths
+      .into_iter()
+      .map(|length| {
+        let rect = match tiling_direction {
+          TilingDirection::Horizontal => Self::from_xy(
+            self.x() + offset,
+            self.y(),
+            length,
+            self.height(),
+          ),
+          TilingDirection::Vertical => Self::from_xy(
+            self.x(),
+            self.y() + offset,
+            self.width(),
+            length,
+          ),
+        };
+
+        offset += length + gap;
+        rect
+      })
+      .collect()
+  }
+
+  /// Shrinks the rect by the given margin on all edges.
+  ///
+  /// If the margin is larger than half the width or height, the rect
+  /// collapses to zero size at its center along that axis.
+  #[must_use]
+  pub fn inset(&self, margin: i32) -> Rect {
+    self.inset_ltrb(margin, margin, margin, margin)
+  }
+
+  /// Grows the rect by the given margin on all edges.
+  #[must_use]
+  pub fn outset(&self, margin: i32) -> Rect {
+    self.inset(-margin)
+  }
+
+  /// Shrinks the rect by a separate margin on each edge. Negative margins
+  /// grow the rect instead.
+  ///
+  /// If the margins on opposing edges overlap, the rect collapses to zero
+  /// size along that axis, centered between the inset edges.
+  #[must_use]
+  pub fn inset_ltrb(
+    &self,
+    left: i32,
+    top: i32,
+    right: i32,
+    bottom: i32,
+  ) -> Rect {
+    let collapse = |start: i32, end: i32| {
+      if start > end {
+        let center = start + (end - start) / 2;
+        (center, center)
+      } else {
+        (start, end)
+      }
+    };
+
+    let (left, right) = collapse(self.left + left, self.right - right);
+    let (top, bottom) = collapse(self.top + top, self.bottom - bottom);
+
+    Self::from_ltrb(left, top, right, bottom)
+  }
+
+  /// Aligns the rect to a pixel grid of the given size. The position and
+  /// size are each rounded to the nearest multiple of `grid`, and a
+  /// non-empty rect is never snapped below one grid cell.
+  ///
+  /// Returns the rect unchanged if `grid` is not positive.
+  #[must_use]
+  pub fn snap_to_grid(&self, grid: i32) -> Rect {
+    if grid <= 0 {
+      return self.clone();
+    }
+
+    let snap = |value: i32| {
+      let remainder = value.rem_euclid(grid);
+
+      if remainder * 2 >= grid {
+        value - remainder + grid
+      } else {
+        value - remainder
+      }
+    };
+
+    let snap_length = |length: i32| {
+      if length > 0 {
+        snap(length).max(grid)
+      } else {
+        snap(length)
+      }
+    };
+
+    Self::from_xy(
+      snap(self.x()),
+      snap(self.y()),
+      snap_length(self.width()),
+      snap_length(self.height()),
+    )
+  }
+
+  /// Moves the rect from one monitor rect to another, keeping its offset
+  /// from the top-left corner and clamping it such that it lies entirely
+  /// within the target monitor.
+  #[must_use]
+  pub fn translate_between_monitors(
+    &self,
+    source_rect: &Rect,
+    target_rect: &Rect,
+  ) -> Rect {
+    self
+      .to_monitor_relative(source_rect)
+      .from_monitor_relative(target_rect)
+      .clamp_within_bounds(target_rect)
+  }
+
+  /// Maps the rect from one monitor rect to another, such that its offset
+  /// and size are kept as fractions of the monitor's size. The result is
+  /// always clamped to lie entirely within the target monitor.
+  ///
+  /// If the source monitor rect is empty, there's no size to take
+  /// fractions of, so the rect is clamped without being remapped.
+  #[must_use]
+  pub fn proportional_remap(&self, from: &Rect, to: &Rect) -> Rect {
+    if from.width() <= 0 || from.height() <= 0 {
+      return self.clamp_within_bounds(to);
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    let remap_coord = |coord: i32, from: (i32, i32), to: (i32, i32)| {
+      let fraction = f64::from(coord - from.0) / f64::from(from.1);
+      to.0 + (fraction * f64::from(to.1)).round() as i32
+    };
+
+    let from_x = (from.x(), from.width());
+    let from_y = (from.y(), from.height());
+    let to_x = (to.x(), to.width());
+    let to_y = (to.y(), to.height());
+
+    Self::from_ltrb(
+      remap_coord(self.left, from_x, to_x),
+      remap_coord(self.top, from_y, to_y),
+      remap_coord(self.right, from_x, to_x),
+      remap_coord(self.bottom, from_y, to_y),
+    )
+    .clamp_within_bounds(to)
+  }
+
+  /// Returns a new `Rect` that is clamped within the bounds of the given
+  /// outer rectangle. Attempts to preserve the width and height of the
+  /// original rectangle.
+  #[must_use]
+  pub fn clamp(&self, outer_rect: &Rect) -> Self {
+    Self::from_xy(
+      self.left.max(outer_rect.left),
+      self.top.max(outer_rect.top),
+      self.width().min(outer_rect.width()),
+      self.height().min(outer_rect.height()),
+    )
+  }
+
+  /// Returns a new `Rect` that is strictly constrained within the bounds
+  /// of the given outer rectangle. If the window would extend beyond the
+  /// bounds, it is repositioned to fit entirely within the outer rectangle.
+  /// Preserves the original width and height when possible.
+  #[must_use]
+  pub fn clamp_within_bounds(&self, outer_rect: &Rect) -> Self {
+    self.clamp_within_bounds_with_mode(outer_rect, ClampMode::ResizeToFit)
+  }
+
+  /// Same as `clamp_within_bounds`, but with control over whether rects
+  /// larger than the outer rect are shrunk to fit.
+  #[must_use]
+  pub fn clamp_within_bounds_with_mode(
+    &self,
+    outer_rect: &Rect,
+    mode: ClampMode,
+  ) -> Self {
+    let (width, height) = match mode {
+      ClampMode::ResizeToFit => (
+        self.width().min(outer_rect.width()),
+        self.height().min(outer_rect.height()),
+      ),
+      ClampMode::RepositionOnly => (self.width(), self.height()),
+    };
+
+    let mut x = self.x();
+    let mut y = self.y();
+
+    // Ensure window doesn't overflow right edge
+    if x + width > outer_rect.right {
+      x = outer_rect.right - width;
+    }
+
+    // Ensure window doesn't overflow bottom edge
+    if y + height > outer_rect.bottom {
+      y = outer_rect.bottom - height;
+    }
+
+    // Ensure window doesn't underflow left/top edges
+    x = x.max(outer_rect.left);
+    y = y.max(outer_rect.top);
+
+    Self::from_xy(x, y, width, height)
+  }
+
+  /// Same as `clamp_within_bounds`, but also returns the per-edge delta
+  /// between the original and the clamped rect. Edges that weren't moved
+  /// have a delta of zero.
+  #[must_use]
+  pub fn clamp_within_bounds_reporting(
+    &self,
+    outer_rect: &Rect,
+  ) -> (Self, RectDelta) {
+    let clamped_rect = self.clamp_within_bounds(outer_rect);
+    let delta = self.delta(&clamped_rect);
+
+    (clamped_rect, delta)
+  }
+
+  /// Caps the edges of the rect at the outer rect's edges, without moving
+  /// the rect.
+  ///
+  /// Only edges that were moved past the outer rect since `prev_rect` are
+  /// capped, so edges that already lay past it (e.g. for a window dragged
+  /// partially off-screen) are left in place.
+  #[must_use]
+  pub fn cap_edges_within(
+    &self,
+    prev_rect: &Rect,
+    outer_rect: &Rect,
+  ) -> Self {
+    Self::from_ltrb(
+      self.left.max(outer_rect.left.min(prev_rect.left)),
+      self.top.max(outer_rect.top.min(prev_rect.top)),
+      self.right.min(outer_rect.right.max(prev_rect.right)),
+      self.bottom.min(outer_rect.bottom.max(prev_rect.bottom)),
+    )
+  }
+
+  /// Fits the rect into the outer rect, moving it as little as possible.
+  ///
+  /// Dimensions larger than the outer rect are shrunk to the outer rect's
+  /// size, and the rect is then shifted just enough to lie within it. A
+  /// rect that already lies within the outer rect is left unchanged.
+  #[must_use]
+  pub fn fit_into(&self, outer: &Rect) -> Rect {
+    let width = self.width().min(outer.width());
+    let height = self.height().min(outer.height());
+
+    Self::from_xy(
+      self.left.clamp(outer.left, outer.right - width),
+      self.top.clamp(outer.top, outer.bottom - height),
+      width,
+      height,
+    )
+  }
+
+  #[must_use]
+  pub fn clamp_size(&self, width: i32, height: i32) -> Self {
+    Self::from_xy(
+      self.x(),
+      self.y(),
+      self.width().min(width),
+      self.height().min(height),
+    )
+  }
+
+  #[must_use]
+  pub fn center_point(&self) -> Point {
+    Point {
+      x: self.left + (self.width() / 2),
+      y: self.top + (self.height() / 2),
+    }
+  }
+
+  /// Gets the delta between this rect and another rect.
+  #[must_use]
+  pub fn delta(&self, other: &Rect) -> RectDelta {
+    RectDelta {
+      left: LengthValue::from_px(other.left - self.left),
+      top: LengthValue::from_px(other.top - self.top),
+      right: LengthValue::from_px(self.right - other.right),
+      bottom: LengthValue::from_px(self.bottom - other.bottom),
+    }
+  }
+
+  /// Grows the rect by the given delta on each edge (e.g. for adding
+  /// window borders). Negative deltas shrink the rect instead.
+  ///
+  /// The resulting width and height are never less than 1px. See
+  /// `apply_delta_with_min_size` for using a different minimum.
+  #[must_use]
+  pub fn apply_delta(
+    &self,
+    delta: &RectDelta,
+    scale_factor: Option<f32>,
+  ) -> Self {
+    self.apply_delta_with_min_size(delta, scale_factor, 1)
+  }
+
+  /// Same as `apply_delta`, but with a custom minimum width and height.
+  ///
+  /// If the delta would shrink an axis below `min_size` (or invert it),
+  /// that axis is instead set to `min_size`, centered on the rect's prior
+  /// center.
+  #[must_use]
+  pub fn apply_delta_with_min_size(
+    &self,
+    delta: &RectDelta,
+    scale_factor: Option<f32>,
+    min_size: i32,
+  ) -> Self {
+    let (delta_left, delta_top, delta_right, delta_bottom) =
+      delta.to_px_edges(self, scale_factor);
+
+    let clamp =
+      |start: i32, end: i32, prior_start: i32, prior_end: i32| {
+        if end - start < min_size {
+          let start =
+            prior_start + (prior_end - prior_start) / 2 - min_size / 2;
+          (start, start + min_size)
+        } else {
+          (start, end)
+        }
+      };
+
+    let (left, right) = clamp(
+      self.left - delta_left,
+      self.right + delta_right,
+      self.left,
+      self.right,
+    );
+
+    let (top, bottom) = clamp(
+      self.top - delta_top,
+      self.bottom + delta_bottom,
+      self.top,
+      self.bottom,
+    );
+
+    Self::from_ltrb(left, top, right, bottom)
+  }
+
+  /// Shrinks the rect by the given delta on each edge (e.g. for applying
+  /// outer gaps).
+  ///
+  /// Uses the same collapsing behavior as `inset_ltrb` if the delta is
+  /// larger than the rect.
+  #[must_use]
+  pub fn apply_inverse_delta(
+    &self,
+    delta: &RectDelta,
+    scale_factor: Option<f32>,
+  ) -> Self {
+    self.apply_inverse_delta_relative_to(delta, self, scale_factor)
+  }
+
+  /// Same as `apply_inverse_delta`, but percentages are resolved against
+  /// the size of `reference` rather than this rect. Left and right edges
+  /// are relative to its width, and top and bottom edges to its height.
+  ///
+  /// For example, outer gaps are relative to the monitor's size, even
+  /// when the rect has already been reduced by struts.
+  #[must_use]
+  pub fn apply_inverse_delta_relative_to(
+    &self,
+    delta: &RectDelta,
+    reference: &Rect,
+    scale_factor: Option<f32>,
+  ) -> Self {
+    let (left, top, right, bottom) =
+      delta.to_px_edges(reference, scale_factor);
+
+    self.inset_ltrb(left, top, right, bottom)
+  }
+
+  // Gets whether the x-coordinate overlaps with the x-coordinate of the
+  // other rect.
+  #[must_use]
+  pub fn has_overlap_x(&self, other: &Rect) -> bool {
+    !(self.x() + self.width() <= other.x()
+      || other.x() + other.width() <= self.x())
+  }
+
+  // Gets whether the y-coordinate overlaps with the y-coordinate of the
+  // other rect.
+  #[must_use]
+  pub fn has_overlap_y(&self, other: &Rect) -> bool {
+    !(self.y() + self.height() <= other.y()
+      || other.y() + other.height() <= self.y())
+  }
+
+  /// Gets the length of the overlap between this rect and another rect
+  /// on the x-axis.
+  ///
+  /// Returns 0 if they don't overlap on the x-axis.
+  #[must_use]
+  pub fn overlap_length_x(&self, other: &Rect) -> i32 {
+    (self.right.min(other.right) - self.left.max(other.left)).max(0)
+  }
+
+  /// Gets the length of the overlap between this rect and another rect
+  /// on the y-axis.
+  ///
+  /// Returns 0 if they don't overlap on the y-axis.
+  #[must_use]
+  pub fn overlap_length_y(&self, other: &Rect) -> i32 {
+    (self.bottom.min(other.bottom) - self.top.max(other.top)).max(0)
+  }
+
+  /// Gets the area of the overlapping region between this rect and
+  /// another rect.
+  ///
+  /// Returns 0 if the rects don't overlap.
+  #[must_use]
+  pub fn overlap_area(&self, other: &Rect) -> i64 {
+    i64::from(self.overlap_length_x(other))
+      * i64::from(self.overlap_length_y(other))
+  }
+
+  /// Gets the directions in which another rect lies entirely beyond this
+  /// rect's edges. Diagonal rects give both a horizontal and a vertical
+  /// direction (e.g. `[Right, Up]`).
+  ///
+  /// Rects that touch an edge count as being beyond it. Returns an empty
+  /// vector if the rects overlap.
+  #[must_use]
+  pub fn relative_position(&self, other: &Rect) -> Vec<Direction> {
+    let mut directions = Vec::new();
+
+    if !self.has_overlap_x(other) {
+      directions.push(if other.left >= self.right {
+        Direction::Right
+      } else {
+        Direction::Left
+      });
+    }
+
+    if !self.has_overlap_y(other) {
+      directions.push(if other.top >= self.bottom {
+        Direction::Down
+      } else {
+        Direction::Up
+      });
+    }
+
+    directions
+  }
+
+  /// Gets the direction of an edge that this rect shares with another
+  /// rect, such that the other rect lies directly beyond that edge.
+  ///
+  /// Edges are shared if they're within 1px of each other and the rects
+  /// overlap along the edge. Returns `None` if no edge is shared (e.g.
+  /// for diagonal neighbors).
+  #[must_use]
+  pub fn shared_edge(&self, other: &Rect) -> Option<Direction> {
+    self.shared_edge_with_tolerance(other, 1)
+  }
+
+  /// Same as `shared_edge`, but with a custom tolerance in pixels between
+  /// the edges. This can be used to find neighbors across a gap.
+  #[must_use]
+  pub fn shared_edge_with_tolerance(
+    &self,
+    other: &Rect,
+    tolerance: i32,
+  ) -> Option<Direction> {
+    let is_near =
+      |edge: i32, other_edge: i32| (edge - other_edge).abs() <= tolerance;
+
+    if self.overlap_length_y(other) > 0 {
+      if is_near(self.right, other.left) {
+        return Some(Direction::Right);
+      }
+
+      if is_near(self.left, other.right) {
+        return Some(Direction::Left);
+      }
+    }
+
+    if self.overlap_length_x(other) > 0 {
+      if is_near(self.bottom, other.top) {
+        return Some(Direction::Down);
+      }
+
+      if is_near(self.top, other.bottom) {
+        return Some(Direction::Up);
+      }
+    }
+
+    None
+  }
+
+  /// Gets the overlapping region between this rect and another rect.
+  ///
+  /// Returns `None` if the rects don't overlap, including when they only
+  /// share an edge.
+  #[must_use]
+  pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+    if !self.has_overlap_x(other) || !self.has_overlap_y(other) {
+      return None;
+    }
+
+    Some(Self::from_ltrb(
+      self.left.max(other.left),
+      self.top.max(other.top),
+      self.right.min(other.right),
+      self.bottom.min(other.bottom),
+    ))
+  }
+
+  /// Gets the index of the monitor rect that this rect overlaps the most.
+  /// If it doesn't overlap any of them, the monitor with the nearest
+  /// center point is picked instead.
+  ///
+  /// Returns `None` if `monitors` is empty.
+  #[must_use]
+  pub fn best_monitor(&self, monitors: &[Rect]) -> Option<usize> {
+    let center = self.center_point();
+
+    monitors
+      .iter()
+      .enumerate()
+      .min_by_key(|(_, monitor)| {
+        let overlap_area = self
+          .intersection(monitor)
+          .map_or(0, |overlap| overlap.area());
+
+        let monitor_center = monitor.center_point();
+        let center_distance = i64::from(center.x - monitor_center.x)
+          .pow(2)
+          + i64::from(center.y - monitor_center.y).pow(2);
+
+        (Reverse(overlap_area), center_distance)
+      })
+      .map(|(index, _)| index)
+  }
+
+  /// Moves the rect onto the monitor picked by `best_monitor`, such that
+  /// it lies entirely within the monitor's bounds.
+  ///
+  /// Returns the index of the monitor along with the moved rect, or `None`
+  /// if `monitors` is empty.
+  #[must_use]
+  pub fn move_onto_monitor(
+    &self,
+    monitors: &[Rect],
+  ) -> Option<(usize, Rect)> {
+    self
+      .best_monitor(monitors)
+      .map(|index| (index, self.clamp_within_bounds(&monitors[index])))
+  }
+
+  /// Keeps the rect within a monitor's work area after the work area
+  /// changes (e.g. when the taskbar is resized or toggles auto-hide).
+  ///
+  /// Edges that were flush with an edge of the previous work area are
+  /// moved to the same edge of the new work area, such that the rect
+  /// neither leaves a gap nor overlaps the taskbar. The result is then
+  /// clamped within the new work area.
+  #[must_use]
+  pub fn constrain_to_work_area(
+    &self,
+    prev_work_area: &Rect,
+    work_area: &Rect,
+  ) -> Self {
+    let snap = |edge: i32, prev_edge: i32, new_edge: i32| {
+      if edge == prev_edge {
+        new_edge
+      } else {
+        edge
+      }
+    };
+
+    Self::from_ltrb(
+      snap(self.left, prev_work_area.left, work_area.left),
+      snap(self.top, prev_work_area.top, work_area.top),
+      snap(self.right, prev_work_area.right, work_area.right),
+      snap(self.bottom, prev_work_area.bottom, work_area.bottom),
+    )
+    .clamp_within_bounds(work_area)
+  }
+
+  /// Keeps the rect on-screen across multiple monitors. Unlike
+  /// `clamp_within_bounds`, a rect that partially overlaps any monitor
+  /// (e.g. one straddling two monitors) is left as-is. It's only moved
+  /// when it's entirely off all monitors, in which case it's moved onto
+  /// the nearest monitor.
+  #[must_use]
+  pub fn clamp_position_to_visible(&self, monitors: &[Rect]) -> Self {
+    let is_visible = monitors
+      .iter()
+      .any(|monitor| self.intersection(monitor).is_some());
+
+    if is_visible {
+      return self.clone();
+    }
+
+    self.best_monitor(monitors).map_or_else(
+      || self.clone(),
+      |index| self.clamp_within_bounds(&monitors[index]),
+    )
+  }
+
+  /// Gets the smallest rect that encloses both this rect and another rect.
+  #[must_use]
+  pub fn union(&self, other: &Rect) -> Rect {
+    Self::from_ltrb(
+      self.left.min(other.left),
+      self.top.min(other.top),
+      self.right.max(other.right),
+      self.bottom.max(other.bottom),
+    )
+  }
+
+  /// Gets the smallest rect that encloses all of the given rects.
+  ///
+  /// Returns `None` if the slice is empty.
+  #[must_use]
+  pub fn union_all(rects: &[Rect]) -> Option<Rect> {
+    let (first, rest) = rects.split_first()?;
+
+    Some(rest.iter().fold(first.clone(), |acc, rect| acc.union(rect)))
+  }
+
+  /// Gets whether the other rect lies entirely within this rect. Edges
+  /// are inclusive.
+  #[must_use]
+  pub fn contains_rect(&self, other: &Rect) -> bool {
+    other.left >= self.left
+      && other.top >= self.top
+      && other.right <= self.right
+      && other.bottom <= self.bottom
+  }
+
+  /// Gets the fraction (0.0 to 1.0) of the other rect's area that lies
+  /// within this rect.
+  ///
+  /// Returns `0.0` if the other rect has no area.
+  #[must_use]
+  pub fn contained_area_ratio(&self, other: &Rect) -> f32 {
+    let other_area = other.area();
+
+    if other_area == 0 {
+      return 0.0;
+    }
+
+    let contained_area =
+      self.intersection(other).map_or(0, |rect| rect.area());
+
+    #[allow(clippy::cast_precision_loss)]
+    {
+      contained_area as f32 / other_area as f32
+    }
+  }
+
+  /// Gets whether the given point lies within the rect.
+  ///
+  /// The right and bottom edges are exclusive, so that a point on the
+  /// seam between two adjacent rects (e.g. monitors) belongs to exactly
+  /// one of them.
+  #[must_use]
+  pub fn contains_point(&self, point: &Point) -> bool {
+    let is_in_x = point.x >= self.left && point.x < self.right;
+    let is_in_y = point.y >= self.top && point.y < self.bottom;
+    is_in_x && is_in_y
+  }
+
+  /// Gets whether the given point lies at least `margin` pixels inside
+  /// the rect on every side.
+  ///
+  /// Useful for adding hysteresis at the boundary between adjacent rects.
+  #[must_use]
+  pub fn contains_point_with_margin(
+    &self,
+    point: &Point,
+    margin: i32,
+  ) -> bool {
+    self.inset(margin).contains_point(point)
+  }
+
+  /// Gets whether the given point lies within the rect, including its
+  /// right and bottom edges.
+  #[must_use]
+  pub fn contains_point_inclusive(&self, point: &Point) -> bool {
+    let is_in_x = point.x >= self.left && point.x <= self.right;
+    let is_in_y = point.y >= self.top && point.y <= self.bottom;
+    is_in_x && is_in_y
+  }
+
+  /// Gets the region of the rect that the point falls in when a dragged
+  /// window is dropped over it, with a band of 25% of the rect's size
+  /// along each edge. See `drop_region_with_band`.
+  #[must_use]
+  pub fn drop_region(&self, point: &Point) -> DropRegion {
+    self.drop_region_with_band(point, 0.25)
+  }
+
+  /// Gets the region of the rect that the point falls in when a dragged
+  /// window is dropped over it.
+  ///
+  /// The point is in an edge's region if it's within `band_fraction` of
+  /// the rect's width (for the left and right edges) or height (for the
+  /// top and bottom edges) from that edge. Near corners, where the bands
+  /// overlap, the relatively closer edge is picked. A `band_fraction` of
+  /// 0.5 divides the rect diagonally into four triangles, such that the
+  /// point is always in one of the edge regions.
+  #[must_use]
+  pub fn drop_region_with_band(
+    &self,
+    point: &Point,
+    band_fraction: f32,
+  ) -> DropRegion {
+    if self.is_empty() {
+      return DropRegion::Center;
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let fraction =
+      |distance: i32, length: i32| distance as f32 / length as f32;
+
+    let edge_fractions = [
+      (
+        DropRegion::Left,
+        fraction(point.x - self.left, self.width()),
+      ),
+      (
+        DropRegion::Right,
+        fraction(self.right - point.x, self.width()),
+      ),
+      (DropRegion::Top, fraction(point.y - self.top, self.height())),
+      (
+        DropRegion::Bottom,
+        fraction(self.bottom - point.y, self.height()),
+      ),
+    ];
+
+    edge_fractions
+      .into_iter()
+      .filter(|(_, fraction)| *fraction <= band_fraction)
+      .min_by(|(_, a), (_, b)| a.total_cmp(b))
+      .map_or(DropRegion::Center, |(region, _)| region)
+  }
+
+  /// Gets the distance from the given point to the furthest corner of
+  /// the rect.
+  ///
+  /// This is not the distance to the rect itself. When finding the
+  /// nearest window to a point, use `closest_distance_to_point` instead.
+  #[must_use]
+  pub fn distance_to_point(&self, point: &Point) -> f32 {
+    let dx = (self.x() - point.x)
+      .abs()
+      .max((self.x() + self.width() - point.x).abs());
+
+    let dy = (self.y() - point.y)
+      .abs()
+      .max((self.y() + self.height() - point.y).abs());
+
+    #[allow(clippy::cast_precision_loss)]
+    ((dx * dx + dy * dy) as f32).sqrt()
+  }
+
+  /// Gets the Euclidean distance from the given point to the nearest edge
+  /// or corner of the rect.
+  ///
+  /// Returns `0.0` if the point is within the rect.
+  #[must_use]
+  pub fn closest_distance_to_point(&self, point: &Point) -> f32 {
+    let dx = (self.left - point.x).max(point.x - self.right).max(0);
+    let dy = (self.top - point.y).max(point.y - self.bottom).max(0);
+
+    #[allow(clippy::cast_precision_loss)]
+    (dx as f32).hypot(dy as f32)
+  }
+}
+
+impl FromStr for Rect {
+  type Err = anyhow::Error;
+
+  /// Parses a string of the format `x,y,width,height`. Allows for
+  /// negative numbers.
+  ///
+  /// Example:
+  /// ```
+  /// # use wm_common::Rect;
+  /// # use std::str::FromStr;
+  /// let parsed = Rect::from_str("100,-50,800,600");
+  /// assert_eq!(parsed.unwrap(), Rect::from_xy(100, -50, 800, 600));
+  /// ```
+  fn from_str(unparsed: &str) -> anyhow::Result<Self> {
+    let err_msg = format!(
+      "Not a valid rect '{unparsed}'. Must be of format 'x,y,width,height'."
+    );
+
+    let parts = unparsed
+      .split(',')
+      .map(|part| part.trim().parse::<i32>())
+      .collect::<Result<Vec<_>, _>>()
+      .context(err_msg.clone())?;
+
+    match parts.as_slice() {
+      [x, y, width, height] => Ok(Self::from_xy(*x, *y, *width, *height)),
+      _ => bail!(err_msg),
+    }
+  }
+}
+
+/// Formats the rect as `x,y,width,height`, which can be parsed back via
+/// `FromStr`.
+impl std::fmt::Display for Rect {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(
+      f,
+      "{},{},{},{}",
+      self.x(),
+      self.y(),
+      self.width(),
+      self.height()
+    )
+  }
+}
+
+/// Deserialize a `Rect` from either a `x,y,width,height` string or a
+/// struct.
+impl<'de> Deserialize<'de> for Rect {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RectDe {
+      Struct {
+        left: i32,
+        top: i32,
+        right: i32,
+        bottom: i32,
+      },
+      String(String),
+    }
+
+    match RectDe::deserialize(deserializer)? {
+      RectDe::Struct {
+        left,
+        top,
+        right,
+        bottom,
+      } => Ok(Self::from_ltrb(left, top, right, bottom)),
+      RectDe::String(str) => {
+        Self::from_str(&str).map_err(serde::de::Error::custom)
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::str::FromStr;
+
+  use super::*;
+  use crate::{DropRegion, Easing, LengthUnit};
+
+  #[test]
+  fn test_clamp_within_bounds_no_overflow() {
+    let window_rect = Rect::from_xy(100, 100, 200, 150);
+    let monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
+    
+    let result = window_rect.clamp_within_bounds(&monitor_rect);
+    
+    // Window should remain unchanged as it fits within bounds
+    assert_eq!(result, window_rect);
+  }
+
+  #[test]
+  fn test_clamp_within_bounds_right_overflow() {
+    let window_rect = Rect::from_xy(1800, 100, 200, 150);
+    let monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
+    
+    let result = window_rect.clamp_within_bounds(&monitor_rect);
+    
+    // Window should be repositioned to fit entirely within monitor
+    assert_eq!(result, Rect::from_xy(1720, 100, 200, 150));
+  }
+
+  #[test]
+  fn test_clamp_within_bounds_bottom_overflow() {
+    let window_rect = Rect::from_xy(100, 1000, 200, 150);
+    let monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
+    
+    let result = window_rect.clamp_within_bounds(&monitor_rect);
+    
+    // Window should be repositioned to fit entirely within monitor
+    assert_eq!(result, Rect::from_xy(100, 930, 200, 150));
+  }
+
+  #[test]
+  fn test_clamp_within_bounds_mixed_resolution_scenario() {
+    // Simulate Task Manager spilling from 4K monitor to smaller monitor
+    // 4K monitor: 0,0 to 3840,2160
+    // 1920x1200 monitor: 3840,0 to 5760,1200
+    let window_rect = Rect::from_xy(3700, 100, 300, 400); // Window near edge of 4K monitor
+    let small_monitor_rect = Rect::from_xy(3840, 0, 1920, 1200);
+    
+    let result = window_rect.clamp_within_bounds(&small_monitor_rect);
+    
+    // Should be fully contained within the small monitor
+    assert_eq!(result, Rect::from_xy(3840, 100, 300, 400));
+    assert!(result.right <= small_monitor_rect.right);
+    assert!(result.bottom <= small_monitor_rect.bottom);
+  }
+
+  #[test]
+  fn test_clamp_within_bounds_oversized_window() {
+    let window_rect = Rect::from_xy(100, 100, 2000, 1500);
+    let monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
+    
+    let result = window_rect.clamp_within_bounds(&monitor_rect);
+    
+    // Window should be resized and positioned at origin
+    assert_eq!(result, Rect::from_xy(0, 0, 1920, 1080));
+  }
+
+  #[test]
+  fn test_clamp_within_bounds_negative_coordinates() {
+    let window_rect = Rect::from_xy(-100, -50, 200, 150);
+    let monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
+    
+    let result = window_rect.clamp_within_bounds(&monitor_rect);
+    
+    // Window should be repositioned to monitor origin
+    assert_eq!(result, Rect::from_xy(0, 0, 200, 150));
+  }
+
+  #[test]
+  fn test_clamp_within_bounds_multi_monitor_boundary() {
     // Test window at boundary between monitors in mixed resolution setup
     let window_rect = Rect::from_xy(3700, 500, 400, 300); // Spans across monitors
     let right_monitor_rect = Rect::from_xy(3840, 0, 1920, 1200);
@@ -321,4 +1762,1710 @@ mod tests {
     assert!(result.left >= right_monitor_rect.left);
     assert!(result.right <= right_monitor_rect.right);
   }
+
+  #[test]
+  fn test_intersection_mixed_resolution_scenario() {
+    // Window straddling the seam between a 4K monitor and a 1920x1200
+    // monitor to its right.
+    let window_rect = Rect::from_xy(3700, 100, 300, 400);
+    let large_monitor_rect = Rect::from_xy(0, 0, 3840, 2160);
+    let small_monitor_rect = Rect::from_xy(3840, 0, 1920, 1200);
+
+    assert_eq!(
+      window_rect.intersection(&large_monitor_rect),
+      Some(Rect::from_xy(3700, 100, 140, 400))
+    );
+    assert_eq!(
+      window_rect.intersection(&small_monitor_rect),
+      Some(Rect::from_xy(3840, 100, 160, 400))
+    );
+  }
+
+  #[test]
+  fn test_intersection_contained() {
+    let window_rect = Rect::from_xy(100, 100, 200, 150);
+    let monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
+
+    assert_eq!(
+      window_rect.intersection(&monitor_rect),
+      Some(window_rect.clone())
+    );
+  }
+
+  #[test]
+  fn test_intersection_no_overlap() {
+    let large_monitor_rect = Rect::from_xy(0, 0, 3840, 2160);
+    let window_rect = Rect::from_xy(4000, 100, 300, 400);
+
+    assert_eq!(window_rect.intersection(&large_monitor_rect), None);
+  }
+
+  #[test]
+  fn test_intersection_shared_edge() {
+    // Adjacent monitors only share an edge, which is a zero-area overlap.
+    let large_monitor_rect = Rect::from_xy(0, 0, 3840, 2160);
+    let small_monitor_rect = Rect::from_xy(3840, 0, 1920, 1200);
+
+    assert_eq!(large_monitor_rect.intersection(&small_monitor_rect), None);
+    assert_eq!(small_monitor_rect.intersection(&large_monitor_rect), None);
+  }
+
+  #[test]
+  fn test_union_disjoint() {
+    let left_rect = Rect::from_xy(0, 0, 100, 100);
+    let right_rect = Rect::from_xy(300, 200, 100, 50);
+
+    assert_eq!(
+      left_rect.union(&right_rect),
+      Rect::from_ltrb(0, 0, 400, 250)
+    );
+    assert_eq!(
+      right_rect.union(&left_rect),
+      Rect::from_ltrb(0, 0, 400, 250)
+    );
+  }
+
+  #[test]
+  fn test_union_contained() {
+    let window_rect = Rect::from_xy(100, 100, 200, 150);
+    let monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
+
+    assert_eq!(window_rect.union(&monitor_rect), monitor_rect);
+    assert_eq!(monitor_rect.union(&window_rect), monitor_rect);
+  }
+
+  #[test]
+  fn test_union_all() {
+    let rects = [
+      Rect::from_xy(0, 0, 3840, 2160),
+      Rect::from_xy(3840, 0, 1920, 1200),
+      Rect::from_xy(-1920, 500, 1920, 1080),
+    ];
+
+    assert_eq!(
+      Rect::union_all(&rects),
+      Some(Rect::from_ltrb(-1920, 0, 5760, 2160))
+    );
+    assert_eq!(Rect::union_all(&[]), None);
+  }
+
+  #[test]
+  #[allow(clippy::float_cmp)]
+  fn test_closest_distance_to_point_inside() {
+    let rect = Rect::from_xy(100, 100, 200, 150);
+
+    assert_eq!(
+      rect.closest_distance_to_point(&Point { x: 150, y: 120 }),
+      0.0
+    );
+    assert_eq!(
+      rect.closest_distance_to_point(&Point { x: 100, y: 250 }),
+      0.0
+    );
+  }
+
+  #[test]
+  #[allow(clippy::float_cmp)]
+  fn test_closest_distance_to_point_beside() {
+    let rect = Rect::from_xy(100, 100, 200, 150);
+
+    // Point to the right of the rect.
+    assert_eq!(
+      rect.closest_distance_to_point(&Point { x: 330, y: 200 }),
+      30.0
+    );
+
+    // Point above the rect.
+    assert_eq!(
+      rect.closest_distance_to_point(&Point { x: 150, y: 60 }),
+      40.0
+    );
+  }
+
+  #[test]
+  #[allow(clippy::float_cmp)]
+  fn test_closest_distance_to_point_diagonal() {
+    let rect = Rect::from_xy(100, 100, 200, 150);
+
+    // Point is 30px left and 40px above the top-left corner.
+    assert_eq!(
+      rect.closest_distance_to_point(&Point { x: 70, y: 60 }),
+      50.0
+    );
+
+    // Point is 30px right and 40px below the bottom-right corner.
+    assert_eq!(
+      rect.closest_distance_to_point(&Point { x: 330, y: 290 }),
+      50.0
+    );
+  }
+
+  #[test]
+  fn test_contains_rect() {
+    let monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
+
+    assert!(monitor_rect.contains_rect(&Rect::from_xy(100, 100, 200, 150)));
+    assert!(
+      !monitor_rect.contains_rect(&Rect::from_xy(1800, 100, 200, 150))
+    );
+    assert!(!monitor_rect.contains_rect(&Rect::from_xy(-10, 0, 200, 150)));
+  }
+
+  #[test]
+  fn test_contains_rect_equal_edges() {
+    let monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
+
+    // Edges are inclusive, so a rect always contains itself.
+    assert!(monitor_rect.contains_rect(&monitor_rect));
+    assert!(monitor_rect.contains_rect(&Rect::from_ltrb(0, 0, 1920, 500)));
+    assert!(
+      monitor_rect.contains_rect(&Rect::from_ltrb(960, 0, 1920, 1080))
+    );
+  }
+
+  #[test]
+  #[allow(clippy::float_cmp)]
+  fn test_contained_area_ratio() {
+    let large_monitor_rect = Rect::from_xy(0, 0, 3840, 2160);
+    let small_monitor_rect = Rect::from_xy(3840, 0, 1920, 1200);
+
+    // 140px of the window's 300px width is on the 4K monitor.
+    let window_rect = Rect::from_xy(3700, 100, 300, 400);
+
+    assert!(
+      (large_monitor_rect.contained_area_ratio(&window_rect)
+        - 140.0 / 300.0)
+        .abs()
+        < f32::EPSILON
+    );
+    assert!(
+      (small_monitor_rect.contained_area_ratio(&window_rect)
+        - 160.0 / 300.0)
+        .abs()
+        < f32::EPSILON
+    );
+    assert_eq!(
+      large_monitor_rect.contained_area_ratio(&large_monitor_rect),
+      1.0
+    );
+    assert_eq!(
+      large_monitor_rect.contained_area_ratio(&small_monitor_rect),
+      0.0
+    );
+  }
+
+  #[test]
+  fn test_contains_point_monitor_seam() {
+    let left_monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
+    let right_monitor_rect = Rect::from_xy(1920, 0, 1920, 1080);
+    let seam_point = Point { x: 1920, y: 500 };
+
+    // Point on the shared edge should belong to exactly one monitor.
+    assert!(!left_monitor_rect.contains_point(&seam_point));
+    assert!(right_monitor_rect.contains_point(&seam_point));
+
+    assert!(left_monitor_rect.contains_point(&Point { x: 1919, y: 500 }));
+    assert!(left_monitor_rect.contains_point(&Point { x: 0, y: 0 }));
+  }
+
+  #[test]
+  fn test_contains_point_inclusive() {
+    let left_monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
+    let right_monitor_rect = Rect::from_xy(1920, 0, 1920, 1080);
+    let seam_point = Point { x: 1920, y: 500 };
+
+    assert!(left_monitor_rect.contains_point_inclusive(&seam_point));
+    assert!(right_monitor_rect.contains_point_inclusive(&seam_point));
+    assert!(left_monitor_rect
+      .contains_point_inclusive(&Point { x: 1920, y: 1080 }));
+  }
+
+  #[test]
+  fn test_scale_about_origin() {
+    let rect = Rect::from_xy(100, 100, 200, 150);
+
+    assert_eq!(
+      rect.scale(1.5, &Point { x: 0, y: 0 }),
+      Rect::from_xy(150, 150, 300, 225)
+    );
+    assert_eq!(
+      rect.scale(2.0, &Point { x: 100, y: 100 }),
+      Rect::from_xy(100, 100, 400, 300)
+    );
+  }
+
+  #[test]
+  fn test_scale_about_center() {
+    let rect = Rect::from_xy(100, 100, 200, 100);
+
+    assert_eq!(
+      rect.scale_about_center(2.0),
+      Rect::from_xy(0, 50, 400, 200)
+    );
+  }
+
+  #[test]
+  fn test_scale_round_trip() {
+    let rect = Rect::from_xy(101, 57, 333, 211);
+    let origin = Point { x: 3840, y: 0 };
+
+    let scaled = rect.scale(1.5, &origin);
+    assert_eq!(scaled.scale(1.0 / 1.5, &origin), rect);
+
+    let scaled = rect.scale_about_center(1.5);
+    assert_eq!(scaled.scale(1.0 / 1.5, &rect.center_point()), rect);
+  }
+
+  #[test]
+  fn test_resize_keeping_aspect_by_width() {
+    let rect = Rect::from_xy(100, 100, 1280, 720);
+
+    assert_eq!(
+      rect.resize_keeping_aspect(Some(1920), None),
+      Rect::from_xy(100, 100, 1920, 1080)
+    );
+    assert_eq!(
+      rect.resize_keeping_aspect(None, Some(360)),
+      Rect::from_xy(100, 100, 640, 360)
+    );
+
+    // Width takes precedence when both are given.
+    assert_eq!(
+      rect.resize_keeping_aspect(Some(1920), Some(100)),
+      Rect::from_xy(100, 100, 1920, 1080)
+    );
+    assert_eq!(rect.resize_keeping_aspect(None, None), rect);
+  }
+
+  #[test]
+  #[allow(clippy::float_cmp)]
+  fn test_resize_keeping_aspect_zero_height() {
+    let rect = Rect::from_xy(100, 100, 1280, 0);
+
+    assert_eq!(rect.aspect_ratio(), 0.0);
+    assert_eq!(
+      rect.resize_keeping_aspect(Some(1920), None),
+      Rect::from_xy(100, 100, 1920, 0)
+    );
+  }
+
+  #[test]
+  fn test_split_weighted_spreads_remainder() {
+    let rect = Rect::from_xy(0, 0, 1000, 800);
+    let weights = [1.0 / 7.0; 7];
+
+    let cells =
+      rect.split_weighted(&weights, &TilingDirection::Horizontal, 0);
+
+    let widths = cells.iter().map(Rect::width).collect::<Vec<_>>();
+    let min_width = *widths.iter().min().unwrap();
+    let max_width = *widths.iter().max().unwrap();
+
+    assert_eq!(widths.iter().sum::<i32>(), 1000);
+    assert!(max_width - min_width <= 1);
+
+    // Cells should be contiguous and span the full rect.
+    assert_eq!(cells.first().unwrap().left, 0);
+    assert_eq!(cells.last().unwrap().right, 1000);
+    for pair in cells.windows(2) {
+      assert_eq!(pair[0].right, pair[1].left);
+    }
+  }
+
+  #[test]
+  fn test_split_weighted_with_gaps() {
+    let rect = Rect::from_xy(100, 0, 1000, 800);
+    let weights = [1.0 / 7.0; 7];
+
+    let cells =
+      rect.split_weighted(&weights, &TilingDirection::Vertical, 10);
+
+    let heights = cells.iter().map(Rect::height).collect::<Vec<_>>();
+    let min_height = *heights.iter().min().unwrap();
+    let max_height = *heights.iter().max().unwrap();
+
+    // Available space is the rect's height minus 6 gaps.
+    assert_eq!(heights.iter().sum::<i32>(), 800 - 6 * 10);
+    assert!(max_height - min_height <= 1);
+    assert_eq!(cells.last().unwrap().bottom, 800);
+    for pair in cells.windows(2) {
+      assert_eq!(pair[0].bottom + 10, pair[1].top);
+      assert_eq!(pair[1].left, 100);
+    }
+  }
+
+  #[test]
+  fn test_split_n_uneven() {
+    let rect = Rect::from_xy(0, 0, 1000, 800);
+
+    // 1000px minus 2 gaps leaves 980px, which doesn't divide evenly.
+    let cells = rect.split_n(3, &Direction::Right, 10);
+
+    assert_eq!(
+      cells,
+      vec![
+        Rect::from_xy(0, 0, 327, 800),
+        Rect::from_xy(337, 0, 327, 800),
+        Rect::from_xy(674, 0, 326, 800),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_split_n_vertical() {
+    let rect = Rect::from_xy(0, 100, 1000, 800);
+
+    let cells = rect.split_n(2, &Direction::Up, 20);
+
+    assert_eq!(
+      cells,
+      vec![
+        Rect::from_xy(0, 100, 1000, 390),
+        Rect::from_xy(0, 510, 1000, 390),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_split_n_zero_and_one() {
+    let rect = Rect::from_xy(0, 0, 1000, 800);
+
+    assert!(rect.split_n(0, &Direction::Right, 10).is_empty());
+    assert_eq!(rect.split_n(1, &Direction::Right, 10), vec![rect]);
+  }
+
+  #[test]
+  fn test_from_str_and_display_round_trip() {
+    let rect = Rect::from_xy(-1920, -50, 800, 600);
+
+    assert_eq!(rect.to_string(), "-1920,-50,800,600");
+    assert_eq!(Rect::from_str(&rect.to_string()).unwrap(), rect);
+    assert_eq!(
+      Rect::from_str(" 100, 100, 800, 600 ").unwrap(),
+      Rect::from_xy(100, 100, 800, 600)
+    );
+
+    assert!(Rect::from_str("100,100,800").is_err());
+    assert!(Rect::from_str("100,100,800,abc").is_err());
+  }
+
+  #[test]
+  fn test_deserialize_string_form() {
+    let rect: Rect = serde_json::from_str("\"-100,200,800,600\"").unwrap();
+    assert_eq!(rect, Rect::from_xy(-100, 200, 800, 600));
+
+    let json = serde_json::to_string(&rect.to_string()).unwrap();
+    assert_eq!(serde_json::from_str::<Rect>(&json).unwrap(), rect);
+  }
+
+  #[test]
+  fn test_deserialize_struct_form() {
+    let rect = Rect::from_ltrb(-3840, -100, 0, 2060);
+
+    // Serialization keeps the struct form by default.
+    let json = serde_json::to_string(&rect).unwrap();
+    assert_eq!(
+      json,
+      r#"{"left":-3840,"top":-100,"right":0,"bottom":2060}"#
+    );
+    assert_eq!(serde_json::from_str::<Rect>(&json).unwrap(), rect);
+  }
+
+  #[test]
+  fn test_apply_inverse_delta_min_axis_percent() {
+    let monitor_rect = Rect::from_xy(0, 0, 3840, 1080);
+    let gap = LengthValue {
+      amount: 0.02,
+      unit: LengthUnit::MinAxisPercent,
+    };
+    let delta =
+      RectDelta::new(gap.clone(), gap.clone(), gap.clone(), gap.clone());
+
+    let result = monitor_rect.apply_inverse_delta(&delta, None);
+
+    // 2% of the 1080px shorter side on every edge.
+    assert_eq!(result, Rect::from_ltrb(21, 21, 3840 - 21, 1080 - 21));
+    assert_eq!(gap.to_px_with_min_axis(3840, Some(1080), None), 21);
+    assert_eq!(gap.to_px_with_min_axis(1080, Some(1080), None), 21);
+  }
+
+  #[test]
+  fn test_inset_and_outset() {
+    let monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
+
+    assert_eq!(
+      monitor_rect.inset(10),
+      Rect::from_ltrb(10, 10, 1910, 1070)
+    );
+    assert_eq!(
+      monitor_rect.outset(10),
+      Rect::from_ltrb(-10, -10, 1930, 1090)
+    );
+    assert_eq!(monitor_rect.inset(10).outset(10), monitor_rect);
+    assert_eq!(
+      monitor_rect.inset_ltrb(0, 40, 0, 0),
+      Rect::from_ltrb(0, 40, 1920, 1080)
+    );
+  }
+
+  #[test]
+  fn test_inset_larger_than_half_size() {
+    let rect = Rect::from_xy(100, 100, 200, 1000);
+
+    // Width collapses to zero at the center, while height is inset as
+    // usual.
+    assert_eq!(rect.inset(150), Rect::from_ltrb(200, 250, 200, 950));
+
+    // Both axes collapse to the center point.
+    let result = rect.inset(600);
+    assert_eq!(result, Rect::from_ltrb(200, 600, 200, 600));
+    assert_eq!(result.width(), 0);
+    assert_eq!(result.height(), 0);
+  }
+
+  #[test]
+  fn test_snap_to_grid() {
+    let rect = Rect::from_xy(103, 96, 333, 207);
+
+    assert_eq!(rect.snap_to_grid(10), Rect::from_xy(100, 100, 330, 210));
+
+    // Width and height that are already aligned are preserved.
+    let rect = Rect::from_xy(-14, 5, 800, 600);
+    assert_eq!(rect.snap_to_grid(10), Rect::from_xy(-10, 10, 800, 600));
+  }
+
+  #[test]
+  fn test_snap_to_grid_small_and_invalid() {
+    let rect = Rect::from_xy(103, 96, 3, 7);
+
+    // Rect shouldn't collapse when its size is smaller than the grid.
+    assert_eq!(rect.snap_to_grid(10), Rect::from_xy(100, 100, 10, 10));
+    assert_eq!(rect.snap_to_grid(0), rect);
+    assert_eq!(rect.snap_to_grid(-10), rect);
+  }
+
+  #[test]
+  fn test_in_direction_from_prefers_overlap() {
+    let origin_rect = Rect::from_xy(500, 500, 200, 200);
+    let others = [
+      // Diagonal to the top-left, and closer.
+      Rect::from_xy(250, 290, 200, 200),
+      // Above but slightly offset, and further away.
+      Rect::from_xy(550, 100, 200, 200),
+      // Below.
+      Rect::from_xy(500, 800, 200, 200),
+    ];
+
+    assert_eq!(
+      origin_rect.in_direction_from(&Direction::Up, &others),
+      Some(1)
+    );
+    assert_eq!(
+      origin_rect.in_direction_from(&Direction::Down, &others),
+      Some(2)
+    );
+    assert_eq!(
+      origin_rect.in_direction_from(&Direction::Left, &others),
+      Some(0)
+    );
+    assert_eq!(
+      origin_rect.in_direction_from(&Direction::Right, &others),
+      None
+    );
+  }
+
+  #[test]
+  fn test_in_direction_from_nearest() {
+    let origin_rect = Rect::from_xy(0, 0, 1920, 1080);
+    let others = [
+      Rect::from_xy(3840, 0, 1920, 1080),
+      Rect::from_xy(1920, 0, 1920, 1080),
+      // Partially overlaps the origin, so it isn't strictly to the right.
+      Rect::from_xy(1000, 0, 1920, 1080),
+    ];
+
+    assert_eq!(
+      origin_rect.in_direction_from(&Direction::Right, &others),
+      Some(1)
+    );
+    assert_eq!(
+      origin_rect.in_direction_from(&Direction::Right, &[]),
+      None
+    );
+  }
+
+  #[test]
+  fn test_normalized_inverted_rect() {
+    let inverted_rect = Rect::from_ltrb(300, 250, 100, 100);
+
+    assert_eq!(inverted_rect.width(), -200);
+    assert!(inverted_rect.is_empty());
+
+    let normalized_rect = inverted_rect.normalized();
+    assert_eq!(normalized_rect, Rect::from_ltrb(100, 100, 300, 250));
+    assert_eq!(normalized_rect.width(), 200);
+    assert_eq!(normalized_rect.height(), 150);
+    assert!(!normalized_rect.is_empty());
+  }
+
+  #[test]
+  fn test_is_empty_zero_area() {
+    assert!(Rect::from_xy(100, 100, 0, 150).is_empty());
+    assert!(Rect::from_xy(100, 100, 200, 0).is_empty());
+    assert!(!Rect::from_xy(100, 100, 1, 1).is_empty());
+  }
+
+  #[test]
+  #[allow(clippy::float_cmp)]
+  fn test_clamp_within_bounds_reporting_right_overflow() {
+    let window_rect = Rect::from_xy(1800, 100, 200, 150);
+    let monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
+
+    let (result, delta) =
+      window_rect.clamp_within_bounds_reporting(&monitor_rect);
+
+    assert_eq!(result, Rect::from_xy(1720, 100, 200, 150));
+
+    // Right edge was pulled in by the overflow.
+    assert_eq!(delta.right.amount, 80.0);
+    assert_eq!(delta.top.amount, 0.0);
+    assert_eq!(delta.bottom.amount, 0.0);
+
+    // Window is repositioned rather than resized, so the left edge moves
+    // by the same amount.
+    assert_eq!(delta.left.amount, -80.0);
+  }
+
+  #[test]
+  #[allow(clippy::float_cmp)]
+  fn test_clamp_within_bounds_reporting_no_overflow() {
+    let window_rect = Rect::from_xy(100, 100, 200, 150);
+    let monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
+
+    let (result, delta) =
+      window_rect.clamp_within_bounds_reporting(&monitor_rect);
+
+    assert_eq!(result, window_rect);
+    assert!(!delta.is_significant());
+    assert_eq!(delta.left.amount, 0.0);
+    assert_eq!(delta.right.amount, 0.0);
+  }
+
+  #[test]
+  fn test_monitor_relative_round_trip() {
+    // Monitor positioned to the top-left of the primary monitor.
+    let monitor_rect = Rect::from_xy(-1920, -200, 1920, 1080);
+    let window_rect = Rect::from_xy(-1800, -100, 800, 600);
+
+    let relative_rect = window_rect.to_monitor_relative(&monitor_rect);
+    assert_eq!(relative_rect, Rect::from_xy(120, 100, 800, 600));
+
+    assert_eq!(
+      relative_rect.from_monitor_relative(&monitor_rect),
+      window_rect
+    );
+  }
+
+  #[test]
+  fn test_split_weighted_transpose_preserves_proportions() {
+    let rect = Rect::from_xy(0, 0, 1000, 1000);
+    let weights = [0.5, 0.3, 0.2];
+
+    let horizontal_cells =
+      rect.split_weighted(&weights, &TilingDirection::Horizontal, 10);
+    let vertical_cells =
+      rect.split_weighted(&weights, &TilingDirection::Vertical, 10);
+
+    // Flipping the tiling direction stacks the cells with the same
+    // proportions, and without leaving any gaps at the end.
+    for (horizontal_cell, vertical_cell) in
+      horizontal_cells.iter().zip(&vertical_cells)
+    {
+      assert_eq!(horizontal_cell.width(), vertical_cell.height());
+      assert_eq!(horizontal_cell.x(), vertical_cell.y());
+      assert_eq!(vertical_cell.width(), 1000);
+    }
+
+    assert_eq!(vertical_cells.last().unwrap().bottom, 1000);
+  }
+
+  #[test]
+  fn test_outer_gaps_single_window() {
+    let monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
+    let outer_gap = RectDelta::new(
+      LengthValue::from_px(10),
+      LengthValue::from_px(20),
+      LengthValue::from_px(30),
+      LengthValue::from_px(40),
+    );
+
+    let workspace_rect =
+      monitor_rect.apply_inverse_delta(&outer_gap, None);
+    let window_rect = workspace_rect
+      .split_weighted(&[1.0], &TilingDirection::Horizontal, 10)
+      .remove(0);
+
+    assert_eq!(window_rect.left - monitor_rect.left, 10);
+    assert_eq!(window_rect.top - monitor_rect.top, 20);
+    assert_eq!(monitor_rect.right - window_rect.right, 30);
+    assert_eq!(monitor_rect.bottom - window_rect.bottom, 40);
+  }
+
+  #[test]
+  fn test_percentage_outer_gaps_scale_with_monitor() {
+    let percent = LengthValue {
+      amount: 0.02,
+      unit: LengthUnit::Percentage,
+    };
+    let outer_gap = RectDelta::new(
+      percent.clone(),
+      LengthValue::from_px(0),
+      LengthValue::from_px(0),
+      percent,
+    );
+
+    let left_gap = |monitor_rect: &Rect| {
+      monitor_rect.apply_inverse_delta(&outer_gap, None).left
+        - monitor_rect.left
+    };
+
+    // 38.4px and 76.8px, truncated like other lengths.
+    assert_eq!(left_gap(&Rect::from_xy(0, 0, 1920, 1080)), 38);
+    assert_eq!(left_gap(&Rect::from_xy(0, 0, 3840, 2160)), 76);
+
+    // Resolved against the monitor rather than the rect being inset,
+    // e.g. after struts have been applied. The bottom gap is relative to
+    // the monitor's height.
+    let monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
+    let struts_rect = Rect::from_xy(200, 0, 1720, 1040);
+    let workspace_rect = struts_rect.apply_inverse_delta_relative_to(
+      &outer_gap,
+      &monitor_rect,
+      None,
+    );
+
+    assert_eq!(workspace_rect.left, 238);
+    assert_eq!(workspace_rect.bottom, 1040 - 21);
+  }
+
+  #[test]
+  fn test_outer_gaps_larger_than_rect() {
+    let rect = Rect::from_xy(0, 0, 100, 100);
+    let outer_gap = RectDelta::new(
+      LengthValue::from_px(80),
+      LengthValue::from_px(0),
+      LengthValue::from_px(80),
+      LengthValue::from_px(0),
+    );
+
+    let inset = rect.apply_inverse_delta(&outer_gap, None);
+    assert_eq!(inset, Rect::from_ltrb(50, 0, 50, 100));
+  }
+
+  #[test]
+  fn test_split_weighted_normalizes_weights() {
+    let rect = Rect::from_xy(0, 0, 1000, 500);
+
+    // Weights that sum to 0.9 still fill the rect exactly.
+    let cells = rect.split_weighted(
+      &[0.3, 0.3, 0.3],
+      &TilingDirection::Horizontal,
+      0,
+    );
+    assert_eq!(cells[0].left, rect.left);
+    assert_eq!(cells[2].right, rect.right);
+    assert!(cells.windows(2).all(|pair| pair[0].right == pair[1].left));
+    assert_eq!(
+      cells.iter().map(Rect::width).collect::<Vec<_>>(),
+      vec![334, 333, 333]
+    );
+
+    // Weights that sum to 1.2 don't overflow the rect.
+    let cells =
+      rect.split_weighted(&[0.6, 0.6], &TilingDirection::Vertical, 10);
+    assert_eq!(cells[0], Rect::from_xy(0, 0, 1000, 245));
+    assert_eq!(cells[1], Rect::from_xy(0, 255, 1000, 245));
+  }
+
+  #[test]
+  fn test_split_weighted_zero_weights() {
+    let rect = Rect::from_xy(0, 0, 300, 100);
+    let cells =
+      rect.split_weighted(&[0., 0., 0.], &TilingDirection::Horizontal, 0);
+
+    assert_eq!(
+      cells.iter().map(Rect::width).collect::<Vec<_>>(),
+      vec![100, 100, 100]
+    );
+  }
+
+  #[test]
+  fn test_split_weighted_equal_weights() {
+    let rect = Rect::from_xy(0, 0, 1001, 700);
+
+    for count in 1..=7 {
+      #[allow(clippy::cast_precision_loss)]
+      let weights = vec![1. / count as f32; count];
+      let widths = rect
+        .split_weighted(&weights, &TilingDirection::Horizontal, 7)
+        .iter()
+        .map(Rect::width)
+        .collect::<Vec<_>>();
+
+      let min_width = widths.iter().min().unwrap();
+      let max_width = widths.iter().max().unwrap();
+      assert!(max_width - min_width <= 1, "{widths:?}");
+    }
+  }
+
+  #[test]
+  fn test_with_dimensions() {
+    let rect = Rect::from_xy(10, 20, 300, 400);
+
+    assert_eq!(rect.with_width(50), Rect::from_xy(10, 20, 50, 400));
+    assert_eq!(rect.with_height(50), Rect::from_xy(10, 20, 300, 50));
+    assert_eq!(rect.with_x(-5), Rect::from_xy(-5, 20, 300, 400));
+    assert_eq!(rect.with_y(-5), Rect::from_xy(10, -5, 300, 400));
+  }
+
+  #[test]
+  fn test_lerp() {
+    let from = Rect::from_ltrb(0, 0, 100, 100);
+    let to = Rect::from_ltrb(100, 50, 300, 151);
+
+    assert_eq!(from.lerp(&to, 0.0), from);
+    assert_eq!(from.lerp(&to, 1.0), to);
+    assert_eq!(from.lerp(&to, 0.5), Rect::from_ltrb(50, 25, 200, 126));
+
+    // Progress outside of 0.0 to 1.0 is clamped.
+    assert_eq!(from.lerp(&to, -1.0), from);
+    assert_eq!(from.lerp(&to, 2.0), to);
+  }
+
+  #[test]
+  fn test_lerp_with_easing() {
+    let from = Rect::from_xy(0, 0, 100, 100);
+    let to = Rect::from_xy(800, 0, 100, 100);
+
+    let eased = from.lerp(&to, Easing::EaseOutCubic.sample(0.5));
+    assert_eq!(eased, Rect::from_xy(700, 0, 100, 100));
+  }
+
+  #[test]
+  fn test_best_monitor_straddling() {
+    let monitors = [
+      Rect::from_xy(0, 0, 1920, 1080),
+      Rect::from_xy(1920, 0, 2560, 1440),
+    ];
+
+    // Mostly on the second monitor.
+    let window = Rect::from_xy(1800, 100, 800, 600);
+    assert_eq!(window.best_monitor(&monitors), Some(1));
+
+    // Mostly on the first monitor.
+    let window = Rect::from_xy(1400, 100, 800, 600);
+    assert_eq!(window.best_monitor(&monitors), Some(0));
+  }
+
+  #[test]
+  fn test_best_monitor_off_screen() {
+    let monitors = [
+      Rect::from_xy(0, 0, 1920, 1080),
+      Rect::from_xy(1920, 0, 1920, 1080),
+    ];
+
+    // No overlap, so the monitor with the nearest center is picked.
+    let window = Rect::from_xy(5000, 200, 400, 300);
+    assert_eq!(window.best_monitor(&monitors), Some(1));
+
+    let (index, moved) = window.move_onto_monitor(&monitors).unwrap();
+    assert_eq!(index, 1);
+    assert_eq!(moved, Rect::from_xy(3440, 200, 400, 300));
+
+    assert_eq!(window.best_monitor(&[]), None);
+  }
+
+  #[test]
+  fn test_drop_region() {
+    let rect = Rect::from_xy(100, 100, 400, 200);
+    let region = |x, y| rect.drop_region(&Point { x, y });
+
+    assert_eq!(region(120, 200), DropRegion::Left);
+    assert_eq!(region(480, 200), DropRegion::Right);
+    assert_eq!(region(300, 110), DropRegion::Top);
+    assert_eq!(region(300, 290), DropRegion::Bottom);
+    assert_eq!(region(300, 200), DropRegion::Center);
+
+    // Bands are relative to the rect's size, so the top band is 50px
+    // tall whereas the left band is 100px wide.
+    assert_eq!(region(300, 160), DropRegion::Center);
+    assert_eq!(region(190, 200), DropRegion::Left);
+
+    // Near a corner, the relatively closer edge is picked.
+    assert_eq!(region(110, 130), DropRegion::Left);
+    assert_eq!(region(150, 105), DropRegion::Top);
+
+    // A band of half the rect's size leaves no center region.
+    let region = |x, y| rect.drop_region_with_band(&Point { x, y }, 0.5);
+    assert_eq!(region(290, 200), DropRegion::Left);
+    assert_eq!(region(310, 200), DropRegion::Right);
+    assert_eq!(region(300, 190), DropRegion::Top);
+  }
+
+  #[test]
+  fn test_fit_into() {
+    let outer = Rect::from_xy(0, 0, 1920, 1080);
+
+    // Rects within the outer rect are left unchanged.
+    let rect = Rect::from_xy(0, 0, 400, 300);
+    assert_eq!(rect.fit_into(&outer), rect);
+    let rect = Rect::from_xy(760, 390, 400, 300);
+    assert_eq!(rect.fit_into(&outer), rect);
+
+    // Overflowing the right edge, so the rect is shifted left just enough
+    // to fit.
+    let rect = Rect::from_xy(1800, 100, 400, 300);
+    assert_eq!(rect.fit_into(&outer), Rect::from_xy(1520, 100, 400, 300));
+
+    // Overflowing the top-left corner.
+    let rect = Rect::from_xy(-100, -50, 400, 300);
+    assert_eq!(rect.fit_into(&outer), Rect::from_xy(0, 0, 400, 300));
+
+    // Larger than the outer rect, so it's shrunk to the outer rect's size.
+    let rect = Rect::from_xy(100, 500, 2400, 800);
+    assert_eq!(rect.fit_into(&outer), Rect::from_xy(0, 280, 1920, 800));
+
+    for rect in [
+      Rect::from_xy(1800, 100, 400, 300),
+      Rect::from_xy(-3000, 2000, 5000, 300),
+    ] {
+      assert!(outer.contains_rect(&rect.fit_into(&outer)));
+    }
+  }
+
+  #[test]
+  fn test_clamp_position_to_visible() {
+    let monitors = [
+      Rect::from_xy(0, 0, 1920, 1080),
+      Rect::from_xy(1920, 0, 2560, 1440),
+    ];
+
+    // Straddling both monitors, so it stays put.
+    let window = Rect::from_xy(1600, 100, 800, 600);
+    assert_eq!(window.clamp_position_to_visible(&monitors), window);
+
+    // Partially off the bottom of the second monitor is still visible.
+    let window = Rect::from_xy(2000, 1300, 800, 600);
+    assert_eq!(window.clamp_position_to_visible(&monitors), window);
+
+    // Entirely off all monitors, so it's snapped to the nearest one.
+    let window = Rect::from_xy(-900, 200, 400, 300);
+    assert_eq!(
+      window.clamp_position_to_visible(&monitors),
+      Rect::from_xy(0, 200, 400, 300)
+    );
+
+    let window = Rect::from_xy(5000, 2000, 400, 300);
+    assert_eq!(
+      window.clamp_position_to_visible(&monitors),
+      Rect::from_xy(4080, 1140, 400, 300)
+    );
+
+    assert_eq!(window.clamp_position_to_visible(&[]), window);
+  }
+
+  #[test]
+  fn test_contains_point_with_margin() {
+    let rect = Rect::from_xy(100, 100, 200, 200);
+
+    assert!(!rect.contains_point_with_margin(&Point { x: 101, y: 200 }, 5));
+    assert!(rect.contains_point_with_margin(&Point { x: 106, y: 200 }, 5));
+    assert!(!rect.contains_point_with_margin(&Point { x: 298, y: 200 }, 5));
+    assert!(rect.contains_point_with_margin(&Point { x: 101, y: 200 }, 0));
+  }
+
+  #[test]
+  fn test_fit_aspect_within_wide_bounds() {
+    let outer = Rect::from_xy(0, 0, 1600, 1200);
+
+    assert_eq!(
+      Rect::fit_aspect_within(&outer, 16.0 / 9.0, Anchor::Center),
+      Rect::from_xy(0, 150, 1600, 900)
+    );
+    assert_eq!(
+      Rect::fit_aspect_within(&outer, 16.0 / 9.0, Anchor::Bottom),
+      Rect::from_xy(0, 300, 1600, 900)
+    );
+  }
+
+  #[test]
+  fn test_fit_aspect_within_tall_bounds() {
+    let outer = Rect::from_xy(100, 100, 900, 1600);
+
+    assert_eq!(
+      Rect::fit_aspect_within(&outer, 16.0 / 9.0, Anchor::TopLeft),
+      Rect::from_xy(100, 100, 900, 506)
+    );
+    assert_eq!(
+      Rect::fit_aspect_within(&outer, 16.0 / 9.0, Anchor::BottomRight),
+      Rect::from_xy(100, 1194, 900, 506)
+    );
+
+    // Height-bound ratios are aligned horizontally instead.
+    assert_eq!(
+      Rect::fit_aspect_within(&outer, 0.25, Anchor::Right),
+      Rect::from_xy(600, 100, 400, 1600)
+    );
+  }
+
+  #[test]
+  fn test_hash_matches_equality() {
+    use std::hash::{DefaultHasher, Hash, Hasher};
+
+    let hash = |rect: &Rect| {
+      let mut hasher = DefaultHasher::new();
+      rect.hash(&mut hasher);
+      hasher.finish()
+    };
+
+    let rect = Rect::from_xy(10, 20, 300, 400);
+    assert_eq!(hash(&rect), hash(&Rect::from_ltrb(10, 20, 310, 420)));
+    assert_ne!(hash(&rect), hash(&rect.with_x(11)));
+  }
+
+  #[test]
+  fn test_translate_in_direction_by() {
+    let reference = Rect::from_xy(0, 0, 1920, 1080);
+    let rect = Rect::from_xy(100, 100, 400, 300);
+
+    assert_eq!(
+      rect.translate_in_direction_by(
+        &Direction::Left,
+        &LengthValue::from_px(30),
+        &reference
+      ),
+      Rect::from_xy(70, 100, 400, 300)
+    );
+
+    let five_percent = LengthValue {
+      amount: 0.05,
+      unit: LengthUnit::Percentage,
+    };
+
+    assert_eq!(
+      rect.translate_in_direction_by(
+        &Direction::Right,
+        &five_percent,
+        &reference
+      ),
+      Rect::from_xy(196, 100, 400, 300)
+    );
+    assert_eq!(
+      rect.translate_in_direction_by(
+        &Direction::Down,
+        &five_percent,
+        &reference
+      ),
+      Rect::from_xy(100, 154, 400, 300)
+    );
+  }
+
+  #[test]
+  fn test_quadrant() {
+    let rect = Rect::from_xy(0, 0, 1920, 1080);
+
+    assert_eq!(
+      rect.quadrant(Anchor::TopLeft, 10, 20),
+      Rect::from_xy(0, 0, 955, 530)
+    );
+    assert_eq!(
+      rect.quadrant(Anchor::Right, 10, 20),
+      Rect::from_xy(965, 0, 955, 1080)
+    );
+    assert_eq!(
+      rect.quadrant(Anchor::BottomRight, 0, 0),
+      Rect::from_xy(960, 540, 960, 540)
+    );
+    assert_eq!(rect.quadrant(Anchor::Center, 10, 20), rect);
+  }
+
+  #[test]
+  fn test_apply_delta_clamps_to_min_size() {
+    let rect = Rect::from_xy(100, 100, 10, 50);
+    let delta = RectDelta::new(
+      LengthValue::from_px(-20),
+      LengthValue::from_px(0),
+      LengthValue::from_px(-20),
+      LengthValue::from_px(0),
+    );
+
+    // Oversized inward delta is clamped to 1px, centered on the prior
+    // center instead of inverting.
+    let shrunk = rect.apply_delta(&delta, None);
+    assert_eq!(shrunk, Rect::from_xy(105, 100, 1, 50));
+
+    let shrunk = rect.apply_delta_with_min_size(&delta, None, 4);
+    assert_eq!(shrunk, Rect::from_xy(103, 100, 4, 50));
+
+    // Deltas that leave enough space are applied as-is.
+    let grown = rect.apply_delta(
+      &RectDelta::new(
+        LengthValue::from_px(20),
+        LengthValue::from_px(0),
+        LengthValue::from_px(20),
+        LengthValue::from_px(0),
+      ),
+      None,
+    );
+    assert_eq!(grown, Rect::from_xy(80, 100, 50, 50));
+  }
+
+  #[test]
+  fn test_relative_position() {
+    let rect = Rect::from_xy(100, 100, 100, 100);
+
+    assert_eq!(
+      rect.relative_position(&Rect::from_xy(200, 120, 50, 50)),
+      vec![Direction::Right]
+    );
+    assert_eq!(
+      rect.relative_position(&Rect::from_xy(250, 0, 50, 50)),
+      vec![Direction::Right, Direction::Up]
+    );
+    assert_eq!(
+      rect.relative_position(&Rect::from_xy(0, 210, 50, 50)),
+      vec![Direction::Left, Direction::Down]
+    );
+    assert!(rect
+      .relative_position(&Rect::from_xy(150, 150, 100, 100))
+      .is_empty());
+  }
+
+  #[test]
+  fn test_center_on_point() {
+    let point = Point { x: 500, y: 500 };
+    let rect = Rect::from_xy(0, 0, 200, 100).center_on_point(&point);
+
+    let center = rect.center_point();
+    assert_eq!(rect, Rect::from_xy(400, 450, 200, 100));
+    assert_eq!((center.x, center.y), (500, 500));
+
+    // Odd sizes are still centered exactly on the point.
+    let center = Rect::from_xy(0, 0, 201, 101)
+      .center_on_point(&point)
+      .center_point();
+    assert_eq!((center.x, center.y), (500, 500));
+  }
+
+  #[test]
+  fn test_overlap_partial() {
+    let rect = Rect::from_xy(0, 0, 100, 100);
+    let other = Rect::from_xy(60, 70, 100, 100);
+
+    assert_eq!(rect.overlap_length_x(&other), 40);
+    assert_eq!(rect.overlap_length_y(&other), 30);
+    assert_eq!(rect.overlap_area(&other), 1200);
+    assert_eq!(other.overlap_area(&rect), 1200);
+  }
+
+  #[test]
+  fn test_overlap_containment() {
+    let rect = Rect::from_xy(0, 0, 100, 100);
+    let inner = Rect::from_xy(20, 30, 40, 50);
+
+    assert_eq!(rect.overlap_length_x(&inner), 40);
+    assert_eq!(rect.overlap_length_y(&inner), 50);
+    assert_eq!(rect.overlap_area(&inner), 2000);
+  }
+
+  #[test]
+  fn test_overlap_disjoint() {
+    let rect = Rect::from_xy(0, 0, 100, 100);
+
+    // Overlaps on the y-axis only.
+    let other = Rect::from_xy(200, 50, 100, 100);
+    assert_eq!(rect.overlap_length_x(&other), 0);
+    assert_eq!(rect.overlap_length_y(&other), 50);
+    assert_eq!(rect.overlap_area(&other), 0);
+
+    // Touching edges don't count as overlapping.
+    let other = Rect::from_xy(100, 100, 100, 100);
+    assert_eq!(rect.overlap_length_x(&other), 0);
+    assert_eq!(rect.overlap_area(&other), 0);
+  }
+
+  #[test]
+  fn test_in_direction_from_prefers_larger_overlap() {
+    let origin_rect = Rect::from_xy(500, 500, 200, 200);
+    let others = [
+      // Overlaps by 50px, with a closer center.
+      Rect::from_xy(650, 100, 50, 200),
+      // Overlaps by 150px at the same distance.
+      Rect::from_xy(350, 100, 300, 200),
+    ];
+
+    assert_eq!(
+      origin_rect.in_direction_from(&Direction::Up, &others),
+      Some(1)
+    );
+  }
+
+  #[test]
+  fn test_in_direction_from_prefers_overlap_over_gap() {
+    let origin_rect = Rect::from_xy(500, 500, 200, 200);
+    let others = [
+      // Nearer, but only overlaps by 50px.
+      Rect::from_xy(650, 250, 200, 200),
+      // Farther away, but overlaps by the full 200px.
+      Rect::from_xy(500, 0, 200, 200),
+    ];
+
+    assert_eq!(
+      origin_rect.in_direction_from(&Direction::Up, &others),
+      Some(1)
+    );
+  }
+
+  #[test]
+  fn test_translate_between_monitors() {
+    let primary = Rect::from_xy(0, 0, 2560, 1440);
+    let secondary = Rect::from_xy(2560, 200, 1280, 1024);
+    let rect = Rect::from_xy(100, 100, 400, 300);
+
+    // Same monitor keeps the rect in place.
+    assert_eq!(rect.translate_between_monitors(&primary, &primary), rect);
+
+    // Offset from the monitor's top-left corner is kept.
+    assert_eq!(
+      rect.translate_between_monitors(&primary, &secondary),
+      Rect::from_xy(2660, 300, 400, 300)
+    );
+
+    // Rect near the bottom-right of a larger monitor stays on-screen
+    // when moved to a smaller one.
+    let rect = Rect::from_xy(2000, 1200, 400, 200);
+    let moved = rect.translate_between_monitors(&primary, &secondary);
+    assert_eq!(moved, Rect::from_xy(3440, 1024, 400, 200));
+    assert!(secondary.contains_rect(&moved));
+  }
+
+  #[test]
+  fn test_proportional_remap() {
+    let from = Rect::from_xy(0, 0, 3840, 2160);
+    let to = Rect::from_xy(3840, 0, 1920, 1080);
+
+    // Window taking up the right half of the 4K monitor, inset by 40px.
+    let rect = Rect::from_ltrb(1920, 40, 3800, 2120);
+    assert_eq!(
+      rect.proportional_remap(&from, &to),
+      Rect::from_ltrb(4800, 20, 5740, 1060)
+    );
+
+    // Odd offsets are rounded.
+    let rect = Rect::from_xy(101, 51, 801, 601);
+    assert_eq!(
+      rect.proportional_remap(&from, &to),
+      Rect::from_ltrb(3891, 26, 4291, 326)
+    );
+
+    // Rect that's partially off the source monitor is clamped on-screen.
+    let rect = Rect::from_xy(3000, 1800, 1200, 600);
+    let remapped = rect.proportional_remap(&from, &to);
+    assert_eq!(remapped, Rect::from_xy(5160, 780, 600, 300));
+    assert!(to.contains_rect(&remapped));
+
+    // Empty source rect falls back to clamping.
+    let rect = Rect::from_xy(0, 0, 100, 100);
+    assert_eq!(
+      rect.proportional_remap(&Rect::from_xy(0, 0, 0, 0), &to),
+      Rect::from_xy(3840, 0, 100, 100)
+    );
+  }
+
+  #[test]
+  fn test_shared_edge_adjacent() {
+    let left = Rect::from_xy(0, 0, 500, 800);
+    let right = Rect::from_xy(500, 0, 500, 800);
+
+    assert_eq!(left.shared_edge(&right), Some(Direction::Right));
+    assert_eq!(right.shared_edge(&left), Some(Direction::Left));
+
+    // Stacked rects share their bottom/top edges.
+    let top = Rect::from_xy(500, 0, 500, 400);
+    let bottom = Rect::from_xy(500, 401, 500, 399);
+    assert_eq!(top.shared_edge(&bottom), Some(Direction::Down));
+    assert_eq!(bottom.shared_edge(&top), Some(Direction::Up));
+
+    // Rects separated by a gap only share an edge with enough tolerance.
+    let gapped = Rect::from_xy(510, 0, 490, 800);
+    assert_eq!(left.shared_edge(&gapped), None);
+    assert_eq!(
+      left.shared_edge_with_tolerance(&gapped, 10),
+      Some(Direction::Right)
+    );
+  }
+
+  #[test]
+  fn test_shared_edge_diagonal() {
+    let rect = Rect::from_xy(0, 0, 500, 400);
+
+    // Corners touch, but the edges don't overlap.
+    assert_eq!(rect.shared_edge(&Rect::from_xy(500, 400, 500, 400)), None);
+    assert_eq!(
+      rect.shared_edge(&Rect::from_xy(-500, -400, 500, 400)),
+      None
+    );
+
+    // Not adjacent at all.
+    assert_eq!(rect.shared_edge(&Rect::from_xy(800, 0, 500, 400)), None);
+  }
+
+  #[test]
+  fn test_snap_to_corner() {
+    let workspace = Rect::from_xy(0, 0, 1920, 1080);
+    let window = Rect::from_xy(600, 300, 800, 600);
+
+    let snap = |corner| window.snap_to_corner(&corner, &workspace);
+
+    assert_eq!(
+      snap(OrdinalDirection::UpLeft),
+      Rect::from_xy(0, 0, 800, 600)
+    );
+    assert_eq!(
+      snap(OrdinalDirection::UpRight),
+      Rect::from_xy(1120, 0, 800, 600)
+    );
+    assert_eq!(
+      snap(OrdinalDirection::DownLeft),
+      Rect::from_xy(0, 480, 800, 600)
+    );
+    assert_eq!(
+      snap(OrdinalDirection::DownRight),
+      Rect::from_xy(1120, 480, 800, 600)
+    );
+
+    // Works on monitors that aren't at the origin.
+    let workspace = Rect::from_xy(-1920, 100, 1920, 1080);
+    assert_eq!(
+      window.snap_to_corner(&OrdinalDirection::DownRight, &workspace),
+      Rect::from_xy(-800, 580, 800, 600)
+    );
+  }
+
+  #[test]
+  fn test_translate_in_ordinal_direction() {
+    let rect = Rect::from_xy(100, 100, 50, 50);
+
+    assert_eq!(
+      rect.translate_in_ordinal_direction(&OrdinalDirection::UpLeft, 10),
+      Rect::from_xy(90, 90, 50, 50)
+    );
+    assert_eq!(
+      rect
+        .translate_in_ordinal_direction(&OrdinalDirection::DownRight, 10),
+      Rect::from_xy(110, 110, 50, 50)
+    );
+  }
+
+  #[test]
+  fn test_focus_past_right_edge() {
+    // Workspace with three side-by-side tiles, where the rightmost tile is
+    // focused.
+    let tiles = [
+      Rect::from_xy(0, 0, 640, 1080),
+      Rect::from_xy(640, 0, 640, 1080),
+      Rect::from_xy(1280, 0, 640, 1080),
+    ];
+    let origin = &tiles[2];
+
+    // `stop`: there's no tile to the right, so focus stays put.
+    assert_eq!(origin.in_direction_from(&Direction::Right, &tiles), None);
+
+    // `wrap`: focus wraps around to the leftmost tile.
+    assert_eq!(
+      origin.wrapped_in_direction_from(&Direction::Right, &tiles),
+      Some(0)
+    );
+
+    // `next_monitor`: focus moves to the monitor on the right.
+    let monitors = [
+      Rect::from_xy(0, 0, 1920, 1080),
+      Rect::from_xy(1920, 0, 2560, 1440),
+    ];
+    assert_eq!(
+      monitors[0].in_direction_from(&Direction::Right, &monitors),
+      Some(1)
+    );
+
+    // Wrapping prefers tiles that overlap the origin vertically.
+    let tiles = [
+      Rect::from_xy(0, 0, 640, 540),
+      Rect::from_xy(0, 540, 640, 540),
+      Rect::from_xy(640, 540, 640, 540),
+    ];
+    assert_eq!(
+      tiles[2].wrapped_in_direction_from(&Direction::Right, &tiles),
+      Some(1)
+    );
+    assert_eq!(
+      tiles[0].wrapped_in_direction_from(&Direction::Up, &tiles),
+      Some(1)
+    );
+  }
+
+  #[test]
+  fn test_area_large_rects() {
+    assert_eq!(Rect::from_xy(0, 0, 3840, 2160).area(), 8_294_400);
+    assert_eq!(Rect::from_xy(0, 0, 100, -10).area(), 0);
+
+    // Exceeds `i32::MAX` when computed with `i32` math.
+    let huge = Rect::from_xy(-40_000, -40_000, 80_000, 80_000);
+    assert_eq!(huge.area(), 6_400_000_000);
+    assert_eq!(huge.overlap_area(&huge), 6_400_000_000);
+  }
+
+  #[test]
+  fn test_proportional_remap_workspace_to_smaller_monitor() {
+    let from = Rect::from_xy(0, 0, 3840, 2160);
+    let to = Rect::from_xy(3840, 0, 1920, 1080);
+
+    // Floating windows spread across a 4K workspace, including ones
+    // touching the right and bottom edges.
+    let windows = [
+      Rect::from_xy(0, 0, 1200, 800),
+      Rect::from_xy(1320, 680, 1200, 800),
+      Rect::from_xy(2640, 1360, 1200, 800),
+      Rect::from_xy(3000, 100, 840, 2060),
+    ];
+
+    for window in &windows {
+      let remapped = window.proportional_remap(&from, &to);
+      assert!(to.contains_rect(&remapped), "{remapped:?} is off-screen");
+    }
+
+    assert_eq!(
+      windows[2].proportional_remap(&from, &to),
+      Rect::from_xy(5160, 680, 600, 400)
+    );
+  }
+
+  #[test]
+  fn test_clamp_reposition_only() {
+    let monitor = Rect::from_xy(1920, 0, 1920, 1080);
+
+    // Oversized window keeps its size, with its top-left pushed to the
+    // monitor's origin.
+    let oversized = Rect::from_xy(2500, 200, 2560, 1440);
+    assert_eq!(
+      oversized.clamp_within_bounds_with_mode(
+        &monitor,
+        ClampMode::RepositionOnly
+      ),
+      Rect::from_xy(1920, 0, 2560, 1440)
+    );
+    assert_eq!(
+      oversized.clamp_within_bounds(&monitor),
+      Rect::from_xy(1920, 0, 1920, 1080)
+    );
+
+    // Windows that fit are repositioned the same way in both modes.
+    let window = Rect::from_xy(3500, -50, 800, 600);
+    assert_eq!(
+      window.clamp_within_bounds_with_mode(
+        &monitor,
+        ClampMode::RepositionOnly
+      ),
+      window.clamp_within_bounds(&monitor)
+    );
+    assert_eq!(
+      window.clamp_within_bounds(&monitor),
+      Rect::from_xy(3040, 0, 800, 600)
+    );
+  }
+
+  #[test]
+  fn test_mirror_within_monitor() {
+    let monitor_rect = Rect::from_xy(1920, 0, 1920, 1080);
+    let corner_rect = Rect::from_xy(1920, 0, 400, 300);
+
+    assert_eq!(
+      corner_rect.mirror_horizontal(&monitor_rect),
+      Rect::from_xy(3440, 0, 400, 300)
+    );
+    assert_eq!(
+      corner_rect.mirror_vertical(&monitor_rect),
+      Rect::from_xy(1920, 780, 400, 300)
+    );
+
+    // Edges swap sides, and mirroring twice gets the original rect.
+    let mirrored = corner_rect.mirror_horizontal(&monitor_rect);
+    assert_eq!(mirrored.right, monitor_rect.right);
+    assert_eq!(mirrored.mirror_horizontal(&monitor_rect), corner_rect);
+
+    // A rect centered within the bounds stays in place.
+    let centered_rect = Rect::from_xy(2680, 340, 400, 400);
+    assert_eq!(
+      centered_rect.mirror_horizontal(&monitor_rect),
+      centered_rect
+    );
+    assert_eq!(
+      centered_rect.mirror_vertical(&monitor_rect),
+      centered_rect
+    );
+  }
+
+  #[test]
+  fn test_split_at_ratio() {
+    let rect = Rect::from_xy(0, 0, 1010, 610);
+
+    assert_eq!(
+      rect.split_at_ratio(&Direction::Right, 0.5, 10),
+      (
+        Rect::from_xy(0, 0, 500, 610),
+        Rect::from_xy(510, 0, 500, 610)
+      )
+    );
+    assert_eq!(
+      rect.split_at_ratio(&Direction::Left, 0.3, 10),
+      (
+        Rect::from_xy(0, 0, 300, 610),
+        Rect::from_xy(310, 0, 700, 610)
+      )
+    );
+    assert_eq!(
+      rect.split_at_ratio(&Direction::Down, 0.5, 10),
+      (
+        Rect::from_xy(0, 0, 1010, 300),
+        Rect::from_xy(0, 310, 1010, 300)
+      )
+    );
+    assert_eq!(
+      rect.split_at_ratio(&Direction::Up, 0.3, 10),
+      (
+        Rect::from_xy(0, 0, 1010, 180),
+        Rect::from_xy(0, 190, 1010, 420)
+      )
+    );
+  }
+
+  #[test]
+  fn test_split_at_ratio_clamps_ratio_and_gap() {
+    let rect = Rect::from_xy(0, 0, 100, 100);
+
+    assert_eq!(
+      rect.split_at_ratio(&Direction::Right, 1.5, 10),
+      (Rect::from_xy(0, 0, 90, 100), Rect::from_xy(100, 0, 0, 100))
+    );
+
+    // Both halves collapse to zero width if the gap fills the rect.
+    let (first, second) = rect.split_at_ratio(&Direction::Right, 0.5, 200);
+    assert_eq!(first, Rect::from_xy(0, 0, 0, 100));
+    assert_eq!(second, Rect::from_xy(100, 0, 0, 100));
+  }
+
+  #[test]
+  fn test_apply_delta_to_single_edge() {
+    let monitor_rect = Rect::from_xy(0, 0, 1920, 1080);
+    let rect = Rect::from_xy(1400, 200, 400, 300);
+    let delta = RectDelta::new(
+      LengthValue::from_px(0),
+      LengthValue::from_px(0),
+      LengthValue::from_px(50),
+      LengthValue::from_px(0),
+    );
+
+    // Only the right edge moves, scaled by the monitor's scale factor.
+    let grown = rect.apply_delta(&delta, Some(1.5));
+    assert_eq!(grown, Rect::from_ltrb(1400, 200, 1875, 500));
+
+    // Growing past the monitor edge is capped at the monitor's edge,
+    // rather than moving the window.
+    let capped = grown
+      .apply_delta(&delta, Some(1.5))
+      .cap_edges_within(&grown, &monitor_rect);
+    assert_eq!(capped, Rect::from_ltrb(1400, 200, 1920, 500));
+
+    // Edges that already lay past the monitor aren't moved onto it.
+    let rect = Rect::from_xy(-100, 200, 400, 300);
+    assert_eq!(
+      rect
+        .apply_delta(&delta, Some(1.))
+        .cap_edges_within(&rect, &monitor_rect),
+      Rect::from_ltrb(-100, 200, 350, 500)
+    );
+  }
+
+  #[test]
+  fn test_constrain_to_work_area() {
+    let prev_work_area = Rect::from_ltrb(0, 0, 1920, 1080);
+
+    // Taskbar reappears at the bottom of the monitor.
+    let work_area = Rect::from_ltrb(0, 0, 1920, 1040);
+
+    // Windows flush with the bottom edge shrink to the new work area.
+    let flush_rect = Rect::from_ltrb(960, 0, 1920, 1080);
+    assert_eq!(
+      flush_rect.constrain_to_work_area(&prev_work_area, &work_area),
+      Rect::from_ltrb(960, 0, 1920, 1040)
+    );
+
+    // Windows overlapping the taskbar are moved back into the work area.
+    let overlapping_rect = Rect::from_ltrb(100, 700, 500, 1060);
+    assert_eq!(
+      overlapping_rect.constrain_to_work_area(&prev_work_area, &work_area),
+      Rect::from_ltrb(100, 680, 500, 1040)
+    );
+
+    // Windows within the new work area are left as-is.
+    let inner_rect = Rect::from_ltrb(100, 100, 500, 500);
+    assert_eq!(
+      inner_rect.constrain_to_work_area(&prev_work_area, &work_area),
+      inner_rect
+    );
+
+    // Flush edges follow the work area when it grows again.
+    let shrunk_rect =
+      flush_rect.constrain_to_work_area(&prev_work_area, &work_area);
+    assert_eq!(
+      shrunk_rect.constrain_to_work_area(&work_area, &prev_work_area),
+      flush_rect
+    );
+  }
+
+  #[test]
+  fn test_translate_to_center_odd_sizes() {
+    // Odd-width window in an odd-width monitor is centered exactly.
+    let monitor = Rect::from_xy(1920, 0, 1365, 767);
+    let window = Rect::from_xy(0, 0, 801, 401);
+    let centered = window.translate_to_center(&monitor);
+
+    assert_eq!(centered, Rect::from_xy(2202, 183, 801, 401));
+    assert_eq!(
+      centered.left - monitor.left,
+      monitor.right - centered.right
+    );
+    assert_eq!(
+      centered.top - monitor.top,
+      monitor.bottom - centered.bottom
+    );
+
+    // Otherwise, the extra pixel is consistently placed after the window,
+    // regardless of which of the two widths is odd.
+    for (outer_width, width) in [(1366, 801), (1365, 800), (5, 2), (6, 3)]
+    {
+      let outer = Rect::from_xy(0, 0, outer_width, 100);
+      let centered =
+        Rect::from_xy(0, 0, width, 100).translate_to_center(&outer);
+
+      assert_eq!(outer.right - centered.right - centered.left, 1);
+    }
+
+    // Windows larger than the monitor overflow evenly on both sides.
+    let centered =
+      Rect::from_xy(0, 0, 1401, 767).translate_to_center(&monitor);
+    assert_eq!(centered.left, 1902);
+    assert_eq!(centered.right - monitor.right, 18);
+  }
+
+  mod clamp_properties {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn monitor_rect() -> impl Strategy<Value = Rect> {
+      (-5000..5000, -5000..5000, 1..4000, 1..4000).prop_map(
+        |(x, y, width, height)| Rect::from_xy(x, y, width, height),
+      )
+    }
+
+    fn window_rect() -> impl Strategy<Value = Rect> {
+      (-10_000..10_000, -10_000..10_000, 1..8000, 1..8000).prop_map(
+        |(x, y, width, height)| Rect::from_xy(x, y, width, height),
+      )
+    }
+
+    proptest! {
+      #[test]
+      fn clamped_rect_lies_within_bounds(
+        window in window_rect(),
+        monitor in monitor_rect(),
+      ) {
+        let clamped = window.clamp_within_bounds(&monitor);
+
+        prop_assert!(monitor.contains_rect(&clamped));
+        prop_assert!(clamped.width() <= monitor.width());
+        prop_assert!(clamped.height() <= monitor.height());
+
+        // Size is only changed if the window doesn't fit.
+        prop_assert_eq!(
+          clamped.width(),
+          window.width().min(monitor.width())
+        );
+        prop_assert_eq!(
+          clamped.height(),
+          window.height().min(monitor.height())
+        );
+      }
+
+      #[test]
+      fn clamping_is_idempotent(
+        window in window_rect(),
+        monitor in monitor_rect(),
+      ) {
+        for mode in [ClampMode::ResizeToFit, ClampMode::RepositionOnly] {
+          let clamped =
+            window.clamp_within_bounds_with_mode(&monitor, mode);
+
+          prop_assert_eq!(
+            clamped.clamp_within_bounds_with_mode(&monitor, mode),
+            clamped
+          );
+        }
+      }
+
+      #[test]
+      fn reposition_only_keeps_size(
+        window in window_rect(),
+        monitor in monitor_rect(),
+      ) {
+        let clamped = window.clamp_within_bounds_with_mode(
+          &monitor,
+          ClampMode::RepositionOnly,
+        );
+
+        prop_assert_eq!(clamped.width(), window.width());
+        prop_assert_eq!(clamped.height(), window.height());
+
+        // Top-left corner is always on the monitor, and the whole window
+        // is on the monitor if it fits.
+        let top_left = Point {
+          x: clamped.x(),
+          y: clamped.y(),
+        };
+        prop_assert!(monitor.contains_point(&top_left));
+
+        if window.width() <= monitor.width()
+          && window.height() <= monitor.height()
+        {
+          prop_assert!(monitor.contains_rect(&clamped));
+        }
+      }
+
+      #[test]
+      fn oversized_window_shrinks_to_monitor(
+        monitor in monitor_rect(),
+        (extra_width, extra_height) in (1..4000, 1..4000),
+        (offset_x, offset_y) in (-10_000..10_000, -10_000..10_000),
+      ) {
+        let window = Rect::from_xy(
+          monitor.x() + offset_x,
+          monitor.y() + offset_y,
+          monitor.width() + extra_width,
+          monitor.height() + extra_height,
+        );
+
+        prop_assert_eq!(window.clamp_within_bounds(&monitor), monitor);
+      }
+    }
+  }
 }
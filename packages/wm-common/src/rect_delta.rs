@@ -1,6 +1,7 @@
+use anyhow::Context;
 use serde::{Deserialize, Serialize};
 
-use super::LengthValue;
+use super::{LengthUnit, LengthValue, Rect};
 
 #[derive(Debug, Deserialize, Clone, Serialize)]
 pub struct RectDelta {
@@ -33,13 +34,186 @@ impl RectDelta {
     }
   }
 
-  /// Checks if the rectangle delta has a value greater than 1.0(px/%) for
-  /// any of its sides.
+  /// Delta of 0px on all sides.
+  #[must_use]
+  pub fn zero() -> Self {
+    Self::new(
+      LengthValue::from_px(0),
+      LengthValue::from_px(0),
+      LengthValue::from_px(0),
+      LengthValue::from_px(0),
+    )
+  }
+
+  /// Sums each side of the two deltas (e.g. for stacking border and gap
+  /// adjustments).
+  ///
+  /// Errors if the units of a side can't be combined (e.g. px + %).
+  pub fn combine(&self, other: &RectDelta) -> anyhow::Result<RectDelta> {
+    let add = |side: &str, a: &LengthValue, b: &LengthValue| {
+      a.checked_add(b).with_context(|| {
+        format!("Cannot combine {side} deltas with different units.")
+      })
+    };
+
+    Ok(Self::new(
+      add("left", &self.left, &other.left)?,
+      add("top", &self.top, &other.top)?,
+      add("right", &self.right, &other.right)?,
+      add("bottom", &self.bottom, &other.bottom)?,
+    ))
+  }
+
+  /// Resolves each side of the delta to pixels, relative to the given
+  /// reference rect. Left and right sides are relative to its width, and
+  /// top and bottom sides to its height.
+  ///
+  /// Returns a tuple of the left, top, right and bottom pixel values.
+  #[must_use]
+  pub fn to_px_edges(
+    &self,
+    reference: &Rect,
+    scale_factor: Option<f32>,
+  ) -> (i32, i32, i32, i32) {
+    let min_axis = Some(reference.width().min(reference.height()));
+    let to_px = |length: &LengthValue, total_px| {
+      length.to_px_with_min_axis(total_px, min_axis, scale_factor)
+    };
+
+    (
+      to_px(&self.left, reference.width()),
+      to_px(&self.top, reference.height()),
+      to_px(&self.right, reference.width()),
+      to_px(&self.bottom, reference.height()),
+    )
+  }
+
+  /// Checks if the rectangle delta has a value greater than 1px, or any
+  /// positive percentage, for any of its sides.
   #[must_use]
   pub fn is_significant(&self) -> bool {
-    self.bottom.amount > 1.0
-      || self.top.amount > 1.0
-      || self.left.amount > 1.0
-      || self.right.amount > 1.0
+    [&self.left, &self.top, &self.right, &self.bottom]
+      .into_iter()
+      .any(|length| match length.unit {
+        LengthUnit::Pixel => length.amount > 1.0,
+        LengthUnit::Percentage | LengthUnit::MinAxisPercent => {
+          length.amount > 0.0
+        }
+      })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn px_delta(left: i32, top: i32, right: i32, bottom: i32) -> RectDelta {
+    RectDelta::new(
+      LengthValue::from_px(left),
+      LengthValue::from_px(top),
+      LengthValue::from_px(right),
+      LengthValue::from_px(bottom),
+    )
+  }
+
+  fn assert_px_eq(delta: &RectDelta, expected: [i32; 4]) {
+    let actual = [&delta.left, &delta.top, &delta.right, &delta.bottom]
+      .map(|length| length.to_px(0, None));
+
+    assert_eq!(actual, expected);
+  }
+
+  #[test]
+  fn test_combine_px_deltas() {
+    let combined = px_delta(1, 2, 3, 4)
+      .combine(&px_delta(10, 20, 30, 40))
+      .unwrap();
+
+    assert_px_eq(&combined, [11, 22, 33, 44]);
+  }
+
+  #[test]
+  fn test_combine_with_zero() {
+    let delta = px_delta(5, -5, 5, -5);
+
+    assert_px_eq(
+      &delta.combine(&RectDelta::zero()).unwrap(),
+      [5, -5, 5, -5],
+    );
+    assert_px_eq(
+      &RectDelta::zero().combine(&delta).unwrap(),
+      [5, -5, 5, -5],
+    );
+  }
+
+  #[test]
+  fn test_combine_mixed_units() {
+    let percent = LengthValue {
+      amount: 0.1,
+      unit: LengthUnit::Percentage,
+    };
+
+    let percent_delta = RectDelta::new(
+      percent.clone(),
+      LengthValue::from_px(0),
+      percent.clone(),
+      LengthValue::from_px(0),
+    );
+
+    // Zero-valued px sides don't conflict with percentages.
+    let combined = percent_delta.combine(&RectDelta::zero()).unwrap();
+    assert_eq!(combined.left, percent);
+    assert_eq!(combined.right, percent);
+
+    // Non-zero px + % is rejected rather than silently mis-summed.
+    assert!(percent_delta.combine(&px_delta(1, 0, 0, 0)).is_err());
+  }
+
+  #[test]
+  fn test_percentage_delta_is_significant() {
+    let percent_delta = RectDelta::new(
+      LengthValue {
+        amount: 0.02,
+        unit: LengthUnit::Percentage,
+      },
+      LengthValue::from_px(0),
+      LengthValue::from_px(0),
+      LengthValue::from_px(0),
+    );
+
+    assert!(percent_delta.is_significant());
+    assert!(!px_delta(1, 1, 0, 0).is_significant());
+    assert!(px_delta(0, 2, 0, 0).is_significant());
+  }
+
+  #[test]
+  fn test_to_px_edges_mixed_units() {
+    let percent = |amount| LengthValue {
+      amount,
+      unit: LengthUnit::Percentage,
+    };
+
+    let delta = RectDelta::new(
+      LengthValue::from_px(10),
+      percent(0.1),
+      percent(0.05),
+      LengthValue::from_px(-4),
+    );
+
+    let reference = Rect::from_xy(0, 0, 1920, 1080);
+    assert_eq!(delta.to_px_edges(&reference, None), (10, 108, 96, -4));
+
+    // Only pixel values are scaled.
+    assert_eq!(
+      delta.to_px_edges(&reference, Some(1.5)),
+      (15, 108, 96, -6)
+    );
+
+    // Edges match how `apply_delta` resolves them.
+    let (left, top, right, bottom) = delta.to_px_edges(&reference, None);
+    assert_eq!(
+      reference.apply_delta(&delta, None),
+      Rect::from_ltrb(-left, -top, 1920 + right, 1080 + bottom)
+    );
   }
 }
@@ -0,0 +1,148 @@
+use crate::{Anchor, Direction, Rect};
+
+/// Snap slot that a floating window was last moved to via the
+/// `cycle-snap` command.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SnapCycle {
+  /// Direction that the window is being cycled in.
+  pub direction: Direction,
+
+  /// Index of the current slot within `SnapCycle::anchors`.
+  pub slot: usize,
+
+  /// Rect of the current slot.
+  pub rect: Rect,
+}
+
+impl SnapCycle {
+  /// Gets the anchors of the slots to cycle through in the given
+  /// direction. The first slot is the half on that side, followed by its
+  /// two quarters.
+  #[must_use]
+  pub fn anchors(direction: &Direction) -> [Anchor; 3] {
+    match direction {
+      Direction::Left => {
+        [Anchor::Left, Anchor::TopLeft, Anchor::BottomLeft]
+      }
+      Direction::Right => {
+        [Anchor::Right, Anchor::TopRight, Anchor::BottomRight]
+      }
+      Direction::Up => [Anchor::Top, Anchor::TopLeft, Anchor::TopRight],
+      Direction::Down => {
+        [Anchor::Bottom, Anchor::BottomLeft, Anchor::BottomRight]
+      }
+    }
+  }
+
+  /// Advances to the next slot within `rect`.
+  ///
+  /// The cycle restarts from the first slot if there is no current cycle,
+  /// if the direction has changed, or if the window has been moved away
+  /// from its slot since (i.e. `window_rect` differs from the slot's
+  /// rect).
+  #[must_use]
+  pub fn next(
+    current: Option<&SnapCycle>,
+    direction: &Direction,
+    window_rect: &Rect,
+    rect: &Rect,
+    horizontal_gap: i32,
+    vertical_gap: i32,
+  ) -> SnapCycle {
+    let anchors = Self::anchors(direction);
+
+    let slot = current
+      .filter(|cycle| {
+        cycle.direction == *direction && cycle.rect == *window_rect
+      })
+      .map_or(0, |cycle| (cycle.slot + 1) % anchors.len());
+
+    SnapCycle {
+      direction: direction.clone(),
+      slot,
+      rect: rect.quadrant(anchors[slot], horizontal_gap, vertical_gap),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_cycle_advances_through_slots() {
+    let rect = Rect::from_xy(0, 0, 1920, 1080);
+    let mut window_rect = Rect::from_xy(100, 100, 800, 600);
+    let mut cycle = None;
+    let mut rects = Vec::new();
+
+    for _ in 0..3 {
+      let next = SnapCycle::next(
+        cycle.as_ref(),
+        &Direction::Left,
+        &window_rect,
+        &rect,
+        0,
+        0,
+      );
+
+      window_rect = next.rect.clone();
+      rects.push(next.rect.clone());
+      cycle = Some(next);
+    }
+
+    assert_eq!(
+      rects,
+      vec![
+        Rect::from_xy(0, 0, 960, 1080),
+        Rect::from_xy(0, 0, 960, 540),
+        Rect::from_xy(0, 540, 960, 540),
+      ]
+    );
+
+    // A fourth press wraps around to the first slot.
+    let next = SnapCycle::next(
+      cycle.as_ref(),
+      &Direction::Left,
+      &window_rect,
+      &rect,
+      0,
+      0,
+    );
+    assert_eq!(next.slot, 0);
+  }
+
+  #[test]
+  fn test_cycle_resets_when_moved() {
+    let rect = Rect::from_xy(0, 0, 1920, 1080);
+    let cycle = SnapCycle {
+      direction: Direction::Right,
+      slot: 1,
+      rect: Rect::from_xy(960, 0, 960, 540),
+    };
+
+    // Window is no longer at its slot's rect.
+    let moved_rect = Rect::from_xy(900, 10, 960, 540);
+    let next = SnapCycle::next(
+      Some(&cycle),
+      &Direction::Right,
+      &moved_rect,
+      &rect,
+      0,
+      0,
+    );
+    assert_eq!(next.slot, 0);
+
+    // Changing direction also restarts the cycle.
+    let next = SnapCycle::next(
+      Some(&cycle),
+      &Direction::Up,
+      &cycle.rect,
+      &rect,
+      0,
+      0,
+    );
+    assert_eq!(next.slot, 0);
+    assert_eq!(next.rect, Rect::from_xy(0, 0, 1920, 540));
+  }
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Rect;
+
+/// Edges of a monitor reserved by third-party bars and docks, in pixels.
+///
+/// Windows are not tiled over reserved edges.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Struts {
+  pub left: i32,
+  pub top: i32,
+  pub right: i32,
+  pub bottom: i32,
+}
+
+impl Struts {
+  /// Shrinks the rect to exclude the reserved edges.
+  #[must_use]
+  pub fn apply(&self, rect: &Rect) -> Rect {
+    rect.inset_ltrb(self.left, self.top, self.right, self.bottom)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Direction;
+
+  #[test]
+  fn test_top_strut_shifts_tiled_windows() {
+    let rect = Rect::from_xy(0, 0, 1920, 1080);
+    let struts = Struts {
+      top: 30,
+      ..Default::default()
+    };
+
+    let windows = rect.split_n(3, &Direction::Right, 10);
+    let strut_windows =
+      struts.apply(&rect).split_n(3, &Direction::Right, 10);
+
+    for (window, strut_window) in windows.iter().zip(&strut_windows) {
+      assert_eq!(strut_window.x(), window.x());
+      assert_eq!(strut_window.y(), window.y() + 30);
+      assert_eq!(strut_window.bottom, window.bottom);
+    }
+  }
+
+  #[test]
+  fn test_no_struts_is_noop() {
+    let rect = Rect::from_xy(100, 50, 800, 600);
+    assert_eq!(Struts::default().apply(&rect), rect);
+  }
+}
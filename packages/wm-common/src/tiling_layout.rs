@@ -0,0 +1,417 @@
+use std::ops::RangeInclusive;
+
+use serde::{Deserialize, Deserializer, Serialize};
+use uuid::Uuid;
+
+use crate::{Direction, Rect, TilingDirection};
+
+/// Strategy for arranging the tiling windows of a workspace.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TilingLayout {
+  /// Windows are arranged by their tiling sizes along the workspace's
+  /// tiling direction, with nested split containers.
+  #[default]
+  Manual,
+
+  /// Each window takes half of the remaining space, alternating between
+  /// horizontal and vertical splits.
+  Spiral,
+
+  /// Windows are arranged by binary space partitioning, where the
+  /// workspace is recursively halved along its longer axis.
+  Bsp,
+
+  /// One or more master windows take up a fraction of the workspace on
+  /// the left, and the remaining windows are stacked on the right.
+  MasterStack {
+    /// Fraction of the workspace's width taken up by the master windows.
+    /// Must be within `MASTER_FRACTION_RANGE`.
+    #[serde(
+      default = "default_master_fraction",
+      deserialize_with = "deserialize_master_fraction"
+    )]
+    master_fraction: f32,
+
+    /// Number of windows in the master area.
+    #[serde(default = "default_master_count")]
+    master_count: usize,
+  },
+}
+
+/// Range that the master fraction of a master-stack layout is limited
+/// to, so that neither side of the layout collapses.
+pub const MASTER_FRACTION_RANGE: RangeInclusive<f32> = 0.1..=0.9;
+
+fn default_master_fraction() -> f32 {
+  0.5
+}
+
+fn deserialize_master_fraction<'de, D>(
+  deserializer: D,
+) -> Result<f32, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let master_fraction = f32::deserialize(deserializer)?;
+
+  if !MASTER_FRACTION_RANGE.contains(&master_fraction) {
+    return Err(serde::de::Error::custom(format!(
+      "Master fraction must be between {} and {}, got {master_fraction}.",
+      MASTER_FRACTION_RANGE.start(),
+      MASTER_FRACTION_RANGE.end(),
+    )));
+  }
+
+  Ok(master_fraction)
+}
+
+fn default_master_count() -> usize {
+  1
+}
+
+impl TilingLayout {
+  /// Gets the rect of each window for the layout, in the same order as
+  /// `window_ids`.
+  ///
+  /// Returns `None` for `TilingLayout::Manual`, since its rects are
+  /// derived from the container tree instead.
+  #[must_use]
+  pub fn window_rects(
+    &self,
+    rect: &Rect,
+    window_ids: &[Uuid],
+    horizontal_gap: i32,
+    vertical_gap: i32,
+  ) -> Option<Vec<(Uuid, Rect)>> {
+    match self {
+      Self::Manual => None,
+      Self::Spiral => Some(spiral_layout(
+        rect,
+        window_ids,
+        horizontal_gap,
+        vertical_gap,
+      )),
+      Self::Bsp => {
+        Some(bsp_layout(rect, window_ids, horizontal_gap, vertical_gap))
+      }
+      Self::MasterStack {
+        master_fraction,
+        master_count,
+      } => Some(master_stack_layout(
+        rect,
+        window_ids,
+        *master_fraction,
+        *master_count,
+        horizontal_gap,
+        vertical_gap,
+      )),
+    }
+  }
+}
+
+/// Lays out windows in a spiral, where each window takes half of the
+/// remaining space and the split axis alternates between horizontal and
+/// vertical. The last window fills whatever space is left.
+///
+/// `horizontal_gap` is inserted between side-by-side windows, and
+/// `vertical_gap` between stacked windows.
+#[must_use]
+pub fn spiral_layout(
+  rect: &Rect,
+  window_ids: &[Uuid],
+  horizontal_gap: i32,
+  vertical_gap: i32,
+) -> Vec<(Uuid, Rect)> {
+  let mut remaining_rect = rect.clone();
+  let mut window_rects = Vec::with_capacity(window_ids.len());
+
+  for (index, window_id) in window_ids.iter().enumerate() {
+    if index == window_ids.len() - 1 {
+      window_rects.push((*window_id, remaining_rect));
+      break;
+    }
+
+    let (tiling_direction, gap) = if index % 2 == 0 {
+      (TilingDirection::Horizontal, horizontal_gap)
+    } else {
+      (TilingDirection::Vertical, vertical_gap)
+    };
+
+    let mut halves =
+      remaining_rect.split_weighted(&[0.5, 0.5], &tiling_direction, gap);
+
+    remaining_rect = halves.remove(1);
+    window_rects.push((*window_id, halves.remove(0)));
+  }
+
+  window_rects
+}
+
+/// Lays out windows by binary space partitioning (see `Rect::tile_bsp`).
+#[must_use]
+pub fn bsp_layout(
+  rect: &Rect,
+  window_ids: &[Uuid],
+  horizontal_gap: i32,
+  vertical_gap: i32,
+) -> Vec<(Uuid, Rect)> {
+  window_ids
+    .iter()
+    .copied()
+    .zip(rect.tile_bsp(window_ids.len(), horizontal_gap, vertical_gap))
+    .collect()
+}
+
+/// Lays out the first `master_count` windows stacked vertically on the
+/// left, taking up `master_fraction` of the width. The remaining windows
+/// are evenly stacked on the right.
+///
+/// Either side fills the whole rect if the other side has no windows.
+#[must_use]
+pub fn master_stack_layout(
+  rect: &Rect,
+  window_ids: &[Uuid],
+  master_fraction: f32,
+  master_count: usize,
+  horizontal_gap: i32,
+  vertical_gap: i32,
+) -> Vec<(Uuid, Rect)> {
+  let master_count = master_count.min(window_ids.len());
+  let stack_count = window_ids.len() - master_count;
+
+  let (master_rect, stack_rect) = if master_count == 0 || stack_count == 0
+  {
+    (rect.clone(), rect.clone())
+  } else {
+    let master_fraction = master_fraction.clamp(0.0, 1.0);
+
+    let mut halves = rect.split_weighted(
+      &[master_fraction, 1.0 - master_fraction],
+      &TilingDirection::Horizontal,
+      horizontal_gap,
+    );
+
+    (halves.remove(0), halves.remove(0))
+  };
+
+  let master_rects =
+    master_rect.split_n(master_count, &Direction::Down, vertical_gap);
+
+  let stack_rects =
+    stack_rect.split_n(stack_count, &Direction::Down, vertical_gap);
+
+  window_ids
+    .iter()
+    .copied()
+    .zip(master_rects.into_iter().chain(stack_rects))
+    .collect()
+}
+
+/// Lays out windows as tabs, where every window takes up the whole rect.
+///
+/// Only the active tab is visible, but inactive tabs keep the full rect
+/// so that they don't need resizing when switched to. They're hidden via
+/// their display state instead, since giving them an empty rect would
+/// resize them to the minimum window size.
+#[must_use]
+pub fn tabbed_layout(
+  rect: &Rect,
+  window_ids: &[Uuid],
+) -> Vec<(Uuid, Rect)> {
+  window_ids
+    .iter()
+    .map(|window_id| (*window_id, rect.clone()))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn spiral_rects(count: usize) -> Vec<Rect> {
+    let window_ids =
+      (0..count).map(|_| Uuid::new_v4()).collect::<Vec<_>>();
+    let rects =
+      spiral_layout(&Rect::from_xy(0, 0, 1000, 800), &window_ids, 10, 20);
+
+    // Rects are returned in the same order as the given IDs.
+    assert!(rects.iter().map(|(id, _)| id).eq(window_ids.iter()));
+
+    rects.into_iter().map(|(_, rect)| rect).collect()
+  }
+
+  #[test]
+  fn test_spiral_single_window() {
+    assert_eq!(spiral_rects(1), vec![Rect::from_xy(0, 0, 1000, 800)]);
+  }
+
+  #[test]
+  fn test_spiral_two_windows() {
+    assert_eq!(
+      spiral_rects(2),
+      vec![
+        Rect::from_xy(0, 0, 495, 800),
+        Rect::from_xy(505, 0, 495, 800),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_spiral_three_windows() {
+    assert_eq!(
+      spiral_rects(3),
+      vec![
+        Rect::from_xy(0, 0, 495, 800),
+        Rect::from_xy(505, 0, 495, 390),
+        Rect::from_xy(505, 410, 495, 390),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_spiral_five_windows() {
+    assert_eq!(
+      spiral_rects(5),
+      vec![
+        Rect::from_xy(0, 0, 495, 800),
+        Rect::from_xy(505, 0, 495, 390),
+        Rect::from_xy(505, 410, 243, 390),
+        Rect::from_xy(758, 410, 242, 185),
+        Rect::from_xy(758, 615, 242, 185),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_manual_layout_has_no_rects() {
+    let rect = Rect::from_xy(0, 0, 100, 100);
+    assert_eq!(
+      TilingLayout::Manual.window_rects(&rect, &[Uuid::new_v4()], 0, 0),
+      None
+    );
+  }
+
+  #[test]
+  fn test_master_stack_layout() {
+    let window_ids = (0..4).map(|_| Uuid::new_v4()).collect::<Vec<_>>();
+    let rects = master_stack_layout(
+      &Rect::from_xy(0, 0, 1000, 800),
+      &window_ids,
+      0.6,
+      1,
+      10,
+      20,
+    );
+
+    assert!(rects.iter().map(|(id, _)| id).eq(window_ids.iter()));
+    assert_eq!(
+      rects.into_iter().map(|(_, rect)| rect).collect::<Vec<_>>(),
+      vec![
+        Rect::from_xy(0, 0, 594, 800),
+        Rect::from_xy(604, 0, 396, 254),
+        Rect::from_xy(604, 274, 396, 253),
+        Rect::from_xy(604, 547, 396, 253),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_master_fraction_out_of_range_is_rejected() {
+    let parse = |json: &str| serde_json::from_str::<TilingLayout>(json);
+
+    assert!(
+      parse(r#"{ "master_stack": { "master_fraction": 1.5 } }"#).is_err()
+    );
+    assert!(
+      parse(r#"{ "master_stack": { "master_fraction": 0 } }"#).is_err()
+    );
+    assert_eq!(
+      parse(r#"{ "master_stack": { "master_fraction": 0.6 } }"#).unwrap(),
+      TilingLayout::MasterStack {
+        master_fraction: 0.6,
+        master_count: 1,
+      }
+    );
+  }
+
+  #[test]
+  fn test_master_stack_single_side() {
+    let rect = Rect::from_xy(0, 0, 1000, 800);
+    let window_ids = (0..2).map(|_| Uuid::new_v4()).collect::<Vec<_>>();
+
+    // All windows are masters, so they take up the full width.
+    let rects = master_stack_layout(&rect, &window_ids, 0.6, 3, 10, 0);
+    assert_eq!(rects[0].1, Rect::from_xy(0, 0, 1000, 400));
+    assert_eq!(rects[1].1, Rect::from_xy(0, 400, 1000, 400));
+
+    // No masters, so the stack takes up the full width.
+    let rects = master_stack_layout(&rect, &window_ids, 0.6, 0, 10, 0);
+    assert_eq!(rects[0].1, Rect::from_xy(0, 0, 1000, 400));
+  }
+
+  #[test]
+  fn test_bsp_layout_wide_monitor() {
+    let window_ids = (0..4).map(|_| Uuid::new_v4()).collect::<Vec<_>>();
+    let rects =
+      bsp_layout(&Rect::from_xy(0, 0, 1920, 1080), &window_ids, 10, 20);
+
+    assert!(rects.iter().map(|(id, _)| id).eq(window_ids.iter()));
+
+    // First split is side-by-side since the monitor is wide, after which
+    // each half is taller than it is wide and is split vertically.
+    assert_eq!(
+      rects.into_iter().map(|(_, rect)| rect).collect::<Vec<_>>(),
+      vec![
+        Rect::from_xy(0, 0, 955, 530),
+        Rect::from_xy(0, 550, 955, 530),
+        Rect::from_xy(965, 0, 955, 530),
+        Rect::from_xy(965, 550, 955, 530),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_bsp_layout_alternates_by_dimension() {
+    let rect = Rect::from_xy(0, 0, 1920, 1080);
+
+    // Remaining half of 3 windows is split vertically, since it's taller
+    // than it is wide.
+    assert_eq!(
+      rect.tile_bsp(3, 0, 0),
+      vec![
+        Rect::from_xy(0, 0, 960, 1080),
+        Rect::from_xy(960, 0, 960, 540),
+        Rect::from_xy(960, 540, 960, 540),
+      ]
+    );
+
+    // Quarters of an ultrawide monitor stay wider than they are tall, so
+    // the halves are split side-by-side again.
+    let rect = Rect::from_xy(0, 0, 5120, 1440);
+    assert_eq!(
+      rect.tile_bsp(4, 0, 0),
+      vec![
+        Rect::from_xy(0, 0, 1280, 1440),
+        Rect::from_xy(1280, 0, 1280, 1440),
+        Rect::from_xy(2560, 0, 1280, 1440),
+        Rect::from_xy(3840, 0, 1280, 1440),
+      ]
+    );
+
+    assert!(rect.tile_bsp(0, 0, 0).is_empty());
+  }
+
+  #[test]
+  fn test_tabbed_layout_keeps_full_rect_for_all_tabs() {
+    let rect = Rect::from_xy(100, 50, 1000, 800);
+    let window_ids = (0..3).map(|_| Uuid::new_v4()).collect::<Vec<_>>();
+
+    let rects = tabbed_layout(&rect, &window_ids);
+    assert!(rects.iter().map(|(id, _)| id).eq(window_ids.iter()));
+
+    // Inactive tabs aren't shrunk to an empty rect, which would otherwise
+    // be clamped to a 1px window when applying border deltas.
+    assert!(rects.iter().all(|(_, window_rect)| *window_rect == rect));
+  }
+}
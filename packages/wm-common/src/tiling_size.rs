@@ -0,0 +1,468 @@
+use uuid::Uuid;
+
+use crate::LengthValue;
+
+/// Resizes the tiling size at `index` to `target_size`, redistributing
+/// the difference amongst its siblings.
+///
+/// No size is shrunk below `min_size`. Siblings give up or receive space
+/// in proportion to how far they are above the minimum (i.e. larger
+/// containers are shrunk more). If every sibling is already at the
+/// minimum, the size at `index` can't grow any further.
+///
+/// Returns the resulting sizes in the same order as `sizes`.
+#[must_use]
+pub fn resize_tiling_sizes(
+  sizes: &[f32],
+  index: usize,
+  target_size: f32,
+  min_size: f32,
+) -> Vec<f32> {
+  let Some(current_size) = sizes.get(index).copied() else {
+    return sizes.to_vec();
+  };
+
+  // Ignore cases where the container is the only child.
+  let sibling_count = sizes.len() - 1;
+  if sibling_count == 0 {
+    return vec![1.];
+  }
+
+  // A minimum that can't be satisfied by every container is lowered to
+  // an even split.
+  #[allow(clippy::cast_precision_loss)]
+  let min_size = min_size.min(1. / sizes.len() as f32);
+
+  // Get available tiling size amongst siblings.
+  let available_size = sizes
+    .iter()
+    .enumerate()
+    .filter(|(sibling_index, _)| *sibling_index != index)
+    .map(|(_, size)| (size - min_size).max(0.))
+    .sum::<f32>();
+
+  // Prevent the container from being smaller than the minimum size, and
+  // larger than the space available from sibling containers.
+  let clamped_target_size =
+    target_size.max(min_size).min(current_size + available_size);
+
+  let size_delta = clamped_target_size - current_size;
+
+  sizes
+    .iter()
+    .enumerate()
+    .map(|(sibling_index, size)| {
+      if sibling_index == index {
+        return clamped_target_size;
+      }
+
+      // Get percentage of resize that affects this sibling. Freed up
+      // space is split evenly if all siblings are at the minimum.
+      #[allow(clippy::cast_precision_loss)]
+      let resize_factor = if available_size > 0. {
+        (size - min_size).max(0.) / available_size
+      } else {
+        1. / sibling_count as f32
+      };
+
+      size - resize_factor * size_delta
+    })
+    .collect()
+}
+
+/// Resizes the tiling size at `index` by `delta`, redistributing the
+/// difference amongst its siblings.
+///
+/// Pixel deltas are resolved against `available_length`, which is the
+/// parent's length along the resize axis excluding gaps. Sizes are
+/// clamped the same way as in `resize_tiling_sizes`.
+#[must_use]
+pub fn resize_tiling_sizes_by(
+  sizes: &[f32],
+  index: usize,
+  delta: &LengthValue,
+  available_length: i32,
+  min_size: f32,
+) -> Vec<f32> {
+  let Some(current_size) = sizes.get(index) else {
+    return sizes.to_vec();
+  };
+
+  let target_size = current_size + delta.to_percentage(available_length);
+  resize_tiling_sizes(sizes, index, target_size, min_size)
+}
+
+/// Moves the edge between the tiling sizes at `index` and
+/// `neighbor_index` by `size_delta`, such that only the neighbor gives up
+/// (or receives) space. A positive delta grows the size at `index`.
+///
+/// Neither size is shrunk below `min_size`. Returns the resulting sizes
+/// in the same order as `sizes`.
+#[must_use]
+pub fn resize_tiling_edge(
+  sizes: &[f32],
+  index: usize,
+  neighbor_index: usize,
+  size_delta: f32,
+  min_size: f32,
+) -> Vec<f32> {
+  let (Some(size), Some(neighbor_size)) =
+    (sizes.get(index), sizes.get(neighbor_index))
+  else {
+    return sizes.to_vec();
+  };
+
+  if index == neighbor_index {
+    return sizes.to_vec();
+  }
+
+  #[allow(clippy::cast_precision_loss)]
+  let min_size = min_size.min(1. / sizes.len() as f32);
+
+  let clamped_delta = size_delta
+    .min((neighbor_size - min_size).max(0.))
+    .max(-(size - min_size).max(0.));
+
+  let mut resized_sizes = sizes.to_vec();
+  resized_sizes[index] += clamped_delta;
+  resized_sizes[neighbor_index] -= clamped_delta;
+  resized_sizes
+}
+
+/// Grows the tiling sizes at `indices` evenly to consume any space that
+/// isn't taken up by the other sizes, so that all sizes add up to 1.
+///
+/// Returns the resulting sizes in the same order as `sizes`.
+#[must_use]
+pub fn grow_to_fill(sizes: &[f32], indices: &[usize]) -> Vec<f32> {
+  let indices = indices
+    .iter()
+    .copied()
+    .filter(|index| *index < sizes.len())
+    .collect::<Vec<_>>();
+
+  if indices.is_empty() {
+    return sizes.to_vec();
+  }
+
+  let free_size = 1. - sizes.iter().sum::<f32>();
+
+  #[allow(clippy::cast_precision_loss)]
+  let size_delta = free_size / indices.len() as f32;
+
+  sizes
+    .iter()
+    .enumerate()
+    .map(|(index, size)| {
+      if indices.contains(&index) {
+        (size + size_delta).max(0.)
+      } else {
+        *size
+      }
+    })
+    .collect()
+}
+
+/// Scales the tiling sizes such that they add up to 1, keeping their
+/// relative proportions.
+///
+/// Sizes are split evenly if they don't add up to a positive total.
+#[must_use]
+pub fn normalize_tiling_sizes(sizes: &[f32]) -> Vec<f32> {
+  let total_size = sizes.iter().map(|size| size.max(0.)).sum::<f32>();
+
+  if total_size <= 0. {
+    #[allow(clippy::cast_precision_loss)]
+    return vec![1. / sizes.len() as f32; sizes.len()];
+  }
+
+  sizes.iter().map(|size| size.max(0.) / total_size).collect()
+}
+
+/// Raises any tiling size below `min_size` up to the minimum, taking the
+/// difference from the sizes above the minimum in proportion to how far
+/// they are above it.
+///
+/// A minimum that can't be satisfied by every container is lowered to
+/// an even split.
+#[must_use]
+pub fn clamp_tiling_sizes(sizes: &[f32], min_size: f32) -> Vec<f32> {
+  let total_size = sizes.iter().sum::<f32>();
+
+  #[allow(clippy::cast_precision_loss)]
+  let min_size = min_size.min(total_size / sizes.len() as f32);
+
+  let deficit = sizes
+    .iter()
+    .map(|size| (min_size - size).max(0.))
+    .sum::<f32>();
+
+  let excess = sizes
+    .iter()
+    .map(|size| (size - min_size).max(0.))
+    .sum::<f32>();
+
+  if deficit <= 0. || excess <= 0. {
+    return sizes.to_vec();
+  }
+
+  sizes
+    .iter()
+    .map(|size| {
+      if *size < min_size {
+        min_size
+      } else {
+        size - (size - min_size) / excess * deficit
+      }
+    })
+    .collect()
+}
+
+/// Gets the tiling sizes to restore a container and its siblings to,
+/// after the container is re-inserted at its previous position (e.g.
+/// when exiting fullscreen).
+///
+/// `prev_sizes` are the IDs and tiling sizes of the container and its
+/// siblings before the container was removed, and `current_ids` are the
+/// IDs of the container and its siblings after it's re-inserted.
+///
+/// Returns `None` if the siblings have changed in the meantime, since the
+/// previous sizes then no longer apply.
+#[must_use]
+pub fn restored_tiling_sizes(
+  prev_sizes: &[(Uuid, f32)],
+  current_ids: &[Uuid],
+) -> Option<Vec<f32>> {
+  let is_unchanged = prev_sizes.len() == current_ids.len()
+    && prev_sizes
+      .iter()
+      .zip(current_ids)
+      .all(|((prev_id, _), id)| prev_id == id);
+
+  is_unchanged.then(|| prev_sizes.iter().map(|(_, size)| *size).collect())
+}
+
+/// Removes the tiling size at `index`, and grows its adjacent siblings
+/// to evenly consume the freed up space.
+///
+/// Returns the sizes of the remaining siblings in their original order.
+#[must_use]
+pub fn remove_tiling_size(sizes: &[f32], index: usize) -> Vec<f32> {
+  if index >= sizes.len() {
+    return sizes.to_vec();
+  }
+
+  let mut remaining_sizes = sizes.to_vec();
+  remaining_sizes.remove(index);
+
+  // After the removal, the previous sibling is at `index - 1` and the
+  // next sibling has shifted into `index`.
+  let neighbor_indices = index
+    .checked_sub(1)
+    .into_iter()
+    .chain((index < remaining_sizes.len()).then_some(index))
+    .collect::<Vec<_>>();
+
+  grow_to_fill(&remaining_sizes, &neighbor_indices)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Direction, LengthUnit, Rect, TilingDirection};
+
+  fn assert_sizes_eq(actual: &[f32], expected: &[f32]) {
+    assert_eq!(actual.len(), expected.len());
+
+    for (actual, expected) in actual.iter().zip(expected) {
+      assert!(
+        (actual - expected).abs() < 1e-5,
+        "{actual:?} != {expected:?}"
+      );
+    }
+  }
+
+  #[test]
+  fn test_resize_stops_at_min_size() {
+    let sizes = resize_tiling_sizes(&[0.2, 0.5, 0.3], 0, 0., 0.1);
+
+    // The shrinking container stops at the minimum, and its siblings
+    // absorb the freed up space in proportion to their size.
+    assert_sizes_eq(&sizes, &[0.1, 0.566_667, 0.333_333]);
+    assert!((sizes.iter().sum::<f32>() - 1.).abs() < 1e-5);
+  }
+
+  #[test]
+  fn test_resize_keeps_siblings_above_min_size() {
+    let sizes = resize_tiling_sizes(&[0.4, 0.3, 0.3], 0, 1., 0.1);
+    assert_sizes_eq(&sizes, &[0.8, 0.1, 0.1]);
+  }
+
+  #[test]
+  fn test_resize_is_noop_when_siblings_at_min_size() {
+    let sizes = resize_tiling_sizes(&[0.8, 0.1, 0.1], 0, 0.9, 0.1);
+    assert_sizes_eq(&sizes, &[0.8, 0.1, 0.1]);
+  }
+
+  #[test]
+  fn test_resize_single_container() {
+    assert_sizes_eq(&resize_tiling_sizes(&[0.7], 0, 0.5, 0.1), &[1.]);
+  }
+
+  #[test]
+  fn test_resize_unsatisfiable_min_size() {
+    let sizes = resize_tiling_sizes(&[0.5, 0.5], 0, 0.2, 0.8);
+    assert_sizes_eq(&sizes, &[0.5, 0.5]);
+  }
+
+  #[test]
+  fn test_resize_by_percentage() {
+    let delta = LengthValue {
+      amount: 0.1,
+      unit: LengthUnit::Percentage,
+    };
+
+    let sizes =
+      resize_tiling_sizes_by(&[0.5, 0.3, 0.2], 0, &delta, 1000, 0.);
+    assert_sizes_eq(&sizes, &[0.6, 0.24, 0.16]);
+  }
+
+  #[test]
+  fn test_resize_by_pixels() {
+    let sizes = resize_tiling_sizes_by(
+      &[0.5, 0.5],
+      1,
+      &LengthValue::from_px(100),
+      1000,
+      0.,
+    );
+    assert_sizes_eq(&sizes, &[0.4, 0.6]);
+
+    let sizes = resize_tiling_sizes_by(
+      &[0.5, 0.5],
+      1,
+      &LengthValue::from_px(-100),
+      1000,
+      0.,
+    );
+    assert_sizes_eq(&sizes, &[0.6, 0.4]);
+  }
+
+  #[test]
+  fn test_resize_by_clamps_to_min_size() {
+    let sizes = resize_tiling_sizes_by(
+      &[0.5, 0.5],
+      0,
+      &LengthValue::from_px(800),
+      1000,
+      0.1,
+    );
+    assert_sizes_eq(&sizes, &[0.9, 0.1]);
+  }
+
+  #[test]
+  fn test_clamp_raises_sizes_below_min_size() {
+    let sizes = clamp_tiling_sizes(&[0.02, 0.49, 0.49], 0.1);
+    assert_sizes_eq(&sizes, &[0.1, 0.45, 0.45]);
+  }
+
+  #[test]
+  fn test_clamp_keeps_sizes_above_min_size() {
+    let sizes = clamp_tiling_sizes(&[0.2, 0.3, 0.5], 0.1);
+    assert_sizes_eq(&sizes, &[0.2, 0.3, 0.5]);
+  }
+
+  #[test]
+  fn test_remove_middle_splits_space_evenly() {
+    let sizes = remove_tiling_size(&[0.2, 0.5, 0.3], 1);
+    assert_sizes_eq(&sizes, &[0.45, 0.55]);
+
+    let sizes = remove_tiling_size(&[1. / 3., 1. / 3., 1. / 3.], 1);
+    assert_sizes_eq(&sizes, &[0.5, 0.5]);
+  }
+
+  #[test]
+  fn test_remove_edge_grows_single_neighbor() {
+    let sizes = remove_tiling_size(&[0.2, 0.5, 0.3], 0);
+    assert_sizes_eq(&sizes, &[0.7, 0.3]);
+
+    let sizes = remove_tiling_size(&[0.2, 0.5, 0.3], 2);
+    assert_sizes_eq(&sizes, &[0.2, 0.8]);
+
+    assert!(remove_tiling_size(&[1.], 0).is_empty());
+  }
+
+  #[test]
+  fn test_grow_to_fill() {
+    let sizes = grow_to_fill(&[0.2, 0.3, 0.1], &[1]);
+    assert_sizes_eq(&sizes, &[0.2, 0.7, 0.1]);
+
+    // Already filled.
+    let sizes = grow_to_fill(&[0.5, 0.5], &[0]);
+    assert_sizes_eq(&sizes, &[0.5, 0.5]);
+  }
+
+  #[test]
+  fn test_resize_edge_shrinks_only_neighbor() {
+    let sizes = [0.3, 0.4, 0.3];
+    let rects = Rect::from_xy(0, 0, 1000, 500).split_weighted(
+      &sizes,
+      &TilingDirection::Horizontal,
+      0,
+    );
+
+    // Neighbor across the middle tile's right edge.
+    let neighbor_index = rects
+      .iter()
+      .position(|rect| {
+        rects[1].shared_edge(rect) == Some(Direction::Right)
+      })
+      .unwrap();
+
+    assert_eq!(neighbor_index, 2);
+
+    let sizes = resize_tiling_edge(&sizes, 1, neighbor_index, 0.1, 0.);
+    assert_sizes_eq(&sizes, &[0.3, 0.5, 0.2]);
+
+    // Clamped such that the neighbor stays above the minimum size.
+    let sizes = resize_tiling_edge(&sizes, 1, 2, 0.5, 0.1);
+    assert_sizes_eq(&sizes, &[0.3, 0.6, 0.1]);
+
+    // Negative deltas give space back to the neighbor.
+    let sizes = resize_tiling_edge(&sizes, 1, 2, -0.2, 0.1);
+    assert_sizes_eq(&sizes, &[0.3, 0.4, 0.3]);
+  }
+
+  #[test]
+  fn test_normalize_tiling_sizes() {
+    let sizes = normalize_tiling_sizes(&[0.6, 0.6]);
+    assert_sizes_eq(&sizes, &[0.5, 0.5]);
+
+    let sizes = normalize_tiling_sizes(&[0.25, 0.5, 0.]);
+    assert_sizes_eq(&sizes, &[1. / 3., 2. / 3., 0.]);
+
+    let sizes = normalize_tiling_sizes(&[0., -0.5]);
+    assert_sizes_eq(&sizes, &[0.5, 0.5]);
+
+    assert!(normalize_tiling_sizes(&[]).is_empty());
+  }
+
+  #[test]
+  fn test_restored_tiling_sizes() {
+    let ids = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+    let prev_sizes =
+      ids.iter().copied().zip([0.5, 0.3, 0.2]).collect::<Vec<_>>();
+
+    let restored_sizes = restored_tiling_sizes(&prev_sizes, &ids).unwrap();
+    assert_sizes_eq(&restored_sizes, &[0.5, 0.3, 0.2]);
+
+    // The previous sizes no longer apply if a window was added or
+    // reordered while fullscreen.
+    let added_ids = [ids[0], ids[1], ids[2], Uuid::new_v4()];
+    assert_eq!(restored_tiling_sizes(&prev_sizes, &added_ids), None);
+    assert_eq!(
+      restored_tiling_sizes(&prev_sizes, &[ids[1], ids[0], ids[2]]),
+      None
+    );
+  }
+}
@@ -8,6 +8,12 @@ where
   ///
   /// Inserts at index if value doesn't already exist in the `VecDeque`.
   fn shift_to_index(&mut self, target_index: usize, item: T);
+
+  /// Replaces the first occurrence of a value in a `VecDeque` with
+  /// another value at the same index.
+  ///
+  /// Returns whether the value was found.
+  fn replace(&mut self, value: &T, replacement: T) -> bool;
 }
 
 impl<T> VecDequeExt<T> for VecDeque<T>
@@ -23,4 +29,32 @@ where
       self.insert(target_index.clamp(0, self.len()), value);
     }
   }
+
+  fn replace(&mut self, value: &T, replacement: T) -> bool {
+    if let Some(index) = self.iter().position(|e| e == value) {
+      self[index] = replacement;
+      true
+    } else {
+      false
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_replace_swaps_across_deques() {
+    let mut a = VecDeque::from([1, 2, 3]);
+    let mut b = VecDeque::from([4, 5]);
+
+    assert!(a.replace(&2, 5));
+    assert!(b.replace(&5, 2));
+    assert_eq!(a, [1, 5, 3]);
+    assert_eq!(b, [4, 2]);
+
+    assert!(!a.replace(&9, 0));
+    assert_eq!(a, [1, 5, 3]);
+  }
 }
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+/// Where new tiling windows are inserted in the container tree.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WindowInsertion {
+  /// Insert as the next sibling of the focused tiling window. Falls back
+  /// to the most recently focused tiling window in the workspace if a
+  /// non-tiling window is focused.
+  #[default]
+  AfterFocused,
+
+  /// Append as the last child of the workspace.
+  End,
+}
+
+impl WindowInsertion {
+  /// Gets the index to insert a new window at within its parent.
+  ///
+  /// `focused_index` is the index of the tiling window to insert next to
+  /// (if any), and `child_count` is the number of children the parent
+  /// currently has.
+  #[must_use]
+  pub fn insertion_index(
+    &self,
+    focused_index: Option<usize>,
+    child_count: usize,
+  ) -> usize {
+    match (self, focused_index) {
+      (WindowInsertion::AfterFocused, Some(focused_index)) => {
+        (focused_index + 1).min(child_count)
+      }
+      _ => child_count,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_insertion_index() {
+    let insertion = WindowInsertion::AfterFocused;
+    assert_eq!(insertion.insertion_index(Some(0), 3), 1);
+    assert_eq!(insertion.insertion_index(Some(2), 3), 3);
+
+    // Without a focused window, windows are appended regardless.
+    assert_eq!(insertion.insertion_index(None, 3), 3);
+    assert_eq!(WindowInsertion::End.insertion_index(Some(0), 3), 3);
+  }
+}
@@ -4,7 +4,7 @@ use uuid::Uuid;
 use crate::{
   dtos::ContainerDto,
   parsed_config::{BindingModeConfig, ParsedConfig},
-  TilingDirection,
+  Rect, SubscribableEvent, TilingDirection,
 };
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -18,12 +18,27 @@ pub enum WmEvent {
   BindingModesChanged {
     new_binding_modes: Vec<BindingModeConfig>,
   },
+  ContainerRectChanged {
+    container_id: Uuid,
+    /// Rect prior to the change. `None` if the container hasn't been
+    /// positioned before.
+    old_rect: Option<Rect>,
+    new_rect: Rect,
+  },
   FocusChanged {
     focused_container: ContainerDto,
   },
   FocusedContainerMoved {
     focused_container: ContainerDto,
   },
+  /// Emitted when the focus ring overlay should be moved, which happens
+  /// on focus changes and when the focused window's rect changes.
+  FocusRingChanged {
+    /// ID of the focused window. `None` if no window has focus.
+    focused_window_id: Option<Uuid>,
+    /// Rect of the overlay. `None` if the overlay should be hidden.
+    overlay_rect: Option<Rect>,
+  },
   MonitorAdded {
     added_monitor: ContainerDto,
   },
@@ -64,3 +79,91 @@ pub enum WmEvent {
     is_paused: bool,
   },
 }
+
+impl WmEvent {
+  /// Gets the event type that subscriptions are filtered by.
+  #[must_use]
+  pub fn event_type(&self) -> SubscribableEvent {
+    match self {
+      WmEvent::ApplicationExiting => SubscribableEvent::ApplicationExiting,
+      WmEvent::BindingModesChanged { .. } => {
+        SubscribableEvent::BindingModesChanged
+      }
+      WmEvent::ContainerRectChanged { .. } => {
+        SubscribableEvent::ContainerRectChanged
+      }
+      WmEvent::FocusChanged { .. } => SubscribableEvent::FocusChanged,
+      WmEvent::FocusedContainerMoved { .. } => {
+        SubscribableEvent::FocusedContainerMoved
+      }
+      WmEvent::FocusRingChanged { .. } => {
+        SubscribableEvent::FocusRingChanged
+      }
+      WmEvent::MonitorAdded { .. } => SubscribableEvent::MonitorAdded,
+      WmEvent::MonitorUpdated { .. } => SubscribableEvent::MonitorUpdated,
+      WmEvent::MonitorRemoved { .. } => SubscribableEvent::MonitorRemoved,
+      WmEvent::TilingDirectionChanged { .. } => {
+        SubscribableEvent::TilingDirectionChanged
+      }
+      WmEvent::UserConfigChanged { .. } => {
+        SubscribableEvent::UserConfigChanged
+      }
+      WmEvent::WindowManaged { .. } => SubscribableEvent::WindowManaged,
+      WmEvent::WindowUnmanaged { .. } => {
+        SubscribableEvent::WindowUnmanaged
+      }
+      WmEvent::WorkspaceActivated { .. } => {
+        SubscribableEvent::WorkspaceActivated
+      }
+      WmEvent::WorkspaceDeactivated { .. } => {
+        SubscribableEvent::WorkspaceDeactivated
+      }
+      WmEvent::WorkspaceUpdated { .. } => {
+        SubscribableEvent::WorkspaceUpdated
+      }
+      WmEvent::PauseChanged { .. } => SubscribableEvent::PauseChanged,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use clap::ValueEnum;
+
+  use super::*;
+
+  #[test]
+  fn test_container_rect_changed_subscription() {
+    let event = WmEvent::ContainerRectChanged {
+      container_id: Uuid::nil(),
+      old_rect: Some(Rect::from_xy(0, 0, 400, 600)),
+      new_rect: Rect::from_xy(0, 0, 800, 600),
+    };
+
+    // Subscriptions to `container_rect_changed` receive the event.
+    let subscribed_event =
+      SubscribableEvent::from_str("container_rect_changed", false)
+        .unwrap();
+
+    assert_eq!(event.event_type(), subscribed_event);
+  }
+
+  #[test]
+  fn test_container_rect_changed_shape() {
+    let event = WmEvent::ContainerRectChanged {
+      container_id: Uuid::nil(),
+      old_rect: None,
+      new_rect: Rect::from_xy(0, 0, 800, 600),
+    };
+
+    assert_eq!(
+      serde_json::to_value(&event).unwrap(),
+      serde_json::json!({
+        "eventType": "container_rect_changed",
+        "containerId": "00000000-0000-0000-0000-000000000000",
+        "oldRect": null,
+        "newRect": { "left": 0, "top": 0, "right": 800, "bottom": 600 },
+      })
+    );
+  }
+}
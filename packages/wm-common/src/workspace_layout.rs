@@ -0,0 +1,178 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Rect, TilingDirection};
+
+/// Snapshot of a workspace's tiling tree, which can be saved to a file
+/// and restored later.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceLayout {
+  /// Name of the workspace that the layout was saved from.
+  pub workspace_name: String,
+
+  /// Tiling direction of the workspace.
+  pub tiling_direction: TilingDirection,
+
+  /// Tiling children of the workspace.
+  pub children: Vec<LayoutNode>,
+}
+
+/// Node in a saved layout tree.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LayoutNode {
+  Split(SplitLayoutNode),
+  Window(WindowLayoutNode),
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SplitLayoutNode {
+  pub tiling_size: f32,
+  pub tiling_direction: TilingDirection,
+  pub children: Vec<LayoutNode>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowLayoutNode {
+  pub tiling_size: f32,
+
+  /// Process name, class name and title are used for matching the node
+  /// to a running window when the layout is restored.
+  pub process_name: String,
+  pub class_name: String,
+  pub title: String,
+
+  /// Rect of the window at the time the layout was saved.
+  pub rect: Rect,
+}
+
+impl LayoutNode {
+  #[must_use]
+  pub fn tiling_size(&self) -> f32 {
+    match self {
+      LayoutNode::Split(split) => split.tiling_size,
+      LayoutNode::Window(window) => window.tiling_size,
+    }
+  }
+
+  pub fn set_tiling_size(&mut self, tiling_size: f32) {
+    match self {
+      LayoutNode::Split(split) => split.tiling_size = tiling_size,
+      LayoutNode::Window(window) => window.tiling_size = tiling_size,
+    }
+  }
+}
+
+impl WindowLayoutNode {
+  /// Gets how well a running window matches this node, where a higher
+  /// score is a better match.
+  ///
+  /// Returns `None` if the process or class names differ. A matching
+  /// title scores higher, but a differing title still matches, since
+  /// titles often change between sessions (e.g. the open file in an
+  /// editor).
+  #[must_use]
+  pub fn match_score(
+    &self,
+    process_name: &str,
+    class_name: &str,
+    title: &str,
+  ) -> Option<u8> {
+    if self.process_name != process_name || self.class_name != class_name {
+      return None;
+    }
+
+    Some(u8::from(self.title == title))
+  }
+}
+
+impl WorkspaceLayout {
+  /// Gets the window nodes of the layout in depth-first order.
+  #[must_use]
+  pub fn windows(&self) -> Vec<&WindowLayoutNode> {
+    fn collect<'a>(
+      nodes: &'a [LayoutNode],
+      windows: &mut Vec<&'a WindowLayoutNode>,
+    ) {
+      for node in nodes {
+        match node {
+          LayoutNode::Split(split) => collect(&split.children, windows),
+          LayoutNode::Window(window) => windows.push(window),
+        }
+      }
+    }
+
+    let mut windows = Vec::new();
+    collect(&self.children, &mut windows);
+    windows
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn window_node(tiling_size: f32, title: &str) -> LayoutNode {
+    LayoutNode::Window(WindowLayoutNode {
+      tiling_size,
+      process_name: "code".to_string(),
+      class_name: "Chrome_WidgetWin_1".to_string(),
+      title: title.to_string(),
+      rect: Rect::from_xy(0, 0, 100, 100),
+    })
+  }
+
+  #[test]
+  fn test_layout_round_trip() {
+    // Layout of H[1 V[2 3]].
+    let layout = WorkspaceLayout {
+      workspace_name: "1".to_string(),
+      tiling_direction: TilingDirection::Horizontal,
+      children: vec![
+        window_node(0.6, "a.rs"),
+        LayoutNode::Split(SplitLayoutNode {
+          tiling_size: 0.4,
+          tiling_direction: TilingDirection::Vertical,
+          children: vec![
+            window_node(0.25, "b.rs"),
+            window_node(0.75, "c.rs"),
+          ],
+        }),
+      ],
+    };
+
+    let json = serde_json::to_string(&layout).unwrap();
+    let restored = serde_json::from_str::<WorkspaceLayout>(&json).unwrap();
+    assert_eq!(restored, layout);
+    assert_eq!(
+      restored
+        .windows()
+        .iter()
+        .map(|window| window.title.as_str())
+        .collect::<Vec<_>>(),
+      vec!["a.rs", "b.rs", "c.rs"]
+    );
+  }
+
+  #[test]
+  fn test_window_match_score() {
+    let LayoutNode::Window(node) = window_node(1.0, "a.rs") else {
+      unreachable!();
+    };
+
+    assert_eq!(
+      node.match_score("code", "Chrome_WidgetWin_1", "a.rs"),
+      Some(1)
+    );
+    assert_eq!(
+      node.match_score("code", "Chrome_WidgetWin_1", "b.rs"),
+      Some(0)
+    );
+    assert_eq!(
+      node.match_score("explorer", "Chrome_WidgetWin_1", "a.rs"),
+      None
+    );
+  }
+}
@@ -12,6 +12,7 @@ mod native_window;
 mod platform;
 mod single_instance;
 mod window_event_hook;
+mod windows_rect;
 
 pub use com::*;
 pub use event_listener::*;
@@ -22,3 +23,4 @@ pub use native_window::*;
 pub use platform::*;
 pub use single_instance::*;
 pub use window_event_hook::*;
+pub use windows_rect::*;
@@ -11,12 +11,16 @@ use windows::{
     },
     UI::{
       HiDpi::{GetDpiForMonitor, MDT_EFFECTIVE_DPI},
-      WindowsAndMessaging::EDD_GET_DEVICE_INTERFACE_NAME,
+      WindowsAndMessaging::{
+        EDD_GET_DEVICE_INTERFACE_NAME, MONITORINFOF_PRIMARY,
+      },
     },
   },
 };
 use wm_common::Rect;
 
+use super::WindowsRectExt;
+
 #[derive(Clone, Debug)]
 pub struct NativeMonitor {
   pub handle: isize,
@@ -30,6 +34,7 @@ struct MonitorInfo {
   hardware_id: Option<String>,
   rect: Rect,
   working_rect: Rect,
+  is_primary: bool,
   dpi: u32,
   scale_factor: f32,
 }
@@ -43,6 +48,32 @@ impl NativeMonitor {
     }
   }
 
+  /// Creates a `NativeMonitor` with prefilled monitor info, so that
+  /// it can be used without querying the OS. The monitor is primary if
+  /// its top-left corner is at 0,0.
+  #[cfg(feature = "test-util")]
+  #[must_use]
+  pub fn new_mock(
+    handle: isize,
+    rect: Rect,
+    working_rect: Rect,
+    hardware_id: Option<String>,
+  ) -> Self {
+    let info = OnceCell::new();
+    let _ = info.set(MonitorInfo {
+      device_name: format!("\\\\.\\DISPLAY{handle}"),
+      device_path: None,
+      hardware_id,
+      is_primary: rect.left == 0 && rect.top == 0,
+      rect,
+      working_rect,
+      dpi: 96,
+      scale_factor: 1.0,
+    });
+
+    Self { handle, info }
+  }
+
   pub fn device_name(&self) -> anyhow::Result<&String> {
     self.monitor_info().map(|info| &info.device_name)
   }
@@ -63,6 +94,12 @@ impl NativeMonitor {
     self.monitor_info().map(|info| &info.working_rect)
   }
 
+  /// Whether this is the primary monitor, which has its top-left corner
+  /// at 0,0.
+  pub fn is_primary(&self) -> anyhow::Result<bool> {
+    self.monitor_info().map(|info| info.is_primary)
+  }
+
   pub fn dpi(&self) -> anyhow::Result<u32> {
     self.monitor_info().map(|info| info.dpi)
   }
@@ -137,21 +174,12 @@ impl NativeMonitor {
       #[allow(clippy::cast_precision_loss)]
       let scale_factor = dpi as f32 / 96.0;
 
-      let rc_monitor = monitor_info.monitorInfo.rcMonitor;
-      let rect = Rect::from_ltrb(
-        rc_monitor.left,
-        rc_monitor.top,
-        rc_monitor.right,
-        rc_monitor.bottom,
-      );
-
-      let rc_work = monitor_info.monitorInfo.rcWork;
-      let working_rect = Rect::from_ltrb(
-        rc_work.left,
-        rc_work.top,
-        rc_work.right,
-        rc_work.bottom,
-      );
+      let rect =
+        Rect::from_windows_rect(&monitor_info.monitorInfo.rcMonitor);
+      let working_rect =
+        Rect::from_windows_rect(&monitor_info.monitorInfo.rcWork);
+      let is_primary =
+        monitor_info.monitorInfo.dwFlags & MONITORINFOF_PRIMARY != 0;
 
       Ok(MonitorInfo {
         device_name,
@@ -159,6 +187,7 @@ impl NativeMonitor {
         hardware_id,
         rect,
         working_rect,
+        is_primary,
         dpi,
         scale_factor,
       })
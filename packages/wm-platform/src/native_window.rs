@@ -1,3 +1,5 @@
+#[cfg(feature = "test-util")]
+use std::sync::{Arc, Mutex, PoisonError};
 use std::time::Duration;
 
 use anyhow::{bail, Context};
@@ -46,7 +48,7 @@ use wm_common::{
   Rect, RectDelta, WindowState,
 };
 
-use super::COM_INIT;
+use super::{WindowsRectExt, COM_INIT};
 
 /// Magic number used to identify programmatic mouse inputs from our own
 /// process.
@@ -60,6 +62,15 @@ pub enum ZOrder {
   TopMost,
 }
 
+/// Positions set on a mocked window, along with the window state they
+/// were set with.
+#[cfg(feature = "test-util")]
+type MockPositions = Arc<Mutex<Vec<(WindowState, Rect)>>>;
+
+/// Alpha values set on a mocked window via `set_transparency`.
+#[cfg(feature = "test-util")]
+type MockTransparencies = Arc<Mutex<Vec<u8>>>;
+
 #[derive(Clone, Debug)]
 pub struct NativeWindow {
   pub handle: isize,
@@ -70,6 +81,15 @@ pub struct NativeWindow {
   border_position: Memo<Rect>,
   is_minimized: Memo<bool>,
   is_maximized: Memo<bool>,
+
+  /// Positions set on a mocked window, in the order they were set. The
+  /// OS isn't called when this is present.
+  #[cfg(feature = "test-util")]
+  mock_positions: Option<MockPositions>,
+
+  /// Alpha values set on a mocked window, in the order they were set.
+  #[cfg(feature = "test-util")]
+  mock_transparencies: Option<MockTransparencies>,
 }
 
 impl NativeWindow {
@@ -85,9 +105,78 @@ impl NativeWindow {
       border_position: Memo::new(),
       is_minimized: Memo::new(),
       is_maximized: Memo::new(),
+      #[cfg(feature = "test-util")]
+      mock_positions: None,
+      #[cfg(feature = "test-util")]
+      mock_transparencies: None,
     }
   }
 
+  /// Creates a `NativeWindow` with prefilled cached values, so that it
+  /// can be used without querying the OS.
+  #[cfg(feature = "test-util")]
+  #[must_use]
+  pub fn new_mock(
+    handle: isize,
+    title: &str,
+    process_name: &str,
+    class_name: &str,
+    frame_position: Rect,
+  ) -> Self {
+    let mut window = Self::new(handle);
+    window.mock_positions = Some(Arc::default());
+    window.mock_transparencies = Some(Arc::default());
+
+    let _ = window.title.update(|()| Ok(title.to_string()), &());
+    let _ = window
+      .process_name
+      .update(|()| Ok(process_name.to_string()), &());
+    let _ = window
+      .class_name
+      .update(|()| Ok(class_name.to_string()), &());
+    let _ = window
+      .frame_position
+      .update(|()| Ok(frame_position.clone()), &());
+    let _ = window.border_position.update(|()| Ok(frame_position), &());
+    let _ = window.is_minimized.update(|()| Ok(false), &());
+    let _ = window.is_maximized.update(|()| Ok(false), &());
+    window
+  }
+
+  /// Gets the positions set on a mocked window.
+  ///
+  /// # Panics
+  ///
+  /// If the window isn't mocked.
+  #[cfg(feature = "test-util")]
+  #[must_use]
+  pub fn mock_positions(&self) -> Vec<(WindowState, Rect)> {
+    self
+      .mock_positions
+      .as_ref()
+      .expect("Window is not mocked.")
+      .lock()
+      .unwrap_or_else(PoisonError::into_inner)
+      .clone()
+  }
+
+  /// Gets the alpha values set on a mocked window.
+  ///
+  /// # Panics
+  ///
+  /// If the window isn't mocked.
+  #[cfg(feature = "test-util")]
+  #[must_use]
+  pub fn mock_transparencies(&self) -> Vec<u8> {
+    self
+      .mock_transparencies
+      .as_ref()
+      .expect("Window is not mocked.")
+      .lock()
+      .unwrap_or_else(PoisonError::into_inner)
+      .clone()
+  }
+
   /// Gets the window's title. If the window is invalid, returns an empty
   /// string.
   ///
@@ -475,6 +564,15 @@ impl NativeWindow {
     &self,
     opacity_value: &OpacityValue,
   ) -> anyhow::Result<()> {
+    #[cfg(feature = "test-util")]
+    if let Some(mock_transparencies) = &self.mock_transparencies {
+      mock_transparencies
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(opacity_value.to_alpha());
+      return Ok(());
+    }
+
     // Make the window layered if it isn't already.
     self.add_window_style_ex(WS_EX_LAYERED);
 
@@ -525,12 +623,7 @@ impl NativeWindow {
     };
 
     if let Ok(()) = dwm_res {
-      Ok(Rect::from_ltrb(
-        rect.left,
-        rect.top,
-        rect.right,
-        rect.bottom,
-      ))
+      Ok(Rect::from_windows_rect(&rect))
     } else {
       warn!("Failed to get window's frame position. Falling back to border position.");
       self.border_position()
@@ -566,12 +659,7 @@ impl NativeWindow {
       )
     }?;
 
-    Ok(Rect::from_ltrb(
-      rect.left,
-      rect.top,
-      rect.right,
-      rect.bottom,
-    ))
+    Ok(Rect::from_windows_rect(&rect))
   }
 
   /// Gets the delta between the window's frame and the window's border.
@@ -612,12 +700,7 @@ impl NativeWindow {
       length: std::mem::size_of::<WINDOWPLACEMENT>() as u32,
       flags: WPF_ASYNCWINDOWPLACEMENT,
       showCmd: SW_RESTORE.0 as u32,
-      rcNormalPosition: RECT {
-        left: rect.left,
-        top: rect.top,
-        right: rect.right,
-        bottom: rect.bottom,
-      },
+      rcNormalPosition: rect.to_windows_rect(),
       ..Default::default()
     };
 
@@ -724,6 +807,15 @@ impl NativeWindow {
     hide_method: &HideMethod,
     has_pending_dpi_adjustment: bool,
   ) -> anyhow::Result<()> {
+    #[cfg(feature = "test-util")]
+    if let Some(mock_positions) = &self.mock_positions {
+      mock_positions
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push((state.clone(), rect.clone()));
+      return Ok(());
+    }
+
     // Restore window if it's minimized/maximized and shouldn't be. This is
     // needed to be able to move and resize it.
     match state {
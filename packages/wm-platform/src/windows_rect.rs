@@ -0,0 +1,52 @@
+use windows::Win32::Foundation::RECT;
+use wm_common::Rect;
+
+/// Conversions between `Rect` and the Win32 `RECT`.
+///
+/// This is an extension trait, since `wm-common` is kept free of
+/// platform-specific types.
+pub trait WindowsRectExt {
+  /// Converts to a Win32 `RECT` with the same edges.
+  fn to_windows_rect(&self) -> RECT;
+
+  /// Creates a rect from the edges of a Win32 `RECT`.
+  fn from_windows_rect(rect: &RECT) -> Self;
+}
+
+impl WindowsRectExt for Rect {
+  fn to_windows_rect(&self) -> RECT {
+    RECT {
+      left: self.left,
+      top: self.top,
+      right: self.right,
+      bottom: self.bottom,
+    }
+  }
+
+  fn from_windows_rect(rect: &RECT) -> Self {
+    Rect::from_ltrb(rect.left, rect.top, rect.right, rect.bottom)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_windows_rect_round_trip() {
+    let rect = Rect::from_xy(-1920, 40, 1920, 1040);
+    let windows_rect = rect.to_windows_rect();
+
+    assert_eq!(
+      windows_rect,
+      RECT {
+        left: -1920,
+        top: 40,
+        right: 0,
+        bottom: 1080,
+      }
+    );
+
+    assert_eq!(Rect::from_windows_rect(&windows_rect), rect);
+  }
+}
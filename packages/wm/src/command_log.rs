@@ -0,0 +1,235 @@
+use std::{
+  fs::OpenOptions,
+  io::Write,
+  path::{Path, PathBuf},
+  time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Context;
+use tracing::warn;
+use wm_common::{latest_layouts, CommandLogEntry, InvokeCommand};
+
+use crate::{
+  commands::workspace::{
+    activate_workspace, apply_workspace_layout, workspace_layout,
+  },
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// File that mutating commands are recorded to, with one JSON-encoded
+/// `CommandLogEntry` per line.
+pub struct CommandLog {
+  path: PathBuf,
+}
+
+impl CommandLog {
+  pub fn new(path: PathBuf) -> Self {
+    Self { path }
+  }
+
+  /// Appends an entry for the given commands to the log, along with the
+  /// layouts of the displayed workspaces. Non-mutating commands are
+  /// skipped.
+  pub fn record(
+    &self,
+    commands: &[InvokeCommand],
+    state: &WmState,
+  ) -> anyhow::Result<()> {
+    let commands = commands
+      .iter()
+      .filter(|command| command.is_mutating())
+      .map(|command| format!("{command:?}"))
+      .collect::<Vec<_>>();
+
+    if commands.is_empty() {
+      return Ok(());
+    }
+
+    let entry = CommandLogEntry {
+      timestamp: SystemTime::now()
+        .duration_since(UNIX_EPOCH)?
+        .as_millis()
+        .try_into()?,
+      commands,
+      layouts: state
+        .monitors()
+        .into_iter()
+        .filter_map(|monitor| monitor.displayed_workspace())
+        .map(|workspace| workspace_layout(&workspace))
+        .try_collect()?,
+    };
+
+    let mut file = OpenOptions::new()
+      .create(true)
+      .append(true)
+      .open(&self.path)
+      .with_context(|| {
+        format!("Failed to open command log '{}'.", self.path.display())
+      })?;
+
+    writeln!(file, "{}", serde_json::to_string(&entry)?)?;
+
+    Ok(())
+  }
+}
+
+/// Restores the layouts from a command log recorded with `CommandLog`,
+/// such that each workspace in the log ends up with the layout it had at
+/// the end of the recording. The recorded commands aren't re-run.
+///
+/// Workspaces that aren't active are activated first. Windows are
+/// matched the same way as when restoring a saved layout.
+pub fn restore_command_log_file(
+  path: &Path,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let contents = std::fs::read_to_string(path).with_context(|| {
+    format!("Failed to read command log '{}'.", path.display())
+  })?;
+
+  let entries = contents
+    .lines()
+    .filter(|line| !line.trim().is_empty())
+    .map(serde_json::from_str::<CommandLogEntry>)
+    .try_collect::<Vec<_>>()
+    .context("Failed to parse command log.")?;
+
+  for layout in latest_layouts(&entries) {
+    let workspace_name = &layout.workspace_name;
+
+    if state.workspace_by_name(workspace_name).is_none() {
+      if let Err(err) =
+        activate_workspace(Some(workspace_name), None, state, config)
+      {
+        warn!("Skipping layout for workspace '{workspace_name}': {err:?}");
+        continue;
+      }
+    }
+
+    let workspace = state
+      .workspace_by_name(workspace_name)
+      .context("Workspace not found.")?;
+
+    apply_workspace_layout(&workspace, &layout, state, config)?;
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use std::str::FromStr;
+
+  use wm_common::{InvokeCommand, InvokeResizeCommand, LengthValue, Rect};
+
+  use super::*;
+  use crate::{
+    commands::{
+      container::{resize_tiling_container, toggle_tiling_direction},
+      window::swap_windows,
+    },
+    test_utils::{
+      add_test_monitor, add_test_window_with_title, displayed_workspace,
+      test_config, test_state, window_by_title,
+    },
+    traits::{CommonGetters, PositionGetters},
+  };
+
+  const TITLES: [&str; 3] = ["a", "b", "c"];
+
+  fn window_rects(state: &WmState) -> Vec<Rect> {
+    TITLES
+      .iter()
+      .map(|title| window_by_title(state, title).to_rect().unwrap())
+      .collect()
+  }
+
+  #[test]
+  fn test_restore_command_log_reproduces_layout() {
+    let config = test_config();
+    let path = std::env::temp_dir()
+      .join(format!("glazewm-command-log-{}.jsonl", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let log = CommandLog::new(path.clone());
+
+    let mut state = test_state();
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1200, 600),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    for title in TITLES {
+      add_test_window_with_title(
+        title,
+        &workspace.clone().into(),
+        &config,
+      );
+    }
+
+    // Wrap "b" in a vertical split, widen "a", then swap "a" and "c"
+    // across the split.
+    toggle_tiling_direction(
+      window_by_title(&state, "b").into(),
+      &mut state,
+      &config,
+    )
+    .unwrap();
+    log
+      .record(&[InvokeCommand::ToggleTilingDirection], &state)
+      .unwrap();
+
+    resize_tiling_container(&window_by_title(&state, "a").into(), 0.5);
+    log
+      .record(
+        &[InvokeCommand::Resize(InvokeResizeCommand {
+          width: Some(LengthValue::from_str("50%").unwrap()),
+          height: None,
+        })],
+        &state,
+      )
+      .unwrap();
+
+    let target = window_by_title(&state, "c");
+    swap_windows(&window_by_title(&state, "a"), &target, &mut state)
+      .unwrap();
+    log
+      .record(
+        &[InvokeCommand::SwapWindows {
+          target_id: target.id(),
+        }],
+        &state,
+      )
+      .unwrap();
+
+    let live_rects = window_rects(&state);
+
+    // Restore into a fresh state, where the windows are opened in the
+    // reverse order.
+    let mut restored_state = test_state();
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1200, 600),
+      &mut restored_state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    for title in TITLES.iter().rev() {
+      add_test_window_with_title(
+        title,
+        &workspace.clone().into(),
+        &config,
+      );
+    }
+
+    assert_ne!(window_rects(&restored_state), live_rects);
+
+    restore_command_log_file(&path, &mut restored_state, &config).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(window_rects(&restored_state), live_rects);
+  }
+}
@@ -0,0 +1,184 @@
+use anyhow::Context;
+
+use super::flatten_split_container;
+use crate::{
+  models::Container,
+  traits::{CommonGetters, TilingDirectionGetters},
+};
+
+/// Replaces any split containers within the given container that only
+/// have a single child with that child.
+///
+/// The child inherits the tiling size of the split container. If the
+/// child is itself a split container with the same tiling direction as
+/// its new parent, it gets flattened as well.
+///
+/// For example:
+/// ```ignore,compile_fail
+/// H[1 V[2]] -> H[1 2]
+/// H[1 V[H[2 3]]] -> H[1 2 3]
+/// ```
+pub fn collapse_single_child_splits(
+  container: &Container,
+) -> anyhow::Result<()> {
+  for child in container.children() {
+    collapse_single_child_splits(&child)?;
+  }
+
+  let Some(split_container) = container
+    .as_split()
+    .filter(|split| !split.is_tabbed() && split.child_count() == 1)
+    .cloned()
+  else {
+    return Ok(());
+  };
+
+  let parent = split_container.parent().context("No parent.")?;
+  let child = split_container
+    .children()
+    .front()
+    .cloned()
+    .context("No child.")?;
+
+  flatten_split_container(split_container)?;
+
+  if let (Some(split_child), Ok(parent)) =
+    (child.as_split(), parent.as_direction_container())
+  {
+    if !split_child.is_tabbed()
+      && split_child.tiling_direction() == parent.tiling_direction()
+    {
+      flatten_split_container(split_child.clone())?;
+    }
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{Rect, TilingDirection};
+
+  use super::*;
+  use crate::{
+    commands::window::unmanage_window,
+    models::TilingWindow,
+    test_utils::{
+      add_test_monitor, add_test_split, add_test_window,
+      displayed_workspace, test_config, test_state,
+    },
+    traits::{PositionGetters, TilingSizeGetters},
+  };
+
+  fn child_ids(container: &Container) -> Vec<uuid::Uuid> {
+    container.children().iter().map(CommonGetters::id).collect()
+  }
+
+  fn window_rects(windows: &[&TilingWindow]) -> Vec<Rect> {
+    windows
+      .iter()
+      .map(|window| window.to_rect().unwrap())
+      .collect()
+  }
+
+  #[test]
+  #[allow(clippy::float_cmp)]
+  fn test_collapse_nested_single_child_split() {
+    let mut state = test_state();
+    let config = test_config();
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1000, 800),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    // Tree of H[1 V[H[2 3]]].
+    let window_1 = add_test_window(&workspace.clone().into(), &config);
+    let split_v = add_test_split(
+      &workspace.clone().into(),
+      TilingDirection::Vertical,
+      &config,
+    );
+    let split_h = add_test_split(
+      &split_v.clone().into(),
+      TilingDirection::Horizontal,
+      &config,
+    );
+    let window_2 = add_test_window(&split_h.clone().into(), &config);
+    let window_3 = add_test_window(&split_h.clone().into(), &config);
+
+    collapse_single_child_splits(&workspace.clone().into()).unwrap();
+
+    assert_eq!(
+      child_ids(&workspace.clone().into()),
+      vec![window_1.id(), window_2.id(), window_3.id()]
+    );
+    assert_eq!(
+      [&window_1, &window_2, &window_3]
+        .map(TilingSizeGetters::tiling_size),
+      [0.5, 0.25, 0.25]
+    );
+    assert_eq!(
+      window_rects(&[&window_1, &window_2, &window_3]),
+      vec![
+        Rect::from_xy(0, 0, 500, 800),
+        Rect::from_xy(500, 0, 250, 800),
+        Rect::from_xy(750, 0, 250, 800),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_closing_windows_collapses_splits() {
+    let mut state = test_state();
+    let config = test_config();
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1920, 1080),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    // Tree of H[1 V[2 H[3 4]]].
+    let window_1 = add_test_window(&workspace.clone().into(), &config);
+    let split_v = add_test_split(
+      &workspace.clone().into(),
+      TilingDirection::Vertical,
+      &config,
+    );
+    let window_2 = add_test_window(&split_v.clone().into(), &config);
+    let split_h = add_test_split(
+      &split_v.clone().into(),
+      TilingDirection::Horizontal,
+      &config,
+    );
+    let window_3 = add_test_window(&split_h.clone().into(), &config);
+    let window_4 = add_test_window(&split_h.clone().into(), &config);
+
+    // Closing 2 leaves a split with a single child, which is flattened.
+    unmanage_window(window_2.into(), &mut state).unwrap();
+
+    assert!(workspace
+      .descendants()
+      .filter_map(|descendant| descendant.as_split().cloned())
+      .all(|split| split.child_count() > 1));
+    assert_eq!(
+      window_rects(&[&window_1, &window_3, &window_4]),
+      vec![
+        Rect::from_xy(0, 0, 960, 1080),
+        Rect::from_xy(960, 0, 480, 1080),
+        Rect::from_xy(1440, 0, 480, 1080),
+      ]
+    );
+
+    unmanage_window(window_1.into(), &mut state).unwrap();
+    unmanage_window(window_3.into(), &mut state).unwrap();
+
+    assert_eq!(child_ids(&workspace.clone().into()), vec![window_4.id()]);
+    assert_eq!(
+      window_4.to_rect().unwrap(),
+      Rect::from_xy(0, 0, 1920, 1080)
+    );
+  }
+}
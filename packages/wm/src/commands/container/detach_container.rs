@@ -1,15 +1,17 @@
 use anyhow::Context;
+use wm_common::remove_tiling_size;
 
 use super::flatten_split_container;
 use crate::{
   models::Container,
-  traits::{CommonGetters, TilingSizeGetters, MIN_TILING_SIZE},
+  traits::{CommonGetters, TilingSizeGetters},
 };
 
 /// Removes a container from the tree.
 ///
-/// If the container is a tiling container, the siblings will be resized to
-/// fill the freed up space. Will flatten empty parent split containers.
+/// If the container is a tiling container, its adjacent siblings will be
+/// resized to evenly fill the freed up space. Will flatten empty parent
+/// split containers.
 #[allow(clippy::needless_pass_by_value)]
 pub fn detach_container(child_to_remove: Container) -> anyhow::Result<()> {
   // Flatten the parent split container if it'll be empty after removing
@@ -25,6 +27,9 @@ pub fn detach_container(child_to_remove: Container) -> anyhow::Result<()> {
 
   let parent = child_to_remove.parent().context("No parent.")?;
 
+  // Get the container and its siblings before it's removed.
+  let tiling_children = parent.tiling_children().collect::<Vec<_>>();
+
   parent
     .borrow_children_mut()
     .retain(|c| c.id() != child_to_remove.id());
@@ -36,22 +41,21 @@ pub fn detach_container(child_to_remove: Container) -> anyhow::Result<()> {
   *child_to_remove.borrow_parent_mut() = None;
 
   // Resize the siblings if it is a tiling container.
-  if let Ok(child_to_remove) = child_to_remove.as_tiling_container() {
-    let tiling_siblings = parent.tiling_children().collect::<Vec<_>>();
-
-    // TODO: Share logic with `resize_tiling_container`.
-    let available_size =
-      tiling_siblings.iter().fold(0.0, |sum, container| {
-        sum + container.tiling_size() - MIN_TILING_SIZE
-      });
-
-    // Adjust size of the siblings based on the freed up space.
-    for sibling in &tiling_siblings {
-      let resize_factor =
-        (sibling.tiling_size() - MIN_TILING_SIZE) / available_size;
-
-      let size_delta = resize_factor * child_to_remove.tiling_size();
-      sibling.set_tiling_size(sibling.tiling_size() + size_delta);
+  if let Some(index) = tiling_children
+    .iter()
+    .position(|child| child.id() == child_to_remove.id())
+  {
+    let tiling_sizes = tiling_children
+      .iter()
+      .map(TilingSizeGetters::tiling_size)
+      .collect::<Vec<_>>();
+
+    let resized_sizes = remove_tiling_size(&tiling_sizes, index);
+
+    for (sibling, tiling_size) in
+      parent.tiling_children().zip(resized_sizes)
+    {
+      sibling.set_tiling_size(tiling_size);
     }
   }
 
@@ -0,0 +1,107 @@
+use anyhow::Context;
+
+use crate::{
+  models::Container,
+  traits::{CommonGetters, TilingDirectionGetters},
+  wm_state::WmState,
+};
+
+/// Resets the tiling sizes of the children of the container's nearest
+/// direction container, so that they're evenly sized.
+pub fn equalize_sizes(
+  container: &Container,
+  recursive: bool,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let direction_container = container
+    .direction_container()
+    .context("No direction container.")?;
+
+  direction_container.equalize_children(recursive);
+
+  state
+    .pending_sync
+    .queue_container_to_redraw(direction_container);
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{Rect, TilingDirection};
+
+  use super::*;
+  use crate::{
+    models::TilingWindow,
+    test_utils::{
+      add_test_monitor, add_test_split, add_test_window,
+      displayed_workspace, test_config, test_state,
+    },
+    traits::{PositionGetters, TilingSizeGetters},
+  };
+
+  /// Gets the difference between the largest and smallest of the given
+  /// lengths.
+  fn spread(lengths: impl Iterator<Item = i32>) -> i32 {
+    let lengths = lengths.collect::<Vec<_>>();
+    lengths.iter().max().unwrap() - lengths.iter().min().unwrap()
+  }
+
+  #[test]
+  fn test_equalize_sizes() {
+    let mut state = test_state();
+    let config = test_config();
+
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1000, 800),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    // Workspace is laid out as: [window, vertical split, window], where
+    // the split holds three windows.
+    let window_1 = add_test_window(&workspace.clone().into(), &config);
+    let split = add_test_split(
+      &workspace.clone().into(),
+      TilingDirection::Vertical,
+      &config,
+    );
+    let window_2 = add_test_window(&workspace.clone().into(), &config);
+    let nested = (0..3)
+      .map(|_| add_test_window(&split.clone().into(), &config))
+      .collect::<Vec<_>>();
+
+    window_1.set_tiling_size(0.6);
+    split.set_tiling_size(0.3);
+    window_2.set_tiling_size(0.1);
+    nested[0].set_tiling_size(0.5);
+    nested[1].set_tiling_size(0.3);
+    nested[2].set_tiling_size(0.2);
+
+    let widths = || {
+      [window_1.to_rect(), split.to_rect(), window_2.to_rect()]
+        .into_iter()
+        .map(|rect| rect.unwrap().width())
+    };
+    let nested_heights = || {
+      nested
+        .iter()
+        .map(TilingWindow::to_rect)
+        .map(|rect| rect.unwrap().height())
+    };
+
+    assert!(spread(widths()) > 1);
+    assert!(spread(nested_heights()) > 1);
+
+    // Without `--recursive`, only the workspace's children are equalized.
+    equalize_sizes(&window_1.clone().into(), false, &mut state).unwrap();
+    assert!(spread(widths()) <= 1);
+    assert!(spread(nested_heights()) > 1);
+
+    // With `--recursive`, the nested split's children are equalized too.
+    equalize_sizes(&window_1.clone().into(), true, &mut state).unwrap();
+    assert!(spread(widths()) <= 1);
+    assert!(spread(nested_heights()) <= 1);
+  }
+}
@@ -13,6 +13,9 @@ use crate::{
 /// H[1 H[2, 3]] -> H[1, 2, 3]
 /// H[V[1]] -> V[1]
 /// ```
+///
+/// Tabbed split containers are never redundant, since their children are
+/// shown differently from the parent's.
 pub fn flatten_child_split_containers(
   parent: &Container,
 ) -> anyhow::Result<()> {
@@ -27,7 +30,10 @@ pub fn flatten_child_split_containers(
     if tiling_children.len() == 1 {
       // Handle case where the parent is a split container and has a
       // single split container child.
-      if let Some(split_child) = tiling_children[0].as_split() {
+      if let Some(split_child) = tiling_children[0]
+        .as_split()
+        .filter(|split_child| !split_child.is_tabbed())
+      {
         flatten_split_container(split_child.clone())?;
         parent.set_tiling_direction(parent.tiling_direction().inverse());
       }
@@ -38,7 +44,8 @@ pub fn flatten_child_split_containers(
         .collect::<Vec<_>>();
 
       for split_child in split_children.iter().filter(|split_child| {
-        split_child.tiling_direction() == parent.tiling_direction()
+        !split_child.is_tabbed()
+          && split_child.tiling_direction() == parent.tiling_direction()
       }) {
         // Additionally flatten redundant top-level split containers in
         // the child.
@@ -0,0 +1,28 @@
+use anyhow::Context;
+
+use crate::{traits::CommonGetters, wm_state::WmState};
+
+/// Selects the child of the current selection that contains the focused
+/// container. The selection is cleared once it reaches the focused
+/// container.
+pub fn focus_child(state: &mut WmState) -> anyhow::Result<()> {
+  let Some(selection) = state.selected_container() else {
+    state.selection = None;
+    return Ok(());
+  };
+
+  let focused_container =
+    state.focused_container().context("No focused container.")?;
+
+  let child = focused_container.self_and_ancestors().find(|ancestor| {
+    ancestor
+      .parent()
+      .is_some_and(|parent| parent.id() == selection.id())
+  });
+
+  state.selection = child
+    .and_then(|child| child.as_split().cloned())
+    .map(|split| split.id());
+
+  Ok(())
+}
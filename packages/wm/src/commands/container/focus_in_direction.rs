@@ -1,10 +1,15 @@
 use anyhow::Context;
-use wm_common::{Direction, TilingDirection, WindowState};
+use wm_common::{
+  Direction, FocusEdgeBehavior, TilingDirection, WindowState,
+};
 
 use super::set_focused_descendant;
 use crate::{
   models::{Container, TilingContainer},
-  traits::{CommonGetters, TilingDirectionGetters, WindowGetters},
+  traits::{
+    CommonGetters, PositionGetters, TilingDirectionGetters, WindowGetters,
+  },
+  user_config::UserConfig,
   wm_state::WmState,
 };
 
@@ -12,15 +17,24 @@ pub fn focus_in_direction(
   origin_container: &Container,
   direction: &Direction,
   state: &mut WmState,
+  config: &UserConfig,
 ) -> anyhow::Result<()> {
   let focus_target = match origin_container {
     Container::TilingWindow(_) => {
       // If a suitable focus target isn't found in the current workspace,
-      // attempt to find a workspace in the given direction.
-      tiling_focus_target(origin_container, direction)?.map_or_else(
-        || workspace_focus_target(origin_container, direction, state),
-        |container| Ok(Some(container)),
-      )?
+      // fall back to the configured edge behavior.
+      match tiling_focus_target(origin_container, direction)? {
+        Some(container) => Some(container),
+        None => match config.value.general.focus_edge_behavior {
+          FocusEdgeBehavior::Stop => None,
+          FocusEdgeBehavior::Wrap => {
+            wrap_focus_target(origin_container, direction)?
+          }
+          FocusEdgeBehavior::NextMonitor => {
+            workspace_focus_target(origin_container, direction, state)?
+          }
+        },
+      }
     }
     Container::NonTilingWindow(ref non_tiling_window) => {
       match non_tiling_window.state() {
@@ -126,6 +140,35 @@ fn tiling_focus_target(
   Ok(None)
 }
 
+/// Gets the tiling window at the opposite edge of the current workspace,
+/// such that focus wraps around when there's nothing in the given
+/// direction.
+fn wrap_focus_target(
+  origin_container: &Container,
+  direction: &Direction,
+) -> anyhow::Result<Option<Container>> {
+  let workspace = origin_container.workspace().context("No workspace.")?;
+
+  let tiling_windows = workspace
+    .descendants()
+    .filter(|descendant| {
+      descendant.is_tiling_window() && !descendant.is_in_inactive_tab()
+    })
+    .collect::<Vec<_>>();
+
+  let rects = tiling_windows
+    .iter()
+    .map(PositionGetters::to_rect)
+    .try_collect::<Vec<_>>()?;
+
+  Ok(
+    origin_container
+      .to_rect()?
+      .wrapped_in_direction_from(direction, &rects)
+      .map(|index| tiling_windows[index].clone()),
+  )
+}
+
 /// Gets a focus target outside of the current workspace in the given
 /// direction.
 ///
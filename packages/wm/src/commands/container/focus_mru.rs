@@ -0,0 +1,41 @@
+use anyhow::Context;
+
+use super::set_focused_descendant;
+use crate::{models::Container, traits::CommonGetters, wm_state::WmState};
+
+/// Cycles focus through the windows of the origin container's workspace,
+/// in the order they were most recently focused.
+///
+/// Repeated invocations move further back in the focus history, similar
+/// to alt-tab.
+pub fn focus_mru(
+  origin_container: &Container,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let workspace = origin_container.workspace().context("No workspace.")?;
+
+  let window_ids = workspace
+    .descendants()
+    .filter(|descendant| descendant.as_window_container().is_ok())
+    .map(|window| window.id())
+    .collect::<Vec<_>>();
+
+  let Some(mru_stack) = state.mru_stacks.get_mut(&workspace.config().name)
+  else {
+    return Ok(());
+  };
+
+  // Forget windows that have since moved to another workspace.
+  mru_stack.retain(|id| window_ids.contains(id));
+
+  let focus_target = mru_stack
+    .cycle()
+    .and_then(|window_id| state.container_by_id(window_id));
+
+  if let Some(focus_target) = focus_target {
+    set_focused_descendant(&focus_target, None);
+    state.pending_sync.queue_focus_change().queue_cursor_jump();
+  }
+
+  Ok(())
+}
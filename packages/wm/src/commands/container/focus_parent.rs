@@ -0,0 +1,24 @@
+use anyhow::Context;
+
+use crate::{traits::CommonGetters, wm_state::WmState};
+
+/// Selects the parent split container of the current selection, or of
+/// the focused container if nothing is selected.
+///
+/// Workspaces can't be selected, so the selection is left unchanged once
+/// it reaches the top of the tiling tree.
+pub fn focus_parent(state: &mut WmState) -> anyhow::Result<()> {
+  let current = match state.selected_container() {
+    Some(selection) => selection.into(),
+    None => state.focused_container().context("No focused container.")?,
+  };
+
+  if let Some(parent) = current
+    .parent()
+    .and_then(|parent| parent.as_split().cloned())
+  {
+    state.selection = Some(parent.id());
+  }
+
+  Ok(())
+}
@@ -0,0 +1,50 @@
+use super::set_focused_descendant;
+use crate::{models::Container, traits::CommonGetters, wm_state::WmState};
+
+/// Focuses the next tab of the closest tabbed split container that
+/// contains the origin container, or the previous tab if `previous` is
+/// true. Wraps around at either end.
+pub fn focus_tab(
+  origin_container: &Container,
+  previous: bool,
+  state: &mut WmState,
+) {
+  // Get the closest tabbed split container and its tab that contains the
+  // origin container.
+  let tabbed_ancestor =
+    origin_container.self_and_ancestors().find_map(|ancestor| {
+      let parent = ancestor.parent()?.as_split().cloned()?;
+      parent.is_tabbed().then_some((parent, ancestor))
+    });
+
+  let Some((tabbed_container, current_tab)) = tabbed_ancestor else {
+    return;
+  };
+
+  let tabs = tabbed_container.tiling_children().collect::<Vec<_>>();
+
+  let Some(index) =
+    tabs.iter().position(|tab| tab.id() == current_tab.id())
+  else {
+    return;
+  };
+
+  let target_index = if previous {
+    (index + tabs.len() - 1) % tabs.len()
+  } else {
+    (index + 1) % tabs.len()
+  };
+
+  // Focus whichever container last had focus within the target tab.
+  let target_tab = tabs[target_index].clone();
+  let last_focused = target_tab.descendant_focus_order().next();
+  let focus_target = last_focused.unwrap_or_else(|| target_tab.into());
+
+  set_focused_descendant(&focus_target, None);
+
+  state
+    .pending_sync
+    .queue_focus_change()
+    .queue_cursor_jump()
+    .queue_container_to_redraw(tabbed_container);
+}
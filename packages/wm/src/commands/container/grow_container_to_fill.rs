@@ -0,0 +1,43 @@
+use wm_common::grow_to_fill;
+
+use crate::{
+  models::Container,
+  traits::{CommonGetters, TilingSizeGetters},
+  wm_state::WmState,
+};
+
+/// Grows a tiling container to take up any space in its parent that
+/// isn't used by its siblings.
+pub fn grow_container_to_fill(
+  container: &Container,
+  state: &mut WmState,
+) {
+  let Some(parent) = container.parent() else {
+    return;
+  };
+
+  let tiling_children = parent.tiling_children().collect::<Vec<_>>();
+
+  let Some(index) = tiling_children
+    .iter()
+    .position(|child| child.id() == container.id())
+  else {
+    return;
+  };
+
+  let tiling_sizes = tiling_children
+    .iter()
+    .map(TilingSizeGetters::tiling_size)
+    .collect::<Vec<_>>();
+
+  for (child, tiling_size) in tiling_children
+    .iter()
+    .zip(grow_to_fill(&tiling_sizes, &[index]))
+  {
+    child.set_tiling_size(tiling_size);
+  }
+
+  state
+    .pending_sync
+    .queue_containers_to_redraw(tiling_children);
+}
@@ -1,25 +1,41 @@
 mod attach_container;
+mod collapse_single_child_splits;
 mod detach_container;
+mod equalize_sizes;
 mod flatten_child_split_containers;
 mod flatten_split_container;
+mod focus_child;
 mod focus_container_by_id;
 mod focus_in_direction;
+mod focus_mru;
+mod focus_parent;
+mod focus_tab;
+mod grow_container_to_fill;
 mod move_container_within_tree;
 mod replace_container;
 mod resize_tiling_container;
 mod set_focused_descendant;
+mod toggle_tabbed;
 mod toggle_tiling_direction;
 mod wrap_in_split_container;
 
 pub use attach_container::*;
+pub use collapse_single_child_splits::*;
 pub use detach_container::*;
+pub use equalize_sizes::*;
 pub use flatten_child_split_containers::*;
 pub use flatten_split_container::*;
+pub use focus_child::*;
 pub use focus_container_by_id::*;
 pub use focus_in_direction::*;
+pub use focus_mru::*;
+pub use focus_parent::*;
+pub use focus_tab::*;
+pub use grow_container_to_fill::*;
 pub use move_container_within_tree::*;
 pub use replace_container::*;
 pub use resize_tiling_container::*;
 pub use set_focused_descendant::*;
+pub use toggle_tabbed::*;
 pub use toggle_tiling_direction::*;
 pub use wrap_in_split_container::*;
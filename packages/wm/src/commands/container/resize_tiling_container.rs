@@ -1,48 +1,126 @@
+use wm_common::{
+  resize_tiling_edge, resize_tiling_sizes, resize_tiling_sizes_by,
+  LengthValue, TilingDirection,
+};
+
 use crate::{
-  models::TilingContainer,
-  traits::{CommonGetters, TilingSizeGetters, MIN_TILING_SIZE},
+  models::{DirectionContainer, TilingContainer},
+  traits::{
+    CommonGetters, PositionGetters, TilingDirectionGetters,
+    TilingSizeGetters, MIN_TILING_SIZE,
+  },
 };
 
 pub fn resize_tiling_container(
   container_to_resize: &TilingContainer,
   target_size: f32,
 ) {
-  let tiling_siblings =
-    container_to_resize.tiling_siblings().collect::<Vec<_>>();
+  resize_with(container_to_resize, |sizes, index, min_size| {
+    resize_tiling_sizes(sizes, index, target_size, min_size)
+  });
+}
+
+/// Resizes a tiling container by a length delta, where
+/// `available_length` is the parent's length along the resize axis
+/// excluding gaps.
+pub fn resize_tiling_container_by(
+  container_to_resize: &TilingContainer,
+  delta: &LengthValue,
+  available_length: i32,
+) {
+  resize_with(container_to_resize, |sizes, index, min_size| {
+    resize_tiling_sizes_by(sizes, index, delta, available_length, min_size)
+  });
+}
+
+/// Resizes a tiling container by a length delta, where only the given
+/// sibling gives up (or receives) space. This moves the edge between the
+/// two containers.
+pub fn resize_tiling_container_edge(
+  container_to_resize: &TilingContainer,
+  neighbor: &TilingContainer,
+  delta: &LengthValue,
+  available_length: i32,
+) {
+  let Some(neighbor_index) = neighbor.parent().and_then(|parent| {
+    parent
+      .tiling_children()
+      .position(|sibling| sibling.id() == neighbor.id())
+  }) else {
+    return;
+  };
+
+  let size_delta = delta.to_percentage(available_length);
 
-  // Ignore cases where the container is the only child.
-  if tiling_siblings.is_empty() {
+  resize_with(container_to_resize, |sizes, index, min_size| {
+    resize_tiling_edge(sizes, index, neighbor_index, size_delta, min_size)
+  });
+}
+
+/// Updates the tiling sizes of the container and its siblings with the
+/// sizes returned by `resize_fn`.
+fn resize_with<F>(container_to_resize: &TilingContainer, resize_fn: F)
+where
+  F: FnOnce(&[f32], usize, f32) -> Vec<f32>,
+{
+  let Some(parent) = container_to_resize
+    .parent()
+    .and_then(|parent| parent.as_direction_container().ok())
+  else {
     container_to_resize.set_tiling_size(1.);
     return;
-  }
+  };
 
-  // Prevent the container from being smaller than the minimum size, and
-  // larger than the space available from sibling containers.
-  #[allow(clippy::cast_precision_loss)]
-  let clamped_target_size = target_size.clamp(
-    MIN_TILING_SIZE,
-    1. - (tiling_siblings.len() as f32 * MIN_TILING_SIZE),
-  );
+  let tiling_children = parent.tiling_children().collect::<Vec<_>>();
 
-  let size_delta = clamped_target_size - container_to_resize.tiling_size();
-  container_to_resize.set_tiling_size(clamped_target_size);
+  let Some(index) = tiling_children
+    .iter()
+    .position(|child| child.id() == container_to_resize.id())
+  else {
+    return;
+  };
 
-  // Get available tiling size amongst siblings.
-  let available_size =
-    tiling_siblings.iter().fold(0.0, |sum, container| {
-      sum + container.tiling_size() - MIN_TILING_SIZE
-    });
+  let tiling_sizes = tiling_children
+    .iter()
+    .map(TilingSizeGetters::tiling_size)
+    .collect::<Vec<_>>();
 
-  // Distribute the available tiling size amongst its siblings.
-  for sibling in &tiling_siblings {
-    // Get percentage of resize that affects this container. Siblings are
-    // resized in proportion to their current size (i.e. larger containers
-    // are shrunk more).
-    let resize_factor =
-      (sibling.tiling_size() - MIN_TILING_SIZE) / available_size;
+  let resized_sizes = resize_fn(
+    &tiling_sizes,
+    index,
+    min_tiling_size(container_to_resize, &parent),
+  );
 
-    let size_delta = resize_factor * size_delta;
+  for (child, tiling_size) in tiling_children.iter().zip(resized_sizes) {
+    child.set_tiling_size(tiling_size);
+  }
+}
+
+/// Gets the configured minimum tiling size as a fraction of the parent's
+/// length along its tiling direction.
+///
+/// Falls back to `MIN_TILING_SIZE` if the parent's rect can't be
+/// resolved (e.g. when it's not yet attached to a monitor).
+fn min_tiling_size(
+  container: &TilingContainer,
+  parent: &DirectionContainer,
+) -> f32 {
+  let Ok(parent_rect) = parent.to_rect() else {
+    return MIN_TILING_SIZE;
+  };
 
-    sibling.set_tiling_size(sibling.tiling_size() - size_delta);
+  let parent_length = match parent.tiling_direction() {
+    TilingDirection::Horizontal => parent_rect.width(),
+    TilingDirection::Vertical => parent_rect.height(),
+  };
+
+  if parent_length <= 0 {
+    return MIN_TILING_SIZE;
   }
+
+  container
+    .gaps_config()
+    .min_tiling_size
+    .to_percentage(parent_length)
+    .max(MIN_TILING_SIZE)
 }
@@ -0,0 +1,67 @@
+use anyhow::Context;
+
+use super::{flatten_split_container, wrap_in_split_container};
+use crate::{
+  models::{Container, DirectionContainer, SplitContainer},
+  traits::{CommonGetters, TilingDirectionGetters},
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Toggles whether the parent split container of a tiling window shows
+/// its children as tabs.
+///
+/// If the window's parent is a workspace, then the workspace's tiling
+/// children are wrapped in a new tabbed split container.
+pub fn toggle_tabbed(
+  container: &Container,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  // Can only toggle tabs from a tiling window.
+  let Container::TilingWindow(tiling_window) = container else {
+    return Ok(());
+  };
+
+  let split_container = match tiling_window
+    .direction_container()
+    .context("No direction container.")?
+  {
+    DirectionContainer::Split(split_container) => {
+      split_container.set_tabbed(!split_container.is_tabbed());
+      split_container
+    }
+    DirectionContainer::Workspace(workspace) => {
+      let split_container = SplitContainer::new(
+        workspace.tiling_direction(),
+        config.value.gaps.clone(),
+      );
+
+      split_container.set_tabbed(true);
+
+      wrap_in_split_container(
+        &split_container,
+        &workspace.clone().into(),
+        &workspace.tiling_children().collect::<Vec<_>>(),
+      )?;
+
+      split_container
+    }
+  };
+
+  let parent = split_container.parent().context("No parent.")?;
+
+  // Flatten the split container if it's redundant once no longer tabbed.
+  let is_redundant = !split_container.is_tabbed()
+    && parent.as_direction_container().is_ok_and(|parent| {
+      parent.tiling_direction() == split_container.tiling_direction()
+    });
+
+  if is_redundant {
+    flatten_split_container(split_container)?;
+  }
+
+  state.pending_sync.queue_container_to_redraw(parent);
+
+  Ok(())
+}
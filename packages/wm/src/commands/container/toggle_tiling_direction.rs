@@ -1,7 +1,10 @@
 use anyhow::Context;
 use wm_common::{TilingDirection, WmEvent};
 
-use super::{flatten_split_container, wrap_in_split_container};
+use super::{
+  flatten_child_split_containers, flatten_split_container,
+  wrap_in_split_container,
+};
 use crate::{
   models::{Container, DirectionContainer, SplitContainer, TilingWindow},
   traits::{CommonGetters, TilingDirectionGetters},
@@ -24,7 +27,11 @@ pub fn toggle_tiling_direction(
 
       Ok(workspace.into())
     }
-    // Can only toggle tiling direction from a tiling window or workspace.
+    Container::Split(split_container) => {
+      toggle_split_direction(split_container)
+    }
+    // Can only toggle tiling direction from a tiling window, split
+    // container, or workspace.
     _ => return Ok(()),
   }?;
 
@@ -36,6 +43,47 @@ pub fn toggle_tiling_direction(
   Ok(())
 }
 
+/// Flips the tiling direction of the container's parent, rather than
+/// wrapping the container in a new split container.
+///
+/// Children of the parent keep their tiling sizes, so a horizontal split
+/// of two windows becomes the same windows stacked vertically.
+pub fn toggle_parent_direction(
+  container: &Container,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  // Can only toggle the parent direction from a tiling container.
+  if !matches!(container, Container::TilingWindow(_) | Container::Split(_))
+  {
+    return Ok(());
+  }
+
+  let parent = container.parent().context("No parent.")?;
+
+  let direction_container = match parent.as_direction_container()? {
+    DirectionContainer::Workspace(workspace) => {
+      workspace
+        .set_tiling_direction(workspace.tiling_direction().inverse());
+
+      workspace.into()
+    }
+    DirectionContainer::Split(split_container) => {
+      toggle_split_direction(split_container)?
+    }
+  };
+
+  state
+    .pending_sync
+    .queue_container_to_redraw(direction_container.clone());
+
+  state.emit_event(WmEvent::TilingDirectionChanged {
+    direction_container: direction_container.to_dto()?,
+    new_tiling_direction: direction_container.tiling_direction(),
+  });
+
+  Ok(())
+}
+
 fn toggle_window_direction(
   tiling_window: TilingWindow,
   config: &UserConfig,
@@ -80,6 +128,31 @@ fn toggle_window_direction(
   Ok(split_container.into())
 }
 
+/// Flips the tiling direction of a split container.
+///
+/// Children keep their tiling sizes, so their relative proportions are
+/// preserved along the new direction.
+fn toggle_split_direction(
+  split_container: SplitContainer,
+) -> anyhow::Result<DirectionContainer> {
+  let parent = split_container.parent().context("No parent.")?;
+
+  split_container
+    .set_tiling_direction(split_container.tiling_direction().inverse());
+
+  // Flatten any split containers made redundant by the new direction,
+  // which can be either children of the split container or the split
+  // container itself.
+  flatten_child_split_containers(&split_container.clone().into())?;
+  flatten_child_split_containers(&parent)?;
+
+  if split_container.is_detached() {
+    parent.as_direction_container()
+  } else {
+    Ok(split_container.into())
+  }
+}
+
 pub fn set_tiling_direction(
   container: Container,
   state: &mut WmState,
@@ -96,3 +169,57 @@ pub fn set_tiling_direction(
     toggle_tiling_direction(container, state, config)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use wm_common::Rect;
+
+  use super::*;
+  use crate::{
+    test_utils::{
+      add_test_monitor, add_test_split, add_test_window,
+      displayed_workspace, test_config, test_state,
+    },
+    traits::{PositionGetters, TilingSizeGetters},
+  };
+
+  #[test]
+  fn test_toggle_parent_direction_keeps_proportions() {
+    let mut state = test_state();
+    let config = test_config();
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1000, 800),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    // Tree of H[1 V[2 3]].
+    let window_1 = add_test_window(&workspace.clone().into(), &config);
+    let split = add_test_split(
+      &workspace.clone().into(),
+      TilingDirection::Vertical,
+      &config,
+    );
+    let window_2 = add_test_window(&split.clone().into(), &config);
+    let window_3 = add_test_window(&split.clone().into(), &config);
+
+    window_2.set_tiling_size(0.25);
+    window_3.set_tiling_size(0.75);
+
+    toggle_parent_direction(&window_2.clone().into(), &mut state).unwrap();
+
+    // The split now has the same direction as the workspace and gets
+    // flattened. Its children keep the same proportions as before.
+    assert!(split.is_detached());
+    assert_eq!(
+      [&window_1, &window_2, &window_3]
+        .map(|window| window.to_rect().unwrap()),
+      [
+        Rect::from_xy(0, 0, 500, 800),
+        Rect::from_xy(500, 0, 125, 800),
+        Rect::from_xy(625, 0, 375, 800),
+      ]
+    );
+  }
+}
@@ -4,14 +4,16 @@ use anyhow::Context;
 use tokio::task;
 use tracing::{info, warn};
 use wm_common::{
-  CornerStyle, CursorJumpTrigger, DisplayState, HideMethod, OpacityValue,
-  UniqueExt, WindowEffectConfig, WindowState, WmEvent,
+  CornerStyle, CursorJumpTrigger, DisplayState, HideMethod, UniqueExt,
+  WindowEffectConfig, WindowState, WmEvent,
 };
 use wm_platform::{Platform, ZOrder};
 
 use crate::{
-  models::{Container, WindowContainer},
-  traits::{CommonGetters, PositionGetters, WindowGetters},
+  models::{Container, SplitContainer, WindowContainer},
+  traits::{
+    CommonGetters, PositionGetters, TilingSizeGetters, WindowGetters,
+  },
   user_config::UserConfig,
   wm_state::WmState,
 };
@@ -27,6 +29,24 @@ pub fn platform_sync(
     sync_focus(&focused_container, state)?;
   }
 
+  // Tiling windows are outset by a different corner compensation when
+  // focused, so windows that gained or lost focus need to be redrawn.
+  let window_effects = &config.value.window_effects;
+  if state.pending_sync.needs_focused_effect_update()
+    && window_effects.focused_window.corner_style.compensation
+      != window_effects.other_windows.corner_style.compensation
+  {
+    let windows = state
+      .prev_effects_window
+      .clone()
+      .into_iter()
+      .chain(focused_container.as_window_container().ok())
+      .filter(|window| matches!(window, WindowContainer::TilingWindow(_)))
+      .collect::<Vec<_>>();
+
+    state.pending_sync.queue_containers_to_redraw(windows);
+  }
+
   if !state.pending_sync.containers_to_redraw().is_empty()
     || !state.pending_sync.workspaces_to_reorder().is_empty()
   {
@@ -71,6 +91,8 @@ pub fn platform_sync(
     }
   }
 
+  sync_focus_ring(&focused_container, state, config)?;
+
   state.pending_sync.clear();
 
   Ok(())
@@ -99,6 +121,20 @@ fn sync_focus(
     }
   }
 
+  if let Ok(window) = focused_container.as_window_container() {
+    state.push_mru_window(&window);
+  }
+
+  // Focus changes can switch the active tab of tabbed split containers,
+  // in which case their tabs need to be shown or hidden.
+  for tabbed_ancestor in focused_container.ancestors().filter(|ancestor| {
+    ancestor.as_split().is_some_and(SplitContainer::is_tabbed)
+  }) {
+    state
+      .pending_sync
+      .queue_container_to_redraw(tabbed_ancestor);
+  }
+
   state.emit_event(WmEvent::FocusChanged {
     focused_container: focused_container.to_dto()?,
   });
@@ -106,6 +142,44 @@ fn sync_focus(
   Ok(())
 }
 
+/// Emits a `FocusRingChanged` event if the overlay around the focused
+/// window has changed since it was last emitted.
+///
+/// The overlay is hidden when no window has focus, or when the focus ring
+/// is disabled (e.g. after a config reload).
+fn sync_focus_ring(
+  focused_container: &Container,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let focus_ring = &config.value.window_effects.focus_ring;
+
+  let overlay = match focused_container.as_window_container() {
+    Ok(window)
+      if focus_ring.enabled
+        && window.state() != WindowState::Minimized =>
+    {
+      Some((window.id(), focus_ring.overlay_rect(&window.to_rect()?)))
+    }
+    _ => None,
+  };
+
+  if state.focus_ring == overlay {
+    return Ok(());
+  }
+
+  state.focus_ring.clone_from(&overlay);
+
+  let (focused_window_id, overlay_rect) = overlay.unzip();
+
+  state.emit_event(WmEvent::FocusRingChanged {
+    focused_window_id,
+    overlay_rect,
+  });
+
+  Ok(())
+}
+
 /// Finds windows that should be brought to the top of their workspace's
 /// z-order.
 ///
@@ -198,6 +272,10 @@ fn redraw_containers(
     windows
   };
 
+  // Rect changes are emitted once all windows have been redrawn, so that
+  // subscribers get at most one event per window per sync.
+  let mut rect_changes = Vec::new();
+
   for window in windows_to_update.iter().rev() {
     let should_bring_to_front = windows_to_bring_to_front.contains(window);
 
@@ -246,7 +324,10 @@ fn redraw_containers(
     // Transition display state depending on whether window will be
     // shown or hidden.
     window.set_display_state(
-      match (window.display_state(), workspace.is_displayed()) {
+      match (
+        window.display_state(),
+        workspace.is_displayed() && !window.is_in_inactive_tab(),
+      ) {
         (DisplayState::Hidden | DisplayState::Hiding, true) => {
           DisplayState::Showing
         }
@@ -257,10 +338,33 @@ fn redraw_containers(
       },
     );
 
-    let original_rect = window
-      .to_rect()?
+    // Rects of sibling windows share the same layout pass, so get them
+    // via the cache rather than laying out each window's ancestors again.
+    let original_rect = state
+      .cached_rect(&(*window).clone().into())?
       .apply_delta(&window.total_border_delta()?, None);
 
+    // Outset tiling windows to shrink the visible gaps at rounded
+    // corners.
+    let original_rect = match window {
+      WindowContainer::TilingWindow(tiling_window) => {
+        let effect_config = if window.id() == focused_container.id() {
+          &config.value.window_effects.focused_window
+        } else {
+          &config.value.window_effects.other_windows
+        };
+
+        let (horizontal_gap, vertical_gap) = tiling_window.inner_gaps()?;
+
+        effect_config.corner_style.compensated_rect(
+          &original_rect,
+          horizontal_gap,
+          vertical_gap,
+        )
+      }
+      WindowContainer::NonTilingWindow(_) => original_rect,
+    };
+
     // Clamp window rect to its monitor's working area to prevent spillover
     // onto adjacent monitors, especially on mixed-resolution setups.
     let monitor = window.monitor().context("Window has no monitor.")?;
@@ -287,17 +391,43 @@ fn redraw_containers(
       DisplayState::Showing | DisplayState::Shown
     );
 
-    info!("Updating window position: {window}");
-
-    if let Err(err) = window.native().set_position(
-      &window.state(),
-      &rect,
-      &z_order,
-      is_visible,
-      &config.value.general.hide_method,
-      window.has_pending_dpi_adjustment(),
-    ) {
-      warn!("Failed to set window position: {}", err);
+    // Skip repositioning windows that are already shown at the target
+    // rect, since this would otherwise be a redundant call to the OS.
+    let is_position_unchanged = state.synced_rects.get(&window.id())
+      == Some(&(rect.clone(), window.state()))
+      && window.display_state() == DisplayState::Shown
+      && z_order == ZOrder::Normal
+      && !window.has_pending_dpi_adjustment();
+
+    if is_position_unchanged {
+      info!("Skipping unchanged window position: {window}");
+    } else {
+      info!("Updating window position: {window}");
+
+      match window.native().set_position(
+        &window.state(),
+        &rect,
+        &z_order,
+        is_visible,
+        &config.value.general.hide_method,
+        window.has_pending_dpi_adjustment(),
+      ) {
+        Ok(()) => {
+          let old_rect = state
+            .synced_rects
+            .insert(window.id(), (rect.clone(), window.state()))
+            .map(|(old_rect, _)| old_rect);
+
+          if old_rect.as_ref() != Some(&rect) {
+            rect_changes.push(WmEvent::ContainerRectChanged {
+              container_id: window.id(),
+              old_rect,
+              new_rect: rect,
+            });
+          }
+        }
+        Err(err) => warn!("Failed to set window position: {}", err),
+      }
     }
 
     // Whether the window is either transitioning to or from fullscreen.
@@ -337,6 +467,10 @@ fn redraw_containers(
     }
   }
 
+  for event in rect_changes {
+    state.emit_event(event);
+  }
+
   Ok(())
 }
 
@@ -407,10 +541,10 @@ fn apply_window_effects(
     apply_corner_effect(window, effect_config);
   }
 
-  if window_effects.focused_window.transparency.enabled
-    || window_effects.other_windows.transparency.enabled
+  if let Some(opacity) =
+    window_effects.opacity(is_focused, window.opacity().as_ref())
   {
-    apply_transparency_effect(window, effect_config);
+    _ = window.native().set_transparency(&opacity);
   }
 }
 
@@ -459,16 +593,308 @@ fn apply_corner_effect(
   _ = window.native().set_corner_style(corner_style);
 }
 
-fn apply_transparency_effect(
-  window: &WindowContainer,
-  effect_config: &WindowEffectConfig,
-) {
-  let transparency = if effect_config.transparency.enabled {
-    &effect_config.transparency.opacity
-  } else {
-    // Reset the transparency to default.
-    &OpacityValue::from_alpha(u8::MAX)
+#[cfg(test)]
+mod tests {
+  use tokio::sync::mpsc;
+  use uuid::Uuid;
+  use wm_common::{OpacityValue, Rect};
+
+  use super::*;
+  use crate::{
+    commands::container::{
+      resize_tiling_container, set_focused_descendant,
+    },
+    test_utils::{
+      add_test_monitor, add_test_window, displayed_workspace, test_config,
+      test_state, test_state_with_events,
+    },
   };
 
-  _ = window.native().set_transparency(transparency);
+  /// Drains the received events, keeping only rect changes.
+  fn rect_changes(
+    event_rx: &mut mpsc::UnboundedReceiver<WmEvent>,
+  ) -> Vec<(Uuid, Option<Rect>, Rect)> {
+    let mut rect_changes = Vec::new();
+
+    while let Ok(event) = event_rx.try_recv() {
+      if let WmEvent::ContainerRectChanged {
+        container_id,
+        old_rect,
+        new_rect,
+      } = event
+      {
+        rect_changes.push((container_id, old_rect, new_rect));
+      }
+    }
+
+    rect_changes.sort_by_key(|(_, _, new_rect)| new_rect.x());
+    rect_changes
+  }
+
+  /// Drains the received events, keeping only focus ring changes.
+  fn focus_ring_changes(
+    event_rx: &mut mpsc::UnboundedReceiver<WmEvent>,
+  ) -> Vec<(Option<Uuid>, Option<Rect>)> {
+    let mut focus_ring_changes = Vec::new();
+
+    while let Ok(event) = event_rx.try_recv() {
+      if let WmEvent::FocusRingChanged {
+        focused_window_id,
+        overlay_rect,
+      } = event
+      {
+        focus_ring_changes.push((focused_window_id, overlay_rect));
+      }
+    }
+
+    focus_ring_changes
+  }
+
+  /// Focuses the window and syncs the change, the same way as when a
+  /// window is focused via the OS.
+  fn focus_and_sync(
+    window: &WindowContainer,
+    state: &mut WmState,
+    config: &UserConfig,
+  ) {
+    set_focused_descendant(&window.clone().into(), None);
+    state
+      .pending_sync
+      .queue_focus_change()
+      .queue_focused_effect_update();
+    platform_sync(state, config).unwrap();
+  }
+
+  #[test]
+  fn test_rect_changed_emitted_for_new_windows() {
+    let (mut state, mut event_rx) = test_state_with_events();
+    let config = test_config();
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1000, 600),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+    let left = add_test_window(&workspace.clone().into(), &config);
+    let right = add_test_window(&workspace.clone().into(), &config);
+
+    state.pending_sync.queue_container_to_redraw(workspace);
+    platform_sync(&mut state, &config).unwrap();
+
+    assert_eq!(
+      rect_changes(&mut event_rx),
+      vec![
+        (left.id(), None, Rect::from_xy(0, 0, 500, 600)),
+        (right.id(), None, Rect::from_xy(500, 0, 500, 600)),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_rect_changes_coalesced_per_sync() {
+    let (mut state, mut event_rx) = test_state_with_events();
+    let config = test_config();
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1000, 600),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+    let left = add_test_window(&workspace.clone().into(), &config);
+    let right = add_test_window(&workspace.clone().into(), &config);
+
+    state
+      .pending_sync
+      .queue_container_to_redraw(workspace.clone());
+    platform_sync(&mut state, &config).unwrap();
+    rect_changes(&mut event_rx);
+
+    // Resize repeatedly within a single frame, as happens during a drag.
+    for tiling_size in [0.55, 0.6, 0.7] {
+      resize_tiling_container(&left.clone().into(), tiling_size);
+    }
+
+    state.pending_sync.queue_container_to_redraw(workspace);
+    platform_sync(&mut state, &config).unwrap();
+
+    // Only the latest rect of each window is emitted.
+    assert_eq!(
+      rect_changes(&mut event_rx),
+      vec![
+        (
+          left.id(),
+          Some(Rect::from_xy(0, 0, 500, 600)),
+          Rect::from_xy(0, 0, 700, 600),
+        ),
+        (
+          right.id(),
+          Some(Rect::from_xy(500, 0, 500, 600)),
+          Rect::from_xy(700, 0, 300, 600),
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_rect_changed_not_emitted_for_unchanged_windows() {
+    let (mut state, mut event_rx) = test_state_with_events();
+    let config = test_config();
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1000, 600),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+    add_test_window(&workspace.clone().into(), &config);
+
+    state
+      .pending_sync
+      .queue_container_to_redraw(workspace.clone());
+    platform_sync(&mut state, &config).unwrap();
+    rect_changes(&mut event_rx);
+
+    state.pending_sync.queue_container_to_redraw(workspace);
+    platform_sync(&mut state, &config).unwrap();
+
+    assert_eq!(rect_changes(&mut event_rx), vec![]);
+  }
+
+  #[test]
+  fn test_unchanged_position_is_skipped() {
+    let mut state = test_state();
+    let config = test_config();
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1000, 600),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+    let window = add_test_window(&workspace.clone().into(), &config);
+
+    for _ in 0..2 {
+      state
+        .pending_sync
+        .queue_container_to_redraw(workspace.clone());
+      platform_sync(&mut state, &config).unwrap();
+
+      // Mocked windows don't report back that they've been shown.
+      window.set_display_state(DisplayState::Shown);
+    }
+
+    assert_eq!(
+      window.native().mock_positions(),
+      vec![(WindowState::Tiling, Rect::from_xy(0, 0, 1000, 600))]
+    );
+  }
+
+  #[test]
+  fn test_state_change_is_redrawn_at_same_rect() {
+    let mut state = test_state();
+    let config = test_config();
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1000, 600),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+    let window = add_test_window(&workspace.clone().into(), &config);
+
+    state
+      .pending_sync
+      .queue_container_to_redraw(workspace.clone());
+    platform_sync(&mut state, &config).unwrap();
+    window.set_display_state(DisplayState::Shown);
+
+    // The rect stays the same, but the window still needs to be
+    // minimized via the OS.
+    window.set_state(WindowState::Minimized);
+    state.pending_sync.queue_container_to_redraw(workspace);
+    platform_sync(&mut state, &config).unwrap();
+
+    assert_eq!(
+      window.native().mock_positions(),
+      vec![
+        (WindowState::Tiling, Rect::from_xy(0, 0, 1000, 600)),
+        (WindowState::Minimized, Rect::from_xy(0, 0, 1000, 600)),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_transparency_applied_on_focus_change() {
+    let mut state = test_state();
+    let mut config = test_config();
+
+    // Border effects are applied asynchronously, so they're disabled to
+    // only test transparency.
+    let window_effects = &mut config.value.window_effects;
+    window_effects.focused_window.border.enabled = false;
+    window_effects.other_windows.border.enabled = false;
+    window_effects.other_windows.transparency.enabled = true;
+    window_effects.other_windows.transparency.opacity =
+      OpacityValue::from_alpha(128);
+
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1000, 600),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+    let window_1 = add_test_window(&workspace.clone().into(), &config);
+    let window_2 = add_test_window(&workspace.clone().into(), &config);
+
+    focus_and_sync(&window_1.clone().into(), &mut state, &config);
+    assert_eq!(window_1.native().mock_transparencies(), vec![255]);
+    assert!(window_2.native().mock_transparencies().is_empty());
+
+    // The previously focused window is dimmed, and the newly focused one
+    // is made opaque.
+    focus_and_sync(&window_2.clone().into(), &mut state, &config);
+    assert_eq!(window_1.native().mock_transparencies(), vec![255, 128]);
+    assert_eq!(window_2.native().mock_transparencies(), vec![255]);
+  }
+
+  #[test]
+  fn test_focus_ring_changed_emitted_on_focus_change() {
+    let (mut state, mut event_rx) = test_state_with_events();
+    let mut config = test_config();
+
+    let window_effects = &mut config.value.window_effects;
+    window_effects.focused_window.border.enabled = false;
+    window_effects.other_windows.border.enabled = false;
+    window_effects.focus_ring.enabled = true;
+
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1000, 600),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+    let window_1 = add_test_window(&workspace.clone().into(), &config);
+    let window_2 = add_test_window(&workspace.clone().into(), &config);
+
+    let focus_ring = &config.value.window_effects.focus_ring;
+    let overlay_1 =
+      focus_ring.overlay_rect(&Rect::from_xy(0, 0, 500, 600));
+    let overlay_2 =
+      focus_ring.overlay_rect(&Rect::from_xy(500, 0, 500, 600));
+
+    focus_and_sync(&window_1.clone().into(), &mut state, &config);
+    assert_eq!(
+      focus_ring_changes(&mut event_rx),
+      vec![(Some(window_1.id()), Some(overlay_1))]
+    );
+
+    focus_and_sync(&window_2.clone().into(), &mut state, &config);
+    assert_eq!(
+      focus_ring_changes(&mut event_rx),
+      vec![(Some(window_2.id()), Some(overlay_2))]
+    );
+
+    // Nothing is re-emitted when neither the focus nor the focused
+    // window's rect has changed.
+    focus_and_sync(&window_2.clone().into(), &mut state, &config);
+    platform_sync(&mut state, &config).unwrap();
+    assert_eq!(focus_ring_changes(&mut event_rx), vec![]);
+  }
 }
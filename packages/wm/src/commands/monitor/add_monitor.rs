@@ -3,9 +3,11 @@ use tracing::info;
 use wm_common::WmEvent;
 use wm_platform::NativeMonitor;
 
+use super::restore_monitor_struts;
 use crate::{
   commands::{
     container::{attach_container, move_container_within_tree},
+    window::{monitor_identities, move_window_to_bound_monitor},
     workspace::{activate_workspace, sort_workspaces},
   },
   models::{Monitor, Workspace},
@@ -24,6 +26,9 @@ pub fn add_monitor(
   // taskbars and other reserved display space.
   let monitor = Monitor::new(native_monitor);
 
+  // Restore struts that were set before the monitor was disconnected.
+  restore_monitor_struts(&monitor, state);
+
   attach_container(
     &monitor.clone().into(),
     &state.root_container.clone().into(),
@@ -74,7 +79,23 @@ pub fn add_monitor(
   // automatically prioritize bound workspace configs and fall back to the
   // first available one if needed.
   if monitor.child_count() == 0 {
-    activate_workspace(None, Some(monitor), state, config)?;
+    activate_workspace(None, Some(monitor.clone()), state, config)?;
+  }
+
+  // Move windows that are bound to the monitor back onto it.
+  let bound_windows = state
+    .monitor_bindings
+    .windows_for_monitor(
+      monitor.index(),
+      &monitor_identities(&state.monitors())?,
+    )
+    .into_iter()
+    .filter_map(|window_id| state.container_by_id(window_id))
+    .filter_map(|container| container.as_window_container().ok())
+    .collect::<Vec<_>>();
+
+  for window in bound_windows {
+    move_window_to_bound_monitor(window, state, config)?;
   }
 
   Ok(())
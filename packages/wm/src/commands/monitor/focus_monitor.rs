@@ -5,17 +5,16 @@ use crate::{
   user_config::UserConfig, wm_state::WmState,
 };
 
-/// Focuses a monitor by a given monitor index.
+/// Focuses the monitor at the given position, where monitors are
+/// ordered from left-to-right (see `WmState::monitor_at_position`).
 pub fn focus_monitor(
-  monitor_index: usize,
+  position: usize,
   state: &mut WmState,
   config: &UserConfig,
 ) -> anyhow::Result<()> {
-  let monitors = state.monitors();
-
-  let target_monitor = monitors.get(monitor_index).with_context(|| {
-    format!("Monitor at index {monitor_index} was not found.")
-  })?;
+  let target_monitor = state
+    .monitor_at_position(position)?
+    .with_context(|| format!("No monitor at position {position}."))?;
 
   let workspace_name = target_monitor
     .displayed_workspace()
@@ -1,11 +1,13 @@
 mod add_monitor;
 mod focus_monitor;
 mod remove_monitor;
+mod set_monitor_struts;
 mod sort_monitors;
 mod update_monitor;
 
 pub use add_monitor::*;
 pub use focus_monitor::*;
 pub use remove_monitor::*;
+pub use set_monitor_struts::*;
 pub use sort_monitors::*;
 pub use update_monitor::*;
@@ -8,7 +8,7 @@ use crate::{
     workspace::sort_workspaces,
   },
   models::Monitor,
-  traits::CommonGetters,
+  traits::{CommonGetters, PositionGetters},
   user_config::UserConfig,
   wm_state::WmState,
 };
@@ -21,10 +21,29 @@ pub fn remove_monitor(
 ) -> anyhow::Result<()> {
   info!("Removing monitor: {monitor}");
 
-  let target_monitor = state
+  let remaining_monitors = state
     .monitors()
     .into_iter()
-    .find(|m| m.id() != monitor.id())
+    .filter(|m| m.id() != monitor.id())
+    .collect::<Vec<_>>();
+
+  let remaining_rects = remaining_monitors
+    .iter()
+    .map(PositionGetters::to_rect)
+    .try_collect::<Vec<_>>()?;
+
+  // Move workspaces to the monitor that overlaps the removed monitor the
+  // most. Falls back to the first remaining monitor if the bounds of the
+  // removed monitor are no longer available.
+  let target_index = monitor
+    .to_rect()
+    .ok()
+    .and_then(|rect| rect.best_monitor(&remaining_rects))
+    .unwrap_or(0);
+
+  let target_monitor = remaining_monitors
+    .get(target_index)
+    .cloned()
     .context("No target monitor to move workspaces.")?;
 
   // Avoid moving empty workspaces.
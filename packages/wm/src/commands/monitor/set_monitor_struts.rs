@@ -0,0 +1,119 @@
+use wm_common::{Struts, WmEvent};
+
+use crate::{models::Monitor, traits::CommonGetters, wm_state::WmState};
+
+/// Reserves edges of the monitor for third-party bars and docks, and
+/// redraws its workspaces to fit within the remaining area.
+///
+/// The struts are kept by the monitor's hardware ID, so that they're
+/// restored if the monitor reconnects (see `restore_monitor_struts`).
+pub fn set_monitor_struts(
+  monitor: &Monitor,
+  struts: Struts,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  if let Ok(Some(hardware_id)) = monitor.native().hardware_id() {
+    state
+      .monitor_struts
+      .insert(hardware_id.clone(), struts.clone());
+  }
+
+  if apply_monitor_struts(monitor, struts, state) {
+    state.emit_event(WmEvent::MonitorUpdated {
+      updated_monitor: monitor.to_dto()?,
+    });
+  }
+
+  Ok(())
+}
+
+/// Re-applies the struts that were last set for the monitor's hardware
+/// ID. Returns whether the monitor's struts changed.
+pub fn restore_monitor_struts(
+  monitor: &Monitor,
+  state: &mut WmState,
+) -> bool {
+  let struts = monitor
+    .native()
+    .hardware_id()
+    .ok()
+    .flatten()
+    .and_then(|hardware_id| state.monitor_struts.get(hardware_id))
+    .cloned();
+
+  struts.is_some_and(|struts| apply_monitor_struts(monitor, struts, state))
+}
+
+/// Sets the struts of the monitor and queues its workspaces for redraw.
+/// Returns whether the monitor's struts changed.
+fn apply_monitor_struts(
+  monitor: &Monitor,
+  struts: Struts,
+  state: &mut WmState,
+) -> bool {
+  if monitor.struts() == struts {
+    return false;
+  }
+
+  monitor.set_struts(struts);
+
+  for workspace in monitor.workspaces() {
+    state
+      .pending_sync
+      .queue_containers_to_redraw(workspace.tiling_children());
+  }
+
+  true
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::Rect;
+
+  use super::*;
+  use crate::{
+    commands::monitor::remove_monitor,
+    test_utils::{
+      add_test_monitor, add_test_monitor_with_hardware_id,
+      displayed_workspace, test_config, test_state,
+    },
+    traits::PositionGetters,
+  };
+
+  #[test]
+  fn test_struts_restored_when_monitor_reconnects() {
+    let mut state = test_state();
+    let config = test_config();
+    let rect = Rect::from_xy(0, 0, 1920, 1080);
+
+    add_test_monitor(
+      Rect::from_xy(-1920, 0, 1920, 1080),
+      &mut state,
+      &config,
+    );
+    let monitor = add_test_monitor_with_hardware_id(
+      rect.clone(),
+      "DEL4321",
+      &mut state,
+      &config,
+    );
+
+    let struts = Struts {
+      top: 40,
+      ..Struts::default()
+    };
+
+    set_monitor_struts(&monitor, struts.clone(), &mut state).unwrap();
+    remove_monitor(monitor, &mut state, &config).unwrap();
+
+    let reconnected_monitor = add_test_monitor_with_hardware_id(
+      rect, "DEL4321", &mut state, &config,
+    );
+
+    assert_eq!(reconnected_monitor.struts(), struts);
+    assert_eq!(
+      displayed_workspace(&reconnected_monitor).to_rect().unwrap(),
+      Rect::from_xy(0, 40, 1920, 1040)
+    );
+  }
+}
@@ -2,6 +2,7 @@ use tracing::info;
 use wm_common::WmEvent;
 use wm_platform::NativeMonitor;
 
+use super::restore_monitor_struts;
 use crate::{models::Monitor, wm_state::WmState};
 
 pub fn update_monitor(
@@ -11,6 +12,10 @@ pub fn update_monitor(
 ) -> anyhow::Result<()> {
   monitor.set_native(native_monitor);
 
+  // The native monitor's hardware ID might differ from before, in which
+  // case any struts set for it are restored.
+  restore_monitor_struts(monitor, state);
+
   info!("Monitor updated: {monitor}");
 
   // TODO: Check that a property on the monitor actually changed.
@@ -0,0 +1,90 @@
+use anyhow::Context;
+use wm_common::{RectDelta, WindowState};
+
+use crate::{
+  models::WindowContainer,
+  traits::{CommonGetters, PositionGetters, WindowGetters},
+  wm_state::WmState,
+};
+
+/// Moves the edges of a floating window by the given delta, where
+/// positive values move an edge outwards. Edges that are moved past the
+/// window's monitor are capped at the monitor's edges.
+///
+/// Non-floating windows are left unchanged.
+pub fn adjust_floating_rect(
+  window: WindowContainer,
+  delta: &RectDelta,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  if !matches!(window.state(), WindowState::Floating(_)) {
+    return Ok(());
+  }
+
+  let monitor = window.monitor().context("No monitor.")?;
+  let monitor_rect = monitor.to_rect()?;
+  let scale_factor = monitor.native().scale_factor()?;
+
+  let floating_placement = window.floating_placement();
+  let target_rect = floating_placement
+    .apply_delta(delta, Some(scale_factor))
+    .cap_edges_within(&floating_placement, &monitor_rect);
+
+  window.set_floating_placement(target_rect);
+  window.set_has_custom_floating_placement(true);
+
+  state.pending_sync.queue_container_to_redraw(window);
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{FloatingStateConfig, LengthValue, Rect};
+
+  use super::*;
+  use crate::{
+    commands::window::update_window_state,
+    test_utils::{
+      add_test_monitor, add_test_window, displayed_workspace, test_config,
+      test_state,
+    },
+  };
+
+  #[test]
+  fn test_grow_right_edge_past_monitor() {
+    let mut state = test_state();
+    let config = test_config();
+
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1920, 1080),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    let window = update_window_state(
+      add_test_window(&workspace.into(), &config).into(),
+      WindowState::Floating(FloatingStateConfig::default()),
+      &mut state,
+      &config,
+    )
+    .unwrap();
+    window.set_floating_placement(Rect::from_xy(1400, 200, 400, 300));
+
+    let delta = RectDelta::new(
+      LengthValue::from_px(0),
+      LengthValue::from_px(0),
+      LengthValue::from_px(300),
+      LengthValue::from_px(0),
+    );
+
+    adjust_floating_rect(window.clone(), &delta, &mut state).unwrap();
+
+    // Only the right edge moves, and it stops at the monitor's edge.
+    assert_eq!(
+      window.floating_placement(),
+      Rect::from_ltrb(1400, 200, 1920, 500)
+    );
+  }
+}
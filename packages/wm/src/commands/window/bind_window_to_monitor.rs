@@ -0,0 +1,151 @@
+use anyhow::Context;
+use wm_common::MonitorIdentity;
+
+use super::move_window_to_workspace;
+use crate::{
+  models::{Monitor, WindowContainer, WorkspaceTarget},
+  traits::CommonGetters,
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Binds a window to the monitor at the given position and moves it to
+/// the monitor's displayed workspace. Monitors are ordered from
+/// left-to-right (see `WmState::monitor_at_position`). Unbinds the window
+/// if no position is given.
+///
+/// If the monitor isn't connected, the window is moved to the primary
+/// monitor instead. The binding is kept, such that the window is moved
+/// back once the monitor is reconnected.
+pub fn bind_window_to_monitor(
+  window: WindowContainer,
+  position: Option<usize>,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let Some(position) = position else {
+    state.monitor_bindings.unbind(&window.id());
+    return Ok(());
+  };
+
+  let monitor = state
+    .monitor_at_position(position)?
+    .with_context(|| format!("No monitor at position {position}."))?
+    .identity()?;
+
+  state.monitor_bindings.bind(window.id(), monitor);
+  move_window_to_bound_monitor(window, state, config)
+}
+
+/// Moves a window to the displayed workspace of the monitor it's bound
+/// to. Does nothing if the window isn't bound to a monitor.
+pub fn move_window_to_bound_monitor(
+  window: WindowContainer,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let monitors = state.monitors();
+
+  let primary_index = monitors
+    .iter()
+    .position(|monitor| monitor.native().is_primary().unwrap_or(false))
+    .unwrap_or(0);
+
+  let Some(target_index) = state.monitor_bindings.target_monitor(
+    &window.id(),
+    &monitor_identities(&monitors)?,
+    primary_index,
+  ) else {
+    return Ok(());
+  };
+
+  let target_monitor =
+    monitors.get(target_index).context("No target monitor.")?;
+
+  let is_on_target = window
+    .monitor()
+    .is_some_and(|monitor| monitor.id() == target_monitor.id());
+
+  if is_on_target {
+    return Ok(());
+  }
+
+  let target_workspace = target_monitor
+    .displayed_workspace()
+    .context("No displayed workspace.")?;
+
+  move_window_to_workspace(
+    window,
+    WorkspaceTarget::Name(target_workspace.config().name),
+    state,
+    config,
+  )
+}
+
+/// Gets the identities of the given monitors, for matching them against
+/// `MonitorBindings`.
+pub fn monitor_identities(
+  monitors: &[Monitor],
+) -> anyhow::Result<Vec<MonitorIdentity>> {
+  monitors.iter().map(Monitor::identity).try_collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::Rect;
+
+  use super::*;
+  use crate::{
+    commands::monitor::remove_monitor,
+    test_utils::{
+      add_test_monitor_with_hardware_id, add_test_window,
+      displayed_workspace, test_config, test_state,
+    },
+  };
+
+  #[test]
+  fn test_bound_window_returns_when_monitor_reconnects() {
+    let mut state = test_state();
+    let config = test_config();
+
+    let laptop = add_test_monitor_with_hardware_id(
+      Rect::from_xy(0, 0, 1920, 1080),
+      "BOE0900",
+      &mut state,
+      &config,
+    );
+    let external = add_test_monitor_with_hardware_id(
+      Rect::from_xy(1920, 0, 2560, 1440),
+      "DEL4088",
+      &mut state,
+      &config,
+    );
+
+    let window =
+      add_test_window(&displayed_workspace(&laptop).into(), &config);
+
+    bind_window_to_monitor(
+      window.clone().into(),
+      Some(1),
+      &mut state,
+      &config,
+    )
+    .unwrap();
+    assert_eq!(window.monitor().unwrap().id(), external.id());
+
+    // External monitor is disconnected, so its workspaces (along with the
+    // bound window) are moved to the laptop.
+    remove_monitor(external, &mut state, &config).unwrap();
+    assert_eq!(window.monitor().unwrap().id(), laptop.id());
+
+    // External monitor is reconnected on the other side of the laptop,
+    // but is still found by its hardware ID.
+    let external = add_test_monitor_with_hardware_id(
+      Rect::from_xy(-2560, 0, 2560, 1440),
+      "DEL4088",
+      &mut state,
+      &config,
+    );
+    assert_eq!(window.monitor().unwrap().id(), external.id());
+  }
+}
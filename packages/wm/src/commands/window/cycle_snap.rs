@@ -0,0 +1,46 @@
+use anyhow::Context;
+use wm_common::{Direction, SnapCycle, WindowState};
+
+use crate::{
+  commands::window::snap_gaps,
+  models::WindowContainer,
+  traits::{CommonGetters, PositionGetters, WindowGetters},
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Moves a floating window to the next snap slot in the given direction
+/// (e.g. left half -> top-left quarter -> bottom-left quarter).
+///
+/// Repeated calls cycle through the slots, unless the window has been
+/// moved in between.
+pub fn cycle_snap(
+  window: WindowContainer,
+  direction: &Direction,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  if !matches!(window.state(), WindowState::Floating(_)) {
+    return Ok(());
+  }
+
+  let workspace_rect =
+    window.workspace().context("No workspace.")?.to_rect()?;
+
+  let (horizontal_gap, vertical_gap) = snap_gaps(&window, config)?;
+
+  let snap_cycle = SnapCycle::next(
+    state.snap_cycles.get(&window.id()),
+    direction,
+    &window.floating_placement(),
+    &workspace_rect,
+    horizontal_gap,
+    vertical_gap,
+  );
+
+  window.set_floating_placement(snap_cycle.rect.clone());
+  state.snap_cycles.insert(window.id(), snap_cycle);
+  state.pending_sync.queue_container_to_redraw(window);
+
+  Ok(())
+}
@@ -0,0 +1,63 @@
+use std::time::{Duration, Instant};
+
+use tokio::task;
+use wm_common::OpacityValue;
+
+use crate::{
+  models::WindowContainer,
+  traits::{CommonGetters, WindowGetters},
+  user_config::UserConfig,
+};
+
+/// Interval between opacity updates while a window is fading.
+const FADE_FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+/// Sets the opacity of the window, and gradually fades from its current
+/// opacity to the new one over the given duration.
+///
+/// The new opacity is dimmed the same way as with `set-transparency` if
+/// the window isn't focused.
+pub fn fade_window_transparency(
+  window: &WindowContainer,
+  opacity: &OpacityValue,
+  duration: Duration,
+  config: &UserConfig,
+) {
+  let window_effects = &config.value.window_effects;
+  let is_focused = window.has_focus(None);
+
+  let from_opacity = window_effects
+    .opacity(is_focused, window.opacity().as_ref())
+    .unwrap_or_default();
+
+  window.set_opacity(Some(opacity.clone()));
+
+  let Some(to_opacity) = window_effects.opacity(is_focused, Some(opacity))
+  else {
+    return;
+  };
+
+  let native = window.native().clone();
+
+  task::spawn(async move {
+    let start = Instant::now();
+    let mut interval = tokio::time::interval(FADE_FRAME_INTERVAL);
+
+    loop {
+      interval.tick().await;
+
+      let progress = if duration.is_zero() {
+        1.0
+      } else {
+        start.elapsed().as_secs_f32() / duration.as_secs_f32()
+      };
+
+      _ =
+        native.set_transparency(&from_opacity.lerp(&to_opacity, progress));
+
+      if progress >= 1.0 {
+        break;
+      }
+    }
+  });
+}
@@ -1,9 +1,10 @@
 use anyhow::Context;
 use tracing::info;
 use wm_common::{
-  try_warn, LengthValue, RectDelta, WindowRuleEvent, WindowState, WmEvent,
+  try_warn, LengthValue, RectDelta, WindowInsertion, WindowRuleEvent,
+  WindowState, WmEvent,
 };
-use wm_platform::NativeWindow;
+use wm_platform::{NativeWindow, Platform};
 
 use crate::{
   commands::{
@@ -24,6 +25,19 @@ pub fn manage_window(
   state: &mut WmState,
   config: &mut UserConfig,
 ) -> anyhow::Result<()> {
+  // Skip windows that have previously been ignored.
+  if state.ignored_windows.contains(&native_window) {
+    return Ok(());
+  }
+
+  // Windows that are ignored by a window rule are never added to the
+  // tree, so that they don't affect the layout of other windows.
+  if try_warn!(config.is_ignored_window(&native_window)) {
+    info!("Ignoring window with handle {}.", native_window.handle);
+    state.ignored_windows.push(native_window);
+    return Ok(());
+  }
+
   // Create the window instance. This may fail if the window handle has
   // already been destroyed.
   let window =
@@ -96,38 +110,48 @@ fn create_window(
   // provided), otherwise, add as a sibling of the focused container.
   let (target_parent, target_index) = match target_parent {
     Some(parent) => (parent, 0),
-    None => insertion_target(&window_state, state)?,
+    None => insertion_target(&window_state, state, config)?,
   };
 
   let target_workspace =
     target_parent.workspace().context("No target workspace.")?;
 
-  let prefers_centered = config
-    .value
-    .window_behavior
-    .state_defaults
-    .floating
-    .centered;
+  let floating_defaults =
+    &config.value.window_behavior.state_defaults.floating;
+
+  let target_workspace_rect = target_workspace.to_rect()?;
+
+  // Cursor position to center the window on, if enabled and the cursor is
+  // within the target workspace.
+  let centering_point = floating_defaults
+    .placement
+    .centered_on_cursor
+    .then(Platform::mouse_position)
+    .and_then(Result::ok)
+    .filter(|point| target_workspace_rect.contains_point(point));
 
   // Calculate where window should be placed when floating is enabled. Use
-  // the original width/height of the window and optionally position it in
-  // the center of the workspace.
+  // the original width/height of the window (clamped to 90% of the
+  // workspace size) and optionally position it in the center of the
+  // workspace. The size is clamped before positioning, so that centered
+  // windows stay centered.
   let is_same_workspace = nearest_workspace.id() == target_workspace.id();
   let floating_placement = {
-    let placement = if !is_same_workspace || prefers_centered {
-      native_window
-        .frame_position()?
-        .translate_to_center(&target_workspace.to_rect()?)
-    } else {
-      native_window.frame_position()?
-    };
-
-    // Clamp the window size to 90% of the workspace size.
     #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
-    placement.clamp_size(
-      (target_workspace.to_rect()?.width() as f32 * 0.9) as i32,
-      (target_workspace.to_rect()?.height() as f32 * 0.9) as i32,
-    )
+    let frame = native_window.frame_position()?.clamp_size(
+      (target_workspace_rect.width() as f32 * 0.9) as i32,
+      (target_workspace_rect.height() as f32 * 0.9) as i32,
+    );
+
+    if let Some(point) = centering_point {
+      frame
+        .center_on_point(&point)
+        .clamp_within_bounds(&target_workspace_rect)
+    } else if !is_same_workspace || floating_defaults.placement.centered {
+      frame.translate_to_center(&target_workspace_rect)
+    } else {
+      frame
+    }
   };
 
   // Window has no border delta unless it's later changed via the
@@ -150,6 +174,7 @@ fn create_window(
       gaps_config,
       Vec::new(),
       None,
+      None,
     )
     .into(),
     _ => NonTilingWindow::new(
@@ -163,6 +188,7 @@ fn create_window(
       false,
       Vec::new(),
       None,
+      None,
     )
     .into(),
   };
@@ -234,16 +260,19 @@ fn window_state_to_create(
 ///
 /// Rules:
 /// - For non-tiling windows: Always append to the workspace.
-/// - For tiling windows:
+/// - For tiling windows with `window_insertion: after_focused`:
 ///   1. Try to insert after the focused tiling window if one exists.
 ///   2. If a non-tiling window is focused, try to insert after the first
 ///      tiling window found.
 ///   3. If no tiling windows exist, append to the workspace.
+/// - For tiling windows with `window_insertion: end`: Always append to the
+///   workspace.
 ///
 /// Returns tuple of (parent container, insertion index).
 fn insertion_target(
   window_state: &WindowState,
   state: &WmState,
+  config: &UserConfig,
 ) -> anyhow::Result<(Container, usize)> {
   let focused_container =
     state.focused_container().context("No focused container.")?;
@@ -251,9 +280,13 @@ fn insertion_target(
   let focused_workspace =
     focused_container.workspace().context("No workspace.")?;
 
+  let window_insertion = &config.value.general.window_insertion;
+
   // For tiling windows, try to find a suitable tiling window to insert
   // next to.
-  if *window_state == WindowState::Tiling {
+  if *window_state == WindowState::Tiling
+    && *window_insertion == WindowInsertion::AfterFocused
+  {
     let sibling = match focused_container {
       Container::TilingWindow(_) => Some(focused_container),
       _ => focused_workspace
@@ -262,16 +295,146 @@ fn insertion_target(
     };
 
     if let Some(sibling) = sibling {
-      return Ok((
-        sibling.parent().context("No parent.")?,
-        sibling.index() + 1,
-      ));
+      let parent = sibling.parent().context("No parent.")?;
+      let index = window_insertion
+        .insertion_index(Some(sibling.index()), parent.child_count());
+
+      return Ok((parent, index));
     }
   }
 
   // Default to appending to workspace.
   Ok((
     focused_workspace.clone().into(),
-    focused_workspace.child_count(),
+    window_insertion
+      .insertion_index(None, focused_workspace.child_count()),
   ))
 }
+
+#[cfg(test)]
+mod tests {
+  use wm_common::Rect;
+
+  use super::*;
+  use crate::{
+    test_utils::{
+      add_test_monitor, add_test_window, displayed_workspace, test_config,
+      test_native_window, test_state, test_window,
+    },
+    traits::TilingSizeGetters,
+  };
+
+  /// Opens a tiling window at the insertion target for new windows, the
+  /// same way as `create_window`.
+  fn open_window(state: &WmState, config: &UserConfig) -> TilingWindow {
+    let (target_parent, target_index) =
+      insertion_target(&WindowState::Tiling, state, config).unwrap();
+
+    let window = test_window("new", config);
+    attach_container(
+      &window.clone().into(),
+      &target_parent,
+      Some(target_index),
+    )
+    .unwrap();
+
+    window
+  }
+
+  #[test]
+  fn test_ignored_window_excluded_from_layout() {
+    let mut state = test_state();
+    let mut config = test_config();
+
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1920, 1080),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    let window_1 = add_test_window(&workspace.clone().into(), &config);
+
+    // Zebar windows are ignored by the sample config.
+    let ignored_window = test_native_window("Zebar", "zebar");
+    manage_window(ignored_window.clone(), None, &mut state, &mut config)
+      .unwrap();
+
+    let window_2 = add_test_window(&workspace.clone().into(), &config);
+
+    assert!(state.ignored_windows.contains(&ignored_window));
+    assert_eq!(state.windows().len(), 2);
+
+    // The remaining windows split the workspace as if the ignored window
+    // didn't exist.
+    assert_eq!(
+      window_1.to_rect().unwrap(),
+      Rect::from_xy(0, 0, 960, 1080)
+    );
+    assert_eq!(
+      window_2.to_rect().unwrap(),
+      Rect::from_xy(960, 0, 960, 1080)
+    );
+  }
+
+  #[test]
+  fn test_open_window_after_focused() {
+    let mut state = test_state();
+    let config = test_config();
+
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1200, 800),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    let windows = [(); 3]
+      .map(|()| add_test_window(&workspace.clone().into(), &config));
+
+    for (window, size) in windows.iter().zip([0.5, 0.25, 0.25]) {
+      window.set_tiling_size(size);
+    }
+
+    set_focused_descendant(&windows[0].clone().into(), None);
+
+    let new_window = open_window(&state, &config);
+    assert_eq!(new_window.parent().unwrap().id(), workspace.id());
+    assert_eq!(new_window.index(), 1);
+    assert_eq!(windows[1].index(), 2);
+
+    // Existing siblings give up space in proportion to their size above
+    // the minimum tiling size, so they roughly keep their proportions.
+    // All sizes add up to 1.
+    let sizes = workspace
+      .tiling_children()
+      .map(|child| child.tiling_size())
+      .collect::<Vec<_>>();
+
+    assert!((sizes[1] - 0.25).abs() < 1e-5);
+    assert!((sizes[0] - 2. * sizes[2]).abs() < 1e-2);
+    assert!((sizes[2] - sizes[3]).abs() < 1e-5);
+    assert!((sizes.iter().sum::<f32>() - 1.).abs() < 1e-5);
+  }
+
+  #[test]
+  fn test_open_window_at_end() {
+    let mut state = test_state();
+    let mut config = test_config();
+    config.value.general.window_insertion = WindowInsertion::End;
+
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1200, 800),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    let window = add_test_window(&workspace.clone().into(), &config);
+    add_test_window(&workspace.clone().into(), &config);
+    set_focused_descendant(&window.into(), None);
+
+    let new_window = open_window(&state, &config);
+    assert_eq!(new_window.index(), 2);
+  }
+}
@@ -1,21 +1,39 @@
+mod adjust_floating_rect;
+mod bind_window_to_monitor;
+mod cycle_snap;
+mod fade_window_transparency;
 mod ignore_window;
 mod manage_window;
+mod move_sticky_windows;
 mod move_window_in_direction;
+mod move_window_into;
+mod move_window_to_monitor;
 mod move_window_to_workspace;
 mod resize_window;
 mod run_window_rules;
 mod set_window_position;
+mod set_window_rect;
 mod set_window_size;
+mod swap_windows;
 mod unmanage_window;
 mod update_window_state;
 
+pub use adjust_floating_rect::*;
+pub use bind_window_to_monitor::*;
+pub use cycle_snap::*;
+pub use fade_window_transparency::*;
 pub use ignore_window::*;
 pub use manage_window::*;
+pub use move_sticky_windows::*;
 pub use move_window_in_direction::*;
+pub use move_window_into::*;
+pub use move_window_to_monitor::*;
 pub use move_window_to_workspace::*;
 pub use resize_window::*;
 pub use run_window_rules::*;
 pub use set_window_position::*;
+pub use set_window_rect::*;
 pub use set_window_size::*;
+pub use swap_windows::*;
 pub use unmanage_window::*;
 pub use update_window_state::*;
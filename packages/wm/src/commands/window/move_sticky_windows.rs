@@ -0,0 +1,146 @@
+use anyhow::Context;
+use wm_common::WindowState;
+
+use crate::{
+  commands::container::move_container_within_tree,
+  models::Workspace,
+  traits::{CommonGetters, PositionGetters, WindowGetters},
+  wm_state::WmState,
+};
+
+/// Moves sticky floating windows from one workspace to another, such that
+/// they follow focus when switching workspaces.
+///
+/// Windows keep their offset within the workspace and are clamped to stay
+/// on-screen if the target workspace is on a smaller monitor.
+pub fn move_sticky_windows(
+  source_workspace: &Workspace,
+  target_workspace: &Workspace,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  if source_workspace.id() == target_workspace.id() {
+    return Ok(());
+  }
+
+  let sticky_windows = source_workspace
+    .descendants()
+    .filter_map(|descendant| descendant.as_non_tiling_window().cloned())
+    .filter(|window| {
+      matches!(
+        window.state(),
+        WindowState::Floating(floating_config) if floating_config.sticky
+      )
+    })
+    .collect::<Vec<_>>();
+
+  if sticky_windows.is_empty() {
+    return Ok(());
+  }
+
+  let source_monitor =
+    source_workspace.monitor().context("No monitor.")?;
+  let target_monitor =
+    target_workspace.monitor().context("No monitor.")?;
+  let source_rect = source_workspace.to_rect()?;
+  let target_rect = target_workspace.to_rect()?;
+
+  let has_dpi_difference =
+    source_monitor.has_dpi_difference(&target_monitor.into())?;
+
+  for window in sticky_windows {
+    window.set_floating_placement(
+      window
+        .floating_placement()
+        .translate_between_monitors(&source_rect, &target_rect),
+    );
+
+    if has_dpi_difference {
+      window.set_has_pending_dpi_adjustment(true);
+    }
+
+    move_container_within_tree(
+      &window.clone().into(),
+      &target_workspace.clone().into(),
+      target_workspace.child_count(),
+      state,
+    )?;
+
+    state.pending_sync.queue_container_to_redraw(window);
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{FloatingStateConfig, Rect};
+
+  use super::*;
+  use crate::{
+    commands::{
+      container::set_focused_descendant, window::update_window_state,
+      workspace::focus_workspace,
+    },
+    models::{WindowContainer, WorkspaceTarget},
+    test_utils::{
+      add_test_monitor, add_test_window, displayed_workspace, test_config,
+      test_state,
+    },
+    user_config::UserConfig,
+  };
+
+  fn add_floating_window(
+    workspace: &Workspace,
+    sticky: bool,
+    state: &mut WmState,
+    config: &UserConfig,
+  ) -> WindowContainer {
+    let window = add_test_window(&workspace.clone().into(), config);
+
+    update_window_state(
+      window.into(),
+      WindowState::Floating(FloatingStateConfig {
+        sticky,
+        ..FloatingStateConfig::default()
+      }),
+      state,
+      config,
+    )
+    .unwrap()
+  }
+
+  #[test]
+  fn test_sticky_windows_follow_workspace_switch() {
+    let mut state = test_state();
+    let config = test_config();
+
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1920, 1080),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    let sticky_window =
+      add_floating_window(&workspace, true, &mut state, &config);
+    let window =
+      add_floating_window(&workspace, false, &mut state, &config);
+    set_focused_descendant(&window.clone().into(), None);
+
+    let floating_rect = sticky_window.to_rect().unwrap();
+
+    focus_workspace(
+      WorkspaceTarget::Name("2".to_string()),
+      &mut state,
+      &config,
+    )
+    .unwrap();
+
+    assert_eq!(sticky_window.workspace().unwrap().config().name, "2");
+    assert_eq!(window.workspace().unwrap().config().name, "1");
+
+    // Both workspaces are on the same monitor, so the window keeps its
+    // rect.
+    assert_eq!(sticky_window.to_rect().unwrap(), floating_rect);
+  }
+}
@@ -0,0 +1,131 @@
+use anyhow::Context;
+use wm_common::{Direction, TilingDirection};
+
+use crate::{
+  commands::container::{
+    move_container_within_tree, wrap_in_split_container,
+  },
+  models::{SplitContainer, TilingWindow},
+  traits::CommonGetters,
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Moves a tiling window into the slot of a target tiling window, such
+/// that the two windows share the target's slot in a new split
+/// container. For example, in the layout H[1 2 3] where container 3 is
+/// moved into container 2 downwards, the result is H[1 V[2 3]].
+///
+/// The window is placed on the `direction` side of the target, and the
+/// split container tiles along the axis of `direction`. The split
+/// container takes over the target's tiling size, so siblings of the
+/// target keep their sizes, and the two windows are evenly sized within
+/// it.
+pub fn move_window_into(
+  window: &TilingWindow,
+  target_window: &TilingWindow,
+  direction: &Direction,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  if window.id() == target_window.id() {
+    return Ok(());
+  }
+
+  let parent = window.parent().context("No parent.")?;
+  let target_parent = target_window.parent().context("No parent.")?;
+
+  let split_container = SplitContainer::new(
+    TilingDirection::from_direction(direction),
+    config.value.gaps.clone(),
+  );
+
+  wrap_in_split_container(
+    &split_container,
+    &target_parent,
+    &[target_window.clone().into()],
+  )?;
+
+  let target_index = match direction {
+    Direction::Up | Direction::Left => 0,
+    _ => 1,
+  };
+
+  // The window is sized to half of the split container on attach.
+  // Redundant split containers are flattened after the move, e.g. when
+  // the split container tiles in the same direction as its parent.
+  move_container_within_tree(
+    &window.clone().into(),
+    &split_container.clone().into(),
+    target_index,
+    state,
+  )?;
+
+  state
+    .pending_sync
+    .queue_containers_to_redraw(parent.tiling_children())
+    .queue_containers_to_redraw(target_parent.tiling_children())
+    .queue_cursor_jump();
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::Rect;
+
+  use super::*;
+  use crate::{
+    test_utils::{
+      add_test_monitor, add_test_window, displayed_workspace, test_config,
+      test_state,
+    },
+    traits::{PositionGetters, TilingDirectionGetters},
+  };
+
+  #[test]
+  fn test_move_into_forms_nested_split() {
+    let mut state = test_state();
+    let config = test_config();
+
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1200, 800),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    let window_1 = add_test_window(&workspace.clone().into(), &config);
+    let window_2 = add_test_window(&workspace.clone().into(), &config);
+    let window_3 = add_test_window(&workspace.clone().into(), &config);
+
+    let window_1_rect = window_1.to_rect().unwrap();
+
+    // H[1 2 3] -> H[1 V[2 3]].
+    move_window_into(
+      &window_3,
+      &window_2,
+      &Direction::Down,
+      &mut state,
+      &config,
+    )
+    .unwrap();
+
+    let split = window_2.parent().unwrap().as_split().cloned().unwrap();
+    assert_eq!(split.tiling_direction(), TilingDirection::Vertical);
+    assert_eq!(window_3.parent().unwrap().id(), split.id());
+    assert_eq!(workspace.child_count(), 2);
+
+    // Window 1 keeps its rect, and the freed up slot of window 3 is
+    // taken by the split, where windows 2 and 3 are stacked.
+    assert_eq!(window_1.to_rect().unwrap(), window_1_rect);
+    assert_eq!(
+      window_2.to_rect().unwrap(),
+      Rect::from_xy(400, 0, 800, 400)
+    );
+    assert_eq!(
+      window_3.to_rect().unwrap(),
+      Rect::from_xy(400, 400, 800, 400)
+    );
+  }
+}
@@ -0,0 +1,36 @@
+use anyhow::Context;
+
+use super::move_window_to_workspace;
+use crate::{
+  models::{WindowContainer, WorkspaceTarget},
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Moves the window to the displayed workspace of the monitor at the
+/// given position, where monitors are ordered from left-to-right (see
+/// `WmState::monitor_at_position`).
+///
+/// Floating windows are remapped proportionally onto the target monitor
+/// when moved to its workspace.
+pub fn move_window_to_monitor(
+  window: WindowContainer,
+  position: usize,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let target_monitor = state
+    .monitor_at_position(position)?
+    .with_context(|| format!("No monitor at position {position}."))?;
+
+  let target_workspace = target_monitor
+    .displayed_workspace()
+    .context("No displayed workspace.")?;
+
+  move_window_to_workspace(
+    window,
+    WorkspaceTarget::Name(target_workspace.config().name),
+    state,
+    config,
+  )
+}
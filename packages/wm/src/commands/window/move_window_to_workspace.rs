@@ -60,12 +60,15 @@ pub fn move_window_to_workspace(
       window.set_has_pending_dpi_adjustment(true);
     }
 
-    // Update floating placement if the window has to cross monitors.
+    // Update floating placement if the window has to cross monitors. The
+    // placement is remapped such that its relative position and size on
+    // the monitor are kept.
     if target_monitor.id() != current_monitor.id() {
       window.set_floating_placement(
-        window
-          .floating_placement()
-          .translate_to_center(&target_workspace.to_rect()?),
+        window.floating_placement().proportional_remap(
+          &current_workspace.to_rect()?,
+          &target_workspace.to_rect()?,
+        ),
       );
     }
 
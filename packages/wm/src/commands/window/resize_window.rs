@@ -1,9 +1,16 @@
-use wm_common::LengthValue;
+use anyhow::Context;
+use wm_common::{Direction, LengthValue, TilingDirection};
 
 use super::set_window_size;
 use crate::{
-  models::WindowContainer,
-  traits::{CommonGetters, PositionGetters, TilingSizeGetters},
+  commands::container::{
+    resize_tiling_container_by, resize_tiling_container_edge,
+  },
+  models::{TilingContainer, TilingWindow, WindowContainer},
+  traits::{
+    CommonGetters, PositionGetters, TilingDirectionGetters,
+    TilingSizeGetters,
+  },
   wm_state::WmState,
 };
 
@@ -13,75 +20,35 @@ pub fn resize_window(
   height_delta: Option<LengthValue>,
   state: &mut WmState,
 ) -> anyhow::Result<()> {
-  let window_rect = window.to_rect()?;
+  if let WindowContainer::TilingWindow(window) = window {
+    let container = resize_target(window, state);
 
-  let target_width = match width_delta {
-    Some(delta) => {
-      let parent_width = match window.as_tiling_container() {
-        Ok(tiling_window) => tiling_window
-          .container_to_resize(true)?
-          .and_then(|container| container.parent())
-          .and_then(|parent| {
-            parent.to_rect().ok().map(|rect| rect.width())
-          })
-          .and_then(|parent_width| {
-            let (horizontal_gap, _) = tiling_window.inner_gaps().ok()?;
-
-            #[allow(
-              clippy::cast_possible_wrap,
-              clippy::cast_possible_truncation
-            )]
-            Some(
-              parent_width
-                - horizontal_gap
-                  * tiling_window.tiling_siblings().count() as i32,
-            )
-          }),
-        _ => window.parent().and_then(|parent| {
-          parent.to_rect().ok().map(|rect| rect.width())
-        }),
-      };
-
-      parent_width.map(|parent_width| {
-        window_rect.width() + delta.to_px(parent_width, None)
-      })
+    if let Some(width_delta) = width_delta {
+      resize_tiling_container_axis(&container, &width_delta, true, state)?;
     }
-    _ => None,
-  };
 
-  let target_height = match height_delta {
-    Some(delta) => {
-      let parent_height = match window.as_tiling_container() {
-        Ok(tiling_window) => tiling_window
-          .container_to_resize(false)?
-          .and_then(|container| container.parent())
-          .and_then(|parent| {
-            parent.to_rect().ok().map(|rect| rect.width())
-          })
-          .and_then(|parent_height| {
-            let (_, vertical_gap) = tiling_window.inner_gaps().ok()?;
-
-            #[allow(
-              clippy::cast_possible_wrap,
-              clippy::cast_possible_truncation
-            )]
-            Some(
-              parent_height
-                - vertical_gap
-                  * tiling_window.tiling_siblings().count() as i32,
-            )
-          }),
-        _ => window.parent().and_then(|parent| {
-          parent.to_rect().ok().map(|rect| rect.width())
-        }),
-      };
-
-      parent_height.map(|parent_height| {
-        window_rect.height() + delta.to_px(parent_height, None)
-      })
+    if let Some(height_delta) = height_delta {
+      resize_tiling_container_axis(
+        &container,
+        &height_delta,
+        false,
+        state,
+      )?;
     }
-    _ => None,
-  };
+
+    return Ok(());
+  }
+
+  let window_rect = window.to_rect()?;
+  let parent_rect = window.parent().context("No parent.")?.to_rect()?;
+
+  let target_width = width_delta.map(|delta| {
+    window_rect.width() + delta.to_px(parent_rect.width(), None)
+  });
+
+  let target_height = height_delta.map(|delta| {
+    window_rect.height() + delta.to_px(parent_rect.height(), None)
+  });
 
   set_window_size(
     window.clone(),
@@ -92,3 +59,223 @@ pub fn resize_window(
 
   Ok(())
 }
+
+/// Gets the container to resize for a tiling window. This is the split
+/// container selected via `focus --parent` if the window is within it,
+/// otherwise the window itself.
+fn resize_target(
+  window: &TilingWindow,
+  state: &WmState,
+) -> TilingContainer {
+  state
+    .selected_container()
+    .filter(|selection| {
+      window
+        .ancestors()
+        .any(|ancestor| ancestor.id() == selection.id())
+    })
+    .map_or_else(|| window.clone().into(), Into::into)
+}
+
+/// Resizes either the width or height of a tiling container by a delta,
+/// which is resolved against the parent's length along that axis.
+fn resize_tiling_container_axis(
+  container: &TilingContainer,
+  delta: &LengthValue,
+  is_width_resize: bool,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  // The container to resize can actually be an ancestor split container.
+  let Some(container_to_resize) =
+    container.container_to_resize(is_width_resize)?
+  else {
+    return Ok(());
+  };
+
+  let parent = container_to_resize.parent().context("No parent.")?;
+  let available_length =
+    available_length(&container_to_resize, is_width_resize)?;
+
+  if available_length <= 0 {
+    return Ok(());
+  }
+
+  resize_tiling_container_by(
+    &container_to_resize,
+    delta,
+    available_length,
+  );
+
+  state
+    .pending_sync
+    .queue_containers_to_redraw(parent.tiling_children());
+
+  Ok(())
+}
+
+/// Resizes a tiling window from the given edge by a delta, such that
+/// only the neighbor across that edge gives up (or receives) space.
+///
+/// The edge can belong to an ancestor split container. For example, in
+/// `H[1 V[2 3] 4]`, the right edge of window 2 is shared with window 4.
+/// Resizes start from the split container selected via `focus --parent`
+/// if the window is within it.
+pub fn resize_window_edge(
+  window: &WindowContainer,
+  edge: &Direction,
+  delta: &LengthValue,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let WindowContainer::TilingWindow(window) = window else {
+    return Ok(());
+  };
+
+  let tiling_direction = TilingDirection::from_direction(edge);
+
+  let edge_neighbor = resize_target(window, state)
+    .self_and_ancestors()
+    .filter_map(|ancestor| ancestor.as_tiling_container().ok())
+    .find_map(|container| {
+      edge_neighbor(&container, edge, &tiling_direction)
+        .map(|neighbor| (container, neighbor))
+    });
+
+  let Some((container_to_resize, neighbor)) = edge_neighbor else {
+    return Ok(());
+  };
+
+  let parent = container_to_resize.parent().context("No parent.")?;
+  let available_length = available_length(
+    &container_to_resize,
+    tiling_direction == TilingDirection::Horizontal,
+  )?;
+
+  if available_length <= 0 {
+    return Ok(());
+  }
+
+  resize_tiling_container_edge(
+    &container_to_resize,
+    &neighbor,
+    delta,
+    available_length,
+  );
+
+  state
+    .pending_sync
+    .queue_containers_to_redraw(parent.tiling_children());
+
+  Ok(())
+}
+
+/// Gets the sibling that lies directly across the given edge of a tiling
+/// container. Siblings can only share an edge along the parent's tiling
+/// direction.
+fn edge_neighbor(
+  container: &TilingContainer,
+  edge: &Direction,
+  tiling_direction: &TilingDirection,
+) -> Option<TilingContainer> {
+  let parent = container.parent()?.as_direction_container().ok()?;
+
+  if parent.tiling_direction() != *tiling_direction {
+    return None;
+  }
+
+  let rect = container.to_rect().ok()?;
+  let (horizontal_gap, vertical_gap) = container.inner_gaps().ok()?;
+
+  // Adjacent siblings are separated by the inner gap.
+  let gap = match tiling_direction {
+    TilingDirection::Horizontal => horizontal_gap,
+    TilingDirection::Vertical => vertical_gap,
+  };
+
+  container.tiling_siblings().find(|sibling| {
+    sibling.to_rect().is_ok_and(|sibling_rect| {
+      rect.shared_edge_with_tolerance(&sibling_rect, gap + 1)
+        == Some(edge.clone())
+    })
+  })
+}
+
+/// Gets the parent's length along the resize axis, excluding the gaps
+/// between the container and its siblings.
+fn available_length(
+  container: &TilingContainer,
+  is_width_resize: bool,
+) -> anyhow::Result<i32> {
+  let parent_rect = container.parent().context("No parent.")?.to_rect()?;
+  let (horizontal_gap, vertical_gap) = container.inner_gaps()?;
+
+  #[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+  let gap_count = container.tiling_siblings().count() as i32;
+
+  Ok(if is_width_resize {
+    parent_rect.width() - horizontal_gap * gap_count
+  } else {
+    parent_rect.height() - vertical_gap * gap_count
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::Rect;
+
+  use super::*;
+  use crate::{
+    commands::container::{focus_parent, set_focused_descendant},
+    test_utils::{
+      add_test_monitor, add_test_split, add_test_window,
+      displayed_workspace, test_config, test_state,
+    },
+  };
+
+  #[test]
+  fn test_resize_selected_split() {
+    let mut state = test_state();
+    let config = test_config();
+
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1200, 800),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    // H[1 H[2 3]].
+    let window_1 = add_test_window(&workspace.clone().into(), &config);
+    let split = add_test_split(
+      &workspace.clone().into(),
+      TilingDirection::Horizontal,
+      &config,
+    );
+    let window_2 = add_test_window(&split.clone().into(), &config);
+    let window_3 = add_test_window(&split.clone().into(), &config);
+
+    set_focused_descendant(&window_2.clone().into(), None);
+    focus_parent(&mut state).unwrap();
+    assert_eq!(state.selected_container().unwrap().id(), split.id());
+
+    resize_window(
+      &window_2.clone().into(),
+      Some(LengthValue::from_px(100)),
+      None,
+      &mut state,
+    )
+    .unwrap();
+
+    // The whole split grows at the expense of window 1, rather than
+    // window 2 growing at the expense of window 3.
+    assert_eq!(window_1.to_rect().unwrap(), Rect::from_xy(0, 0, 500, 800));
+    assert_eq!(split.to_rect().unwrap(), Rect::from_xy(500, 0, 700, 800));
+    assert_eq!(
+      window_2.to_rect().unwrap(),
+      Rect::from_xy(500, 0, 350, 800)
+    );
+    assert_eq!(
+      window_3.to_rect().unwrap(),
+      Rect::from_xy(850, 0, 350, 800)
+    );
+  }
+}
@@ -1,21 +1,25 @@
 use anyhow::Context;
-use wm_common::{Rect, WindowState};
+use wm_common::{Anchor, OrdinalDirection, Rect, WindowState};
 
 use crate::{
   models::WindowContainer,
   traits::{CommonGetters, PositionGetters, WindowGetters},
+  user_config::UserConfig,
   wm_state::WmState,
 };
 
 pub enum WindowPositionTarget {
   Centered,
   Coordinates(Option<i32>, Option<i32>),
+  Quadrant(Anchor),
+  Corner(OrdinalDirection),
 }
 
 pub fn set_window_position(
   window: WindowContainer,
   target: &WindowPositionTarget,
   state: &mut WmState,
+  config: &UserConfig,
 ) -> anyhow::Result<()> {
   if matches!(window.state(), WindowState::Floating(_)) {
     let placement = window.floating_placement();
@@ -32,6 +36,18 @@ pub fn set_window_position(
           placement.height(),
         )
       }
+      WindowPositionTarget::Quadrant(anchor) => {
+        let workspace_rect =
+          window.workspace().context("No workspace.")?.to_rect()?;
+
+        let (horizontal_gap, vertical_gap) = snap_gaps(&window, config)?;
+
+        workspace_rect.quadrant(*anchor, horizontal_gap, vertical_gap)
+      }
+      WindowPositionTarget::Corner(corner) => placement.snap_to_corner(
+        corner,
+        &window.workspace().context("No workspace.")?.to_rect()?,
+      ),
     };
 
     window.set_floating_placement(new_placement);
@@ -40,3 +56,23 @@ pub fn set_window_position(
 
   Ok(())
 }
+
+/// Gets the horizontal and vertical gaps in pixels to leave between
+/// floating windows that are snapped to halves or quarters of the
+/// workspace.
+///
+/// These are the same as the inner gaps between tiling windows.
+pub fn snap_gaps(
+  window: &WindowContainer,
+  config: &UserConfig,
+) -> anyhow::Result<(i32, i32)> {
+  let monitor = window.monitor().context("No monitor.")?;
+  let workspace_gaps =
+    window.workspace().and_then(|workspace| workspace.config().gaps);
+
+  Ok(config.value.gaps.inner_gaps(
+    workspace_gaps.as_ref(),
+    &monitor.to_rect()?,
+    monitor.native().scale_factor()?,
+  ))
+}
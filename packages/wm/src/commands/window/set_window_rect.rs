@@ -0,0 +1,42 @@
+use anyhow::Context;
+use wm_common::{Rect, WindowState};
+
+use super::update_window_state;
+use crate::{
+  models::WindowContainer,
+  traits::{CommonGetters, PositionGetters, WindowGetters},
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Moves a window to the given rect, clamped within the bounds of its
+/// monitor. Tiling windows are floated first.
+pub fn set_window_rect(
+  window: WindowContainer,
+  target_rect: &Rect,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let window = match window.state() {
+    WindowState::Floating(_) => window,
+    _ => update_window_state(
+      window,
+      WindowState::Floating(
+        config.value.window_behavior.state_defaults.floating.clone(),
+      ),
+      state,
+      config,
+    )?,
+  };
+
+  let monitor_rect = window.monitor().context("No monitor.")?.to_rect()?;
+
+  window.set_floating_placement(
+    target_rect.clamp_within_bounds(&monitor_rect),
+  );
+  window.set_has_custom_floating_placement(true);
+
+  state.pending_sync.queue_container_to_redraw(window);
+
+  Ok(())
+}
@@ -0,0 +1,228 @@
+use anyhow::Context;
+use wm_common::{VecDequeExt, WmEvent};
+
+use crate::{
+  commands::container::set_focused_descendant,
+  models::TilingWindow,
+  traits::{CommonGetters, TilingSizeGetters, WindowGetters},
+  wm_state::WmState,
+};
+
+/// Swaps the positions of two tiling windows in the tree.
+///
+/// Each window takes over the other's tiling size, so the sizes of the
+/// two slots are unchanged. The windows can be in different split
+/// containers or workspaces.
+pub fn swap_windows(
+  window: &TilingWindow,
+  target_window: &TilingWindow,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  if window.id() == target_window.id() {
+    return Ok(());
+  }
+
+  let parent = window.parent().context("No parent.")?;
+  let target_parent = target_window.parent().context("No parent.")?;
+
+  let monitor = window.monitor().context("No monitor.")?;
+  let target_monitor = target_window.monitor().context("No monitor.")?;
+
+  // Since the windows could be swapped across monitors, adjustments might
+  // need to be made because of DPI.
+  if monitor.has_dpi_difference(&target_monitor.into())? {
+    window.set_has_pending_dpi_adjustment(true);
+    target_window.set_has_pending_dpi_adjustment(true);
+  }
+
+  // Get the focused window of the two (if any) before the swap, since
+  // focus order is exchanged along with the windows' places in the tree.
+  let focused_window = [window, target_window]
+    .into_iter()
+    .find(|swapped_window| swapped_window.has_focus(None))
+    .cloned();
+
+  if parent == target_parent {
+    let index = window.index();
+    let target_index = target_window.index();
+    parent.borrow_children_mut().swap(index, target_index);
+  } else {
+    parent
+      .borrow_children_mut()
+      .replace(&window.clone().into(), target_window.clone().into());
+
+    target_parent
+      .borrow_children_mut()
+      .replace(&target_window.clone().into(), window.clone().into());
+
+    // Each window takes over the other's place in the focus order.
+    parent
+      .borrow_child_focus_order_mut()
+      .replace(&window.id(), target_window.id());
+
+    target_parent
+      .borrow_child_focus_order_mut()
+      .replace(&target_window.id(), window.id());
+
+    *window.borrow_parent_mut() = Some(target_parent);
+    *target_window.borrow_parent_mut() = Some(parent);
+  }
+
+  let tiling_size = window.tiling_size();
+  window.set_tiling_size(target_window.tiling_size());
+  target_window.set_tiling_size(tiling_size);
+
+  if let Some(focused_window) = focused_window {
+    let is_displayed = focused_window
+      .workspace()
+      .is_some_and(|workspace| workspace.is_displayed());
+
+    // Focus follows the window, unless it was swapped onto a workspace
+    // that isn't displayed. In that case, focus is kept on the visible
+    // workspace by focusing the window that took its place.
+    if is_displayed {
+      set_focused_descendant(&focused_window.clone().into(), None);
+
+      state.emit_event(WmEvent::FocusedContainerMoved {
+        focused_container: focused_window.to_dto()?,
+      });
+    } else {
+      let other_window = if focused_window.id() == window.id() {
+        target_window
+      } else {
+        window
+      };
+
+      set_focused_descendant(&other_window.clone().into(), None);
+      state.pending_sync.queue_focus_change();
+    }
+  }
+
+  state
+    .pending_sync
+    .queue_container_to_redraw(window.clone())
+    .queue_container_to_redraw(target_window.clone());
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{Rect, TilingDirection};
+
+  use super::*;
+  use crate::{
+    commands::workspace::focus_workspace,
+    models::WorkspaceTarget,
+    test_utils::{
+      add_test_monitor, add_test_split, add_test_window,
+      displayed_workspace, test_config, test_state,
+    },
+    traits::PositionGetters,
+  };
+
+  #[test]
+  fn test_swapped_windows_exchange_rects() {
+    let mut state = test_state();
+    let config = test_config();
+
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1000, 800),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    let window_1 = add_test_window(&workspace.clone().into(), &config);
+    let window_2 = add_test_window(&workspace.clone().into(), &config);
+    window_1.set_tiling_size(0.7);
+    window_2.set_tiling_size(0.3);
+
+    let rect_1 = window_1.to_rect().unwrap();
+    let rect_2 = window_2.to_rect().unwrap();
+
+    swap_windows(&window_1, &window_2, &mut state).unwrap();
+
+    assert_eq!(window_1.index(), 1);
+    assert_eq!(window_1.to_rect().unwrap(), rect_2);
+    assert_eq!(window_2.to_rect().unwrap(), rect_1);
+  }
+
+  #[test]
+  fn test_swap_windows_across_splits() {
+    let mut state = test_state();
+    let config = test_config();
+
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1000, 800),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    // H[1 V[2 3]].
+    let window_1 = add_test_window(&workspace.clone().into(), &config);
+    let split = add_test_split(
+      &workspace.clone().into(),
+      TilingDirection::Vertical,
+      &config,
+    );
+    let window_2 = add_test_window(&split.clone().into(), &config);
+    let window_3 = add_test_window(&split.clone().into(), &config);
+    window_1.set_tiling_size(0.6);
+    split.set_tiling_size(0.4);
+
+    let rect_1 = window_1.to_rect().unwrap();
+    let rect_2 = window_2.to_rect().unwrap();
+    let rect_3 = window_3.to_rect().unwrap();
+
+    swap_windows(&window_1, &window_3, &mut state).unwrap();
+
+    assert_eq!(window_1.parent().unwrap().id(), split.id());
+    assert_eq!(window_3.parent().unwrap().id(), workspace.id());
+    assert_eq!(window_1.to_rect().unwrap(), rect_3);
+    assert_eq!(window_3.to_rect().unwrap(), rect_1);
+    assert_eq!(window_2.to_rect().unwrap(), rect_2);
+  }
+
+  #[test]
+  fn test_swap_focused_window_onto_hidden_workspace() {
+    let mut state = test_state();
+    let config = test_config();
+
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1000, 800),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+    let window_1 = add_test_window(&workspace.clone().into(), &config);
+
+    let focus_workspace_by_name = |name: &str, state: &mut WmState| {
+      focus_workspace(
+        WorkspaceTarget::Name(name.to_string()),
+        state,
+        &config,
+      )
+      .unwrap();
+    };
+
+    focus_workspace_by_name("2", &mut state);
+    let hidden_workspace = displayed_workspace(&monitor);
+    let window_2 =
+      add_test_window(&hidden_workspace.clone().into(), &config);
+    focus_workspace_by_name("1", &mut state);
+
+    set_focused_descendant(&window_1.clone().into(), None);
+    state.pending_sync.clear();
+
+    swap_windows(&window_1, &window_2, &mut state).unwrap();
+
+    // Focus stays on the displayed workspace, with the window that took
+    // the focused window's place.
+    assert_eq!(window_1.workspace().unwrap().id(), hidden_workspace.id());
+    assert_eq!(window_2.workspace().unwrap().id(), workspace.id());
+    assert!(window_2.has_focus(None));
+    assert!(state.pending_sync.needs_focus_update());
+  }
+}
@@ -3,8 +3,8 @@ use wm_common::{WindowState, WmEvent};
 
 use crate::{
   commands::container::{
-    detach_container, flatten_child_split_containers,
-    set_focused_descendant,
+    collapse_single_child_splits, detach_container,
+    flatten_child_split_containers, set_focused_descendant,
   },
   models::WindowContainer,
   traits::{CommonGetters, WindowGetters},
@@ -18,6 +18,7 @@ pub fn unmanage_window(
 ) -> anyhow::Result<()> {
   // Create iterator of parent, grandparent, and great-grandparent.
   let ancestors = window.ancestors().take(3).collect::<Vec<_>>();
+  let workspace = window.workspace();
 
   // Get container to switch focus to after the window has been removed.
   let focus_target = state.focus_target_after_removal(&window.clone());
@@ -31,6 +32,20 @@ pub fn unmanage_window(
     flatten_child_split_containers(ancestor)?;
   }
 
+  // Split containers can still be left with a single child further up
+  // the tree, which are replaced by their child.
+  if let Some(workspace) = workspace {
+    collapse_single_child_splits(&workspace.into())?;
+  }
+
+  state.synced_rects.remove(&window.id());
+  state.snap_cycles.remove(&window.id());
+  state.monitor_bindings.unbind(&window.id());
+
+  for mru_stack in state.mru_stacks.values_mut() {
+    mru_stack.remove(&window.id());
+  }
+
   state.emit_event(WmEvent::WindowUnmanaged {
     unmanaged_id: window.id(),
     unmanaged_handle: window.native().handle,
@@ -1,13 +1,15 @@
 use anyhow::Context;
 use tracing::{info, warn};
-use wm_common::WindowState;
+use wm_common::{restored_tiling_sizes, WindowState};
 
 use crate::{
   commands::container::{
     move_container_within_tree, replace_container, resize_tiling_container,
   },
-  models::{Container, InsertionTarget, WindowContainer},
-  traits::{CommonGetters, TilingSizeGetters, WindowGetters},
+  models::{Container, InsertionTarget, TilingWindow, WindowContainer},
+  traits::{
+    CommonGetters, PositionGetters, TilingSizeGetters, WindowGetters,
+  },
   user_config::UserConfig,
   wm_state::WmState,
 };
@@ -96,16 +98,8 @@ fn set_tiling(
     state,
   )?;
 
-  #[allow(clippy::cast_precision_loss)]
   if let Some(insertion_target) = &insertion_target {
-    let size_scale = (insertion_target.prev_sibling_count + 1) as f32
-      / (tiling_window.tiling_siblings().count() + 1) as f32;
-
-    // Scale the window's previous size based on the current number of
-    // siblings. E.g. if the window was 0.5 with 1 sibling, and now has 2
-    // siblings, scale to 0.5 * (2/3) to maintain proportional sizing.
-    let target_size = insertion_target.prev_tiling_size * size_scale;
-    resize_tiling_container(&tiling_window.clone().into(), target_size);
+    restore_tiling_size(&tiling_window, insertion_target);
   }
 
   state
@@ -116,6 +110,46 @@ fn set_tiling(
   Ok(tiling_window.into())
 }
 
+/// Restores the tiling size of a window that has been re-inserted at its
+/// previous position in the tree (e.g. when exiting fullscreen).
+fn restore_tiling_size(
+  window: &TilingWindow,
+  insertion_target: &InsertionTarget,
+) {
+  let tiling_children = insertion_target
+    .target_parent
+    .tiling_children()
+    .collect::<Vec<_>>();
+
+  let child_ids = tiling_children
+    .iter()
+    .map(CommonGetters::id)
+    .collect::<Vec<_>>();
+
+  // Restore the exact previous sizes of the window and its siblings if
+  // they're unchanged, such that the window gets its previous rect back.
+  if let Some(sizes) =
+    restored_tiling_sizes(&insertion_target.prev_tiling_sizes, &child_ids)
+  {
+    for (child, size) in tiling_children.iter().zip(sizes) {
+      child.set_tiling_size(size);
+    }
+
+    return;
+  }
+
+  // Otherwise, the layout has changed, so scale the window's previous
+  // size based on the current number of siblings. E.g. if the window
+  // was 0.5 with 1 sibling, and now has 2 siblings, scale to 0.5 * (2/3)
+  // to maintain proportional sizing.
+  #[allow(clippy::cast_precision_loss)]
+  let size_scale = (insertion_target.prev_sibling_count + 1) as f32
+    / (window.tiling_siblings().count() + 1) as f32;
+
+  let target_size = insertion_target.prev_tiling_size * size_scale;
+  resize_tiling_container(&window.clone().into(), target_size);
+}
+
 /// Updates the state of a window to be either `WindowState::Floating`,
 /// `WindowState::Fullscreen`, or `WindowState::Minimized`.
 fn set_non_tiling(
@@ -157,15 +191,34 @@ fn set_non_tiling(
     WindowContainer::TilingWindow(window) => {
       let parent = window.parent().context("No parent")?;
 
-      let non_tiling_window = window.to_non_tiling(
-        target_state.clone(),
+      let preserve_geometry = matches!(
+        &target_state,
+        WindowState::Floating(floating_config)
+          if floating_config.placement.preserve_geometry
+      );
+
+      // Float the window at its current tiled rect. Its previous tiling
+      // position isn't kept, such that it's instead inserted beside the
+      // focused window when tiled again.
+      let insertion_target = if preserve_geometry {
+        window.set_floating_placement(window.to_rect()?);
+        window.set_has_custom_floating_placement(true);
+        None
+      } else {
         Some(InsertionTarget {
           target_parent: parent.clone(),
           target_index: window.index(),
           prev_tiling_size: window.tiling_size(),
           prev_sibling_count: window.tiling_siblings().count(),
-        }),
-      );
+          prev_tiling_sizes: parent
+            .tiling_children()
+            .map(|child| (child.id(), child.tiling_size()))
+            .collect(),
+        })
+      };
+
+      let non_tiling_window =
+        window.to_non_tiling(target_state.clone(), insertion_target);
 
       // Non-tiling windows should always be direct children of the
       // workspace.
@@ -194,3 +247,123 @@ fn set_non_tiling(
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{
+    FloatingPlacementConfig, FloatingStateConfig, FullscreenStateConfig,
+    Rect,
+  };
+
+  use super::*;
+  use crate::{
+    commands::container::set_focused_descendant,
+    test_utils::{
+      add_test_monitor, add_test_window, displayed_workspace, test_config,
+      test_state,
+    },
+  };
+
+  #[test]
+  fn test_fullscreen_restores_tiled_rect() {
+    let mut state = test_state();
+    let config = test_config();
+
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1000, 800),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    let windows = [(); 3]
+      .map(|()| add_test_window(&workspace.clone().into(), &config));
+
+    for (window, size) in windows.iter().zip([0.5, 0.3, 0.2]) {
+      window.set_tiling_size(size);
+    }
+
+    let prev_rects =
+      windows.clone().map(|window| window.to_rect().unwrap());
+
+    let fullscreen_window = update_window_state(
+      windows[1].clone().into(),
+      WindowState::Fullscreen(FullscreenStateConfig::default()),
+      &mut state,
+      &config,
+    )
+    .unwrap();
+
+    // The remaining windows grow to take up the freed up space.
+    assert_eq!(workspace.tiling_children().count(), 2);
+    assert_ne!(windows[0].to_rect().unwrap(), prev_rects[0]);
+
+    let tiling_window = update_window_state(
+      fullscreen_window,
+      WindowState::Tiling,
+      &mut state,
+      &config,
+    )
+    .unwrap();
+
+    // Exiting fullscreen with unchanged siblings restores the exact
+    // previous rects.
+    assert_eq!(tiling_window.index(), 1);
+    assert_eq!(tiling_window.to_rect().unwrap(), prev_rects[1]);
+    assert_eq!(windows[0].to_rect().unwrap(), prev_rects[0]);
+    assert_eq!(windows[2].to_rect().unwrap(), prev_rects[2]);
+    assert_eq!(prev_rects[1], Rect::from_xy(500, 0, 300, 800));
+  }
+
+  #[test]
+  fn test_float_preserves_tiled_rect() {
+    let mut state = test_state();
+    let config = test_config();
+
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1200, 800),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    let window_1 = add_test_window(&workspace.clone().into(), &config);
+    let window_2 = add_test_window(&workspace.clone().into(), &config);
+    let window_3 = add_test_window(&workspace.clone().into(), &config);
+
+    let tiled_rect = window_2.to_rect().unwrap();
+
+    let floating_window = update_window_state(
+      window_2.into(),
+      WindowState::Floating(FloatingStateConfig {
+        placement: FloatingPlacementConfig {
+          preserve_geometry: true,
+          ..FloatingPlacementConfig::default()
+        },
+        ..FloatingStateConfig::default()
+      }),
+      &mut state,
+      &config,
+    )
+    .unwrap();
+
+    assert_eq!(floating_window.floating_placement(), tiled_rect);
+    assert_eq!(floating_window.to_rect().unwrap(), tiled_rect);
+
+    // Re-tiling inserts the window beside the focused window, rather than
+    // at its previous position.
+    set_focused_descendant(&window_3.clone().into(), None);
+
+    let tiling_window = update_window_state(
+      floating_window,
+      WindowState::Tiling,
+      &mut state,
+      &config,
+    )
+    .unwrap();
+
+    assert_eq!(window_1.index(), 0);
+    assert_eq!(window_3.index(), 1);
+    assert_eq!(tiling_window.index(), 2);
+  }
+}
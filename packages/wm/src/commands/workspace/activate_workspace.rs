@@ -1,6 +1,6 @@
 use anyhow::Context;
 use tracing::info;
-use wm_common::{TilingDirection, WmEvent, WorkspaceConfig};
+use wm_common::{WmEvent, WorkspaceConfig};
 
 use super::sort_workspaces;
 use crate::{
@@ -49,13 +49,11 @@ pub fn activate_workspace(
     })
     .context("Failed to get a target monitor for the workspace.")?;
 
-  let monitor_rect = target_monitor.to_rect()?;
-
-  let tiling_direction = if monitor_rect.height() > monitor_rect.width() {
-    TilingDirection::Vertical
-  } else {
-    TilingDirection::Horizontal
-  };
+  let tiling_direction = config
+    .value
+    .general
+    .default_tiling_direction
+    .for_monitor(&target_monitor.to_rect()?);
 
   let workspace = Workspace::new(
     workspace_config.clone(),
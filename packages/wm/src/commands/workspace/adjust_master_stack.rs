@@ -0,0 +1,38 @@
+use wm_common::{TilingLayout, MASTER_FRACTION_RANGE};
+
+use crate::{models::Workspace, wm_state::WmState};
+
+/// Adjusts the master fraction and master count of a workspace using the
+/// master-stack layout.
+///
+/// Does nothing if the workspace uses a different layout.
+pub fn adjust_master_stack(
+  workspace: &Workspace,
+  master_fraction_delta: Option<f32>,
+  master_count_delta: Option<i32>,
+  state: &mut WmState,
+) {
+  let mut config = workspace.config();
+
+  let TilingLayout::MasterStack {
+    master_fraction,
+    master_count,
+  } = &mut config.tiling_layout
+  else {
+    return;
+  };
+
+  if let Some(delta) = master_fraction_delta {
+    *master_fraction = (*master_fraction + delta)
+      .clamp(*MASTER_FRACTION_RANGE.start(), *MASTER_FRACTION_RANGE.end());
+  }
+
+  if let Some(delta) = master_count_delta {
+    *master_count = master_count.saturating_add_signed(delta as isize);
+  }
+
+  workspace.set_config(config);
+  state
+    .pending_sync
+    .queue_container_to_redraw(workspace.clone());
+}
@@ -0,0 +1,86 @@
+use anyhow::Context;
+
+use crate::{models::Container, traits::CommonGetters, wm_state::WmState};
+
+/// Evenly sizes every container in the container's workspace.
+///
+/// The workspace is only redrawn once, after all tiling sizes have been
+/// updated.
+pub fn balance_workspace(
+  container: &Container,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let workspace = container.workspace().context("No workspace.")?;
+  workspace.balance_all();
+
+  state.pending_sync.queue_container_to_redraw(workspace);
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{Rect, TilingDirection};
+
+  use super::*;
+  use crate::{
+    test_utils::{
+      add_test_monitor, add_test_split, add_test_window,
+      displayed_workspace, test_config, test_state,
+    },
+    traits::{PositionGetters, TilingSizeGetters},
+  };
+
+  #[test]
+  fn test_balance_nested_tree() {
+    let mut state = test_state();
+    let config = test_config();
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1200, 800),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    // Tree of H[1 V[2 H[3 4]] 5] with uneven sizes.
+    let window_1 = add_test_window(&workspace.clone().into(), &config);
+    let split_v = add_test_split(
+      &workspace.clone().into(),
+      TilingDirection::Vertical,
+      &config,
+    );
+    let window_2 = add_test_window(&split_v.clone().into(), &config);
+    let split_h = add_test_split(
+      &split_v.clone().into(),
+      TilingDirection::Horizontal,
+      &config,
+    );
+    let window_3 = add_test_window(&split_h.clone().into(), &config);
+    let window_4 = add_test_window(&split_h.clone().into(), &config);
+    let window_5 = add_test_window(&workspace.clone().into(), &config);
+
+    window_1.set_tiling_size(0.5);
+    split_v.set_tiling_size(0.2);
+    window_5.set_tiling_size(0.3);
+    window_2.set_tiling_size(0.7);
+    split_h.set_tiling_size(0.3);
+    window_3.set_tiling_size(0.9);
+    window_4.set_tiling_size(0.1);
+
+    balance_workspace(&window_1.clone().into(), &mut state).unwrap();
+
+    let rects = [&window_1, &window_2, &window_3, &window_4, &window_5]
+      .map(|window| window.to_rect().unwrap());
+
+    assert_eq!(
+      rects,
+      [
+        Rect::from_xy(0, 0, 400, 800),
+        Rect::from_xy(400, 0, 400, 400),
+        Rect::from_xy(400, 400, 200, 400),
+        Rect::from_xy(600, 400, 200, 400),
+        Rect::from_xy(800, 0, 400, 800),
+      ]
+    );
+  }
+}
@@ -4,7 +4,8 @@ use tracing::info;
 use super::activate_workspace;
 use crate::{
   commands::{
-    container::set_focused_descendant, workspace::deactivate_workspace,
+    container::set_focused_descendant, window::move_sticky_windows,
+    workspace::deactivate_workspace,
   },
   models::WorkspaceTarget,
   traits::CommonGetters,
@@ -69,7 +70,10 @@ pub fn focus_workspace(
     state
       .pending_sync
       .queue_container_to_redraw(displayed_workspace)
-      .queue_container_to_redraw(target_workspace);
+      .queue_container_to_redraw(target_workspace.clone());
+
+    // Sticky windows follow focus to the target workspace.
+    move_sticky_windows(&focused_workspace, &target_workspace, state)?;
 
     // Get empty workspace to destroy (if one is found). Cannot destroy
     // empty workspaces if they're the only workspace on the monitor.
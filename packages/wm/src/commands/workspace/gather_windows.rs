@@ -0,0 +1,185 @@
+use anyhow::Context;
+use tracing::info;
+use wm_common::WindowState;
+
+use super::deactivate_workspace;
+use crate::{
+  commands::window::move_window_to_workspace,
+  models::{Container, WorkspaceTarget},
+  traits::{CommonGetters, PositionGetters, WindowGetters},
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Moves every window from the other workspaces on the container's
+/// monitor into the container's workspace.
+///
+/// Tiling windows are re-tiled into the workspace, and floating windows
+/// are clamped within its bounds. Sticky windows are skipped, since they
+/// already follow focus between workspaces. Workspaces that are left
+/// empty are destroyed, unless they're `keep_alive`.
+pub fn gather_windows(
+  container: &Container,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let target_workspace = container.workspace().context("No workspace.")?;
+  let monitor = target_workspace.monitor().context("No monitor.")?;
+
+  let source_workspaces = monitor
+    .workspaces()
+    .into_iter()
+    .filter(|workspace| workspace.id() != target_workspace.id())
+    .collect::<Vec<_>>();
+
+  let windows = source_workspaces
+    .iter()
+    .flat_map(CommonGetters::descendants)
+    .filter_map(|descendant| descendant.as_window_container().ok())
+    .filter(|window| {
+      !matches!(
+        window.state(),
+        WindowState::Floating(floating_config) if floating_config.sticky
+      )
+    })
+    .collect::<Vec<_>>();
+
+  info!(
+    "Gathering {} windows into workspace: '{}'.",
+    windows.len(),
+    target_workspace.config().name
+  );
+
+  let target_workspace_rect = target_workspace.to_rect()?;
+
+  for window in windows {
+    window.set_floating_placement(
+      window
+        .floating_placement()
+        .clamp_within_bounds(&target_workspace_rect),
+    );
+
+    move_window_to_workspace(
+      window,
+      WorkspaceTarget::Name(target_workspace.config().name),
+      state,
+      config,
+    )?;
+  }
+
+  for workspace in source_workspaces {
+    if !workspace.config().keep_alive
+      && !workspace.has_children()
+      && !workspace.is_displayed()
+    {
+      deactivate_workspace(workspace, state)?;
+    }
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{FloatingStateConfig, Rect};
+
+  use super::*;
+  use crate::{
+    commands::{
+      container::set_focused_descendant, window::update_window_state,
+      workspace::focus_workspace,
+    },
+    models::Workspace,
+    test_utils::{
+      add_test_monitor, add_test_window, displayed_workspace, test_config,
+      test_state,
+    },
+  };
+
+  /// Focuses the workspace with the given name, and adds a focused tiling
+  /// window to it.
+  fn focus_workspace_with_window(
+    name: &str,
+    state: &mut WmState,
+    config: &UserConfig,
+  ) -> Workspace {
+    focus_workspace(
+      WorkspaceTarget::Name(name.to_string()),
+      state,
+      config,
+    )
+    .unwrap();
+
+    let workspace = state.workspace_by_name(name).unwrap();
+    let window = add_test_window(&workspace.clone().into(), config);
+    set_focused_descendant(&window.into(), None);
+    workspace
+  }
+
+  #[test]
+  fn test_gather_windows_from_every_workspace() {
+    let mut state = test_state();
+    let config = test_config();
+
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1200, 800),
+      &mut state,
+      &config,
+    );
+
+    let window =
+      add_test_window(&displayed_workspace(&monitor).into(), &config);
+    set_focused_descendant(&window.into(), None);
+
+    // Float a window partially outside of the monitor on workspace 2.
+    let workspace_2 =
+      focus_workspace_with_window("2", &mut state, &config);
+    let floating_window = update_window_state(
+      add_test_window(&workspace_2.into(), &config).into(),
+      WindowState::Floating(FloatingStateConfig::default()),
+      &mut state,
+      &config,
+    )
+    .unwrap();
+    floating_window
+      .set_floating_placement(Rect::from_xy(1000, 700, 400, 300));
+
+    let workspace_3 =
+      focus_workspace_with_window("3", &mut state, &config);
+
+    gather_windows(&workspace_3.clone().into(), &mut state, &config)
+      .unwrap();
+
+    // Every window is now in the active workspace, and the emptied
+    // workspaces are destroyed.
+    assert_eq!(state.windows().len(), 4);
+    assert!(state.windows().iter().all(|window| window
+      .workspace()
+      .unwrap()
+      .id()
+      == workspace_3.id()));
+    assert_eq!(monitor.workspaces().len(), 1);
+
+    // Tiling windows split the workspace evenly.
+    let mut tiling_rects = workspace_3
+      .tiling_children()
+      .map(|child| child.to_rect().unwrap())
+      .collect::<Vec<_>>();
+    tiling_rects.sort_by_key(Rect::x);
+
+    assert_eq!(
+      tiling_rects,
+      vec![
+        Rect::from_xy(0, 0, 400, 800),
+        Rect::from_xy(400, 0, 400, 800),
+        Rect::from_xy(800, 0, 400, 800),
+      ]
+    );
+
+    // The floating window is clamped within the workspace.
+    assert_eq!(
+      floating_window.floating_placement(),
+      Rect::from_xy(800, 500, 400, 300)
+    );
+  }
+}
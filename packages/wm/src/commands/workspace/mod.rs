@@ -1,11 +1,25 @@
 mod activate_workspace;
+mod adjust_master_stack;
+mod balance_workspace;
 mod deactivate_workspace;
 mod focus_workspace;
+mod gather_windows;
 mod move_workspace_in_direction;
+mod move_workspace_to_monitor;
+mod restore_workspace_layout;
+mod save_workspace_layout;
 mod sort_workspaces;
+mod validate_layout;
 
 pub use activate_workspace::*;
+pub use adjust_master_stack::*;
+pub use balance_workspace::*;
 pub use deactivate_workspace::*;
 pub use focus_workspace::*;
+pub use gather_windows::*;
 pub use move_workspace_in_direction::*;
+pub use move_workspace_to_monitor::*;
+pub use restore_workspace_layout::*;
+pub use save_workspace_layout::*;
 pub use sort_workspaces::*;
+pub use validate_layout::*;
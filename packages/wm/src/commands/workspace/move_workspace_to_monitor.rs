@@ -0,0 +1,179 @@
+use anyhow::Context;
+use wm_common::WmEvent;
+
+use super::{activate_workspace, deactivate_workspace, sort_workspaces};
+use crate::{
+  commands::container::move_container_within_tree,
+  models::{Monitor, Workspace},
+  traits::{CommonGetters, PositionGetters, WindowGetters},
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Moves a workspace and all its windows to the given monitor.
+///
+/// Floating windows are remapped proportionally to the new monitor's
+/// size, such that they stay on-screen when moving to a smaller monitor.
+pub fn move_workspace_to_monitor(
+  workspace: &Workspace,
+  target_monitor: &Monitor,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let origin_monitor = workspace.monitor().context("No monitor.")?;
+
+  if origin_monitor.id() == target_monitor.id() {
+    return Ok(());
+  }
+
+  // Get currently displayed workspace on the target monitor.
+  let displayed_workspace = target_monitor
+    .displayed_workspace()
+    .context("No displayed workspace.")?;
+
+  let origin_rect = workspace.to_rect()?;
+
+  move_container_within_tree(
+    &workspace.clone().into(),
+    &target_monitor.clone().into(),
+    target_monitor.child_count(),
+    state,
+  )?;
+
+  let target_rect = workspace.to_rect()?;
+
+  let windows = workspace
+    .descendants()
+    .filter_map(|descendant| descendant.as_window_container().ok());
+
+  for window in windows {
+    window.set_has_pending_dpi_adjustment(true);
+
+    window.set_floating_placement(
+      window
+        .floating_placement()
+        .proportional_remap(&origin_rect, &target_rect),
+    );
+  }
+
+  state
+    .pending_sync
+    .queue_cursor_jump()
+    .queue_container_to_redraw(workspace.clone())
+    .queue_container_to_redraw(displayed_workspace);
+
+  match origin_monitor.child_count() {
+    0 => {
+      // Prevent origin monitor from having no workspaces.
+      activate_workspace(None, Some(origin_monitor), state, config)?;
+    }
+    _ => {
+      // Redraw the workspace on the origin monitor.
+      state.pending_sync.queue_container_to_redraw(
+        origin_monitor
+          .displayed_workspace()
+          .context("No displayed workspace.")?,
+      );
+    }
+  }
+
+  // Get empty workspace to destroy (if one is found). Cannot destroy
+  // empty workspaces if they're the only workspace on the monitor.
+  let workspace_to_destroy =
+    target_monitor.workspaces().into_iter().find(|workspace| {
+      !workspace.config().keep_alive
+        && !workspace.has_children()
+        && !workspace.is_displayed()
+    });
+
+  if let Some(workspace) = workspace_to_destroy {
+    deactivate_workspace(workspace, state)?;
+  }
+
+  sort_workspaces(target_monitor, config)?;
+
+  state.emit_event(WmEvent::WorkspaceUpdated {
+    updated_workspace: workspace.to_dto()?,
+  });
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{FloatingStateConfig, Rect, WindowState};
+
+  use super::*;
+  use crate::{
+    commands::window::update_window_state,
+    test_utils::{
+      add_test_monitor, add_test_window, displayed_workspace, test_config,
+      test_state,
+    },
+  };
+
+  #[test]
+  fn test_move_workspace_to_smaller_monitor() {
+    let mut state = test_state();
+    let config = test_config();
+
+    let large_monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 3840, 2160),
+      &mut state,
+      &config,
+    );
+    let small_monitor = add_test_monitor(
+      Rect::from_xy(3840, 0, 1920, 1080),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&large_monitor);
+
+    let tiling_window =
+      add_test_window(&workspace.clone().into(), &config);
+    add_test_window(&workspace.clone().into(), &config);
+
+    // Float a window touching the bottom-right corner of the 4K monitor.
+    let floating_window = update_window_state(
+      add_test_window(&workspace.clone().into(), &config).into(),
+      WindowState::Floating(FloatingStateConfig::default()),
+      &mut state,
+      &config,
+    )
+    .unwrap();
+    floating_window
+      .set_floating_placement(Rect::from_xy(2640, 1360, 1200, 800));
+
+    move_workspace_to_monitor(
+      &workspace,
+      &small_monitor,
+      &mut state,
+      &config,
+    )
+    .unwrap();
+
+    assert_eq!(workspace.monitor().unwrap().id(), small_monitor.id());
+    assert!(large_monitor.displayed_workspace().is_some());
+
+    // Every window remains on-screen on the new monitor.
+    let monitor_rect = small_monitor.to_rect().unwrap();
+    for window in workspace
+      .descendants()
+      .filter_map(|descendant| descendant.as_window_container().ok())
+    {
+      let rect = window.to_rect().unwrap();
+      assert!(monitor_rect.contains_rect(&rect), "{rect:?} is off-screen");
+    }
+
+    // The floating window's tiling space went to its adjacent sibling,
+    // so the first window keeps a third of the width.
+    assert_eq!(
+      tiling_window.to_rect().unwrap(),
+      Rect::from_xy(3840, 0, 640, 1080)
+    );
+    assert_eq!(
+      floating_window.to_rect().unwrap(),
+      Rect::from_xy(5160, 680, 600, 400)
+    );
+  }
+}
@@ -0,0 +1,286 @@
+use std::{cmp::Reverse, path::Path};
+
+use anyhow::Context;
+use wm_common::{LayoutNode, WindowLayoutNode, WmEvent, WorkspaceLayout};
+
+use crate::{
+  commands::container::{
+    attach_container, detach_container, flatten_child_split_containers,
+  },
+  models::{
+    Container, SplitContainer, TilingContainer, TilingWindow, Workspace,
+  },
+  traits::{
+    CommonGetters, TilingDirectionGetters, TilingSizeGetters,
+    WindowGetters,
+  },
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Restores a layout saved with `save_workspace_layout` to the given
+/// workspace.
+///
+/// Running tiling windows are matched to the windows in the layout by
+/// their process name, class name and title, and are then moved into the
+/// workspace. Windows in the layout without a match are skipped.
+pub fn restore_workspace_layout(
+  workspace: &Workspace,
+  path: &Path,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let json = std::fs::read_to_string(path).with_context(|| {
+    format!("Failed to read layout from '{}'.", path.display())
+  })?;
+
+  let layout = serde_json::from_str::<WorkspaceLayout>(&json)
+    .context("Failed to parse layout.")?;
+
+  apply_workspace_layout(workspace, &layout, state, config)
+}
+
+/// Same as `restore_workspace_layout`, but with an already parsed
+/// layout.
+pub fn apply_workspace_layout(
+  workspace: &Workspace,
+  layout: &WorkspaceLayout,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let matched_windows = match_windows(layout, state);
+
+  // Detach matched windows from their current position in the tree, and
+  // redraw the workspaces that they are removed from.
+  for window in matched_windows.iter().flatten() {
+    if let Some(source_workspace) = window.workspace() {
+      state
+        .pending_sync
+        .queue_containers_to_redraw(source_workspace.tiling_children());
+    }
+
+    detach_container(window.clone().into())?;
+  }
+
+  workspace.set_tiling_direction(layout.tiling_direction.clone());
+
+  attach_nodes(
+    &layout.children,
+    &workspace.clone().into(),
+    &mut matched_windows.into_iter(),
+    config,
+  )?;
+
+  flatten_child_split_containers(&workspace.clone().into())?;
+
+  state
+    .pending_sync
+    .queue_containers_to_redraw(workspace.tiling_children());
+
+  state.emit_event(WmEvent::WorkspaceUpdated {
+    updated_workspace: workspace.to_dto()?,
+  });
+
+  Ok(())
+}
+
+/// Gets the best matching running window for each window in the layout,
+/// in the same order as `WorkspaceLayout::windows`. A running window is
+/// matched at most once.
+fn match_windows(
+  layout: &WorkspaceLayout,
+  state: &WmState,
+) -> Vec<Option<TilingWindow>> {
+  let mut candidates = state
+    .windows()
+    .into_iter()
+    .filter_map(|window| window.as_tiling_window().cloned())
+    .collect::<Vec<_>>();
+
+  layout
+    .windows()
+    .into_iter()
+    .map(|node| {
+      let (index, _) = candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(index, window)| {
+          match_score(node, window).map(|score| (index, score))
+        })
+        .min_by_key(|(_, score)| Reverse(*score))?;
+
+      Some(candidates.remove(index))
+    })
+    .collect()
+}
+
+fn match_score(
+  node: &WindowLayoutNode,
+  window: &TilingWindow,
+) -> Option<u8> {
+  let native = window.native();
+
+  node.match_score(
+    &native.process_name().ok()?,
+    &native.class_name().ok()?,
+    &native.title().ok()?,
+  )
+}
+
+/// Recreates the given layout nodes as children of `parent`, taking the
+/// matched window for each window node from `matched_windows`.
+fn attach_nodes(
+  nodes: &[LayoutNode],
+  parent: &Container,
+  matched_windows: &mut impl Iterator<Item = Option<TilingWindow>>,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  // Attaching a container resizes its siblings, so the saved tiling
+  // sizes are only applied once all the nodes are attached.
+  let mut saved_sizes = Vec::<(TilingContainer, f32)>::new();
+
+  for node in nodes {
+    match node {
+      LayoutNode::Window(node) => {
+        if let Some(window) = matched_windows.next().flatten() {
+          attach_container(&window.clone().into(), parent, None)?;
+          saved_sizes.push((window.into(), node.tiling_size));
+        }
+      }
+      LayoutNode::Split(node) => {
+        let split_container = SplitContainer::new(
+          node.tiling_direction.clone(),
+          config.value.gaps.clone(),
+        );
+
+        attach_container(&split_container.clone().into(), parent, None)?;
+
+        attach_nodes(
+          &node.children,
+          &split_container.clone().into(),
+          matched_windows,
+          config,
+        )?;
+
+        // Discard the split container if none of its windows matched.
+        if split_container.child_count() == 0 {
+          detach_container(split_container.into())?;
+        } else {
+          flatten_child_split_containers(&split_container.clone().into())?;
+          saved_sizes.push((split_container.into(), node.tiling_size));
+        }
+      }
+    }
+  }
+
+  for (container, tiling_size) in saved_sizes {
+    container.set_tiling_size(tiling_size);
+  }
+
+  // Saved tiling sizes don't account for windows that were skipped or
+  // that were already in the parent, so scale the sizes to add up to 1.
+  let tiling_children = parent.tiling_children().collect::<Vec<_>>();
+  let total_tiling_size = tiling_children
+    .iter()
+    .map(TilingSizeGetters::tiling_size)
+    .sum::<f32>();
+
+  if total_tiling_size > 0.0 {
+    for child in &tiling_children {
+      child.set_tiling_size(child.tiling_size() / total_tiling_size);
+    }
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{Rect, TilingDirection};
+
+  use super::*;
+  use crate::{
+    commands::{
+      container::resize_tiling_container, workspace::save_workspace_layout,
+    },
+    test_utils::{
+      add_test_monitor, add_test_split, add_test_window_with_title,
+      displayed_workspace, test_config, test_state, window_by_title,
+    },
+    traits::PositionGetters,
+  };
+
+  const TITLES: [&str; 4] = ["a", "b", "c", "d"];
+
+  fn window_rects(state: &WmState) -> Vec<Rect> {
+    TITLES
+      .iter()
+      .map(|title| window_by_title(state, title).to_rect().unwrap())
+      .collect()
+  }
+
+  #[test]
+  fn test_restore_reproduces_saved_rects() {
+    let config = test_config();
+    let path = std::env::temp_dir()
+      .join(format!("glazewm-layout-{}.json", std::process::id()));
+
+    // Save a layout of H[a V[b c] d] with uneven sizes.
+    let mut state = test_state();
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1200, 600),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    let window_a =
+      add_test_window_with_title("a", &workspace.clone().into(), &config);
+    let split = add_test_split(
+      &workspace.clone().into(),
+      TilingDirection::Vertical,
+      &config,
+    );
+    let window_b =
+      add_test_window_with_title("b", &split.clone().into(), &config);
+    add_test_window_with_title("c", &split.clone().into(), &config);
+    add_test_window_with_title("d", &workspace.clone().into(), &config);
+
+    resize_tiling_container(&window_a.into(), 0.5);
+    resize_tiling_container(&window_b.into(), 0.25);
+
+    save_workspace_layout(&workspace, &path).unwrap();
+    let saved_rects = window_rects(&state);
+
+    // Restore into a fresh state, where the windows are opened flat and
+    // in the reverse order.
+    let mut restored_state = test_state();
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1200, 600),
+      &mut restored_state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    for title in TITLES.iter().rev() {
+      add_test_window_with_title(
+        title,
+        &workspace.clone().into(),
+        &config,
+      );
+    }
+
+    assert_ne!(window_rects(&restored_state), saved_rects);
+
+    restore_workspace_layout(
+      &workspace,
+      &path,
+      &mut restored_state,
+      &config,
+    )
+    .unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(window_rects(&restored_state), saved_rects);
+  }
+}
@@ -0,0 +1,68 @@
+use std::path::Path;
+
+use anyhow::Context;
+use wm_common::{
+  LayoutNode, SplitLayoutNode, WindowLayoutNode, WorkspaceLayout,
+};
+
+use crate::{
+  models::{TilingContainer, Workspace},
+  traits::{
+    CommonGetters, PositionGetters, TilingDirectionGetters,
+    TilingSizeGetters, WindowGetters,
+  },
+};
+
+/// Saves the tiling tree of a workspace to a JSON file, so that it can
+/// later be restored with `restore_workspace_layout`.
+pub fn save_workspace_layout(
+  workspace: &Workspace,
+  path: &Path,
+) -> anyhow::Result<()> {
+  let layout = workspace_layout(workspace)?;
+  let json = serde_json::to_string_pretty(&layout)?;
+
+  std::fs::write(path, json).with_context(|| {
+    format!("Failed to write layout to '{}'.", path.display())
+  })
+}
+
+/// Gets a snapshot of the tiling tree of a workspace.
+pub fn workspace_layout(
+  workspace: &Workspace,
+) -> anyhow::Result<WorkspaceLayout> {
+  Ok(WorkspaceLayout {
+    workspace_name: workspace.config().name,
+    tiling_direction: workspace.tiling_direction(),
+    children: workspace
+      .tiling_children()
+      .map(|child| layout_node(&child))
+      .try_collect()?,
+  })
+}
+
+fn layout_node(container: &TilingContainer) -> anyhow::Result<LayoutNode> {
+  let node = match container {
+    TilingContainer::Split(split) => LayoutNode::Split(SplitLayoutNode {
+      tiling_size: split.tiling_size(),
+      tiling_direction: split.tiling_direction(),
+      children: split
+        .tiling_children()
+        .map(|child| layout_node(&child))
+        .try_collect()?,
+    }),
+    TilingContainer::TilingWindow(window) => {
+      let native = window.native();
+
+      LayoutNode::Window(WindowLayoutNode {
+        tiling_size: window.tiling_size(),
+        process_name: native.process_name()?,
+        class_name: native.class_name()?,
+        title: native.title()?,
+        rect: window.to_rect()?,
+      })
+    }
+  };
+
+  Ok(node)
+}
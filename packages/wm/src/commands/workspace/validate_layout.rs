@@ -0,0 +1,61 @@
+use tracing::{info, warn};
+use wm_common::normalize_tiling_sizes;
+
+use crate::{
+  models::Workspace,
+  traits::{CommonGetters, TilingSizeGetters},
+  wm_state::WmState,
+};
+
+/// Logs any issues with the layout of the workspace's tiling windows
+/// (see `Workspace::validate_layout`).
+///
+/// If `repair` is true and issues are found, the tiling sizes within the
+/// workspace are normalized and the workspace is redrawn.
+pub fn validate_layout(
+  workspace: &Workspace,
+  repair: bool,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let issues = workspace.validate_layout()?;
+
+  if issues.is_empty() {
+    info!("No layout issues found in workspace: {workspace}");
+    return Ok(());
+  }
+
+  for issue in &issues {
+    warn!("Layout issue in workspace {workspace}: {issue:?}");
+  }
+
+  if repair {
+    info!("Repairing layout of workspace: {workspace}");
+
+    let direction_containers = workspace
+      .self_and_descendants()
+      .filter_map(|container| container.as_direction_container().ok());
+
+    for direction_container in direction_containers {
+      let tiling_children =
+        direction_container.tiling_children().collect::<Vec<_>>();
+
+      let tiling_sizes = tiling_children
+        .iter()
+        .map(TilingSizeGetters::tiling_size)
+        .collect::<Vec<_>>();
+
+      for (child, tiling_size) in tiling_children
+        .iter()
+        .zip(normalize_tiling_sizes(&tiling_sizes))
+      {
+        child.set_tiling_size(tiling_size);
+      }
+    }
+
+    state
+      .pending_sync
+      .queue_container_to_redraw(workspace.clone());
+  }
+
+  Ok(())
+}
@@ -1,5 +1,7 @@
 use anyhow::Context;
 use tracing::info;
+use uuid::Uuid;
+use wm_common::{match_monitors_by_geometry, Rect};
 use wm_platform::Platform;
 
 use crate::{
@@ -17,6 +19,19 @@ pub fn handle_display_settings_changed(
 ) -> anyhow::Result<()> {
   info!("Display settings changed.");
 
+  // Bounds and work areas of the monitors prior to the change.
+  let prev_work_areas = state
+    .monitors()
+    .iter()
+    .map(|monitor| {
+      anyhow::Ok((
+        monitor.id(),
+        monitor.to_rect()?,
+        monitor.native().working_rect()?.clone(),
+      ))
+    })
+    .try_collect::<Vec<_>>()?;
+
   let native_monitors = Platform::sorted_monitors()?;
 
   let hardware_ids = native_monitors
@@ -84,10 +99,29 @@ pub fn handle_display_settings_changed(
     }
   }
 
-  for native_monitor in new_native_monitors {
-    match pending_monitors.first() {
-      Some(_) => {
-        let monitor = pending_monitors.remove(0);
+  // Match the remaining monitors by their position instead, since their
+  // IDs can change when they're reconnected. This keeps workspaces on the
+  // monitor at the same position.
+  let pending_rects = pending_monitors
+    .iter()
+    .map(PositionGetters::to_rect)
+    .try_collect::<Vec<_>>()?;
+
+  let new_rects = new_native_monitors
+    .iter()
+    .map(|native_monitor| native_monitor.rect().cloned())
+    .try_collect::<Vec<_>>()?;
+
+  let matches = match_monitors_by_geometry(&pending_rects, &new_rects);
+  let mut matched_monitor_ids = Vec::new();
+
+  for (native_monitor, pending_index) in
+    new_native_monitors.into_iter().zip(matches)
+  {
+    match pending_index {
+      Some(index) => {
+        let monitor = pending_monitors[index].clone();
+        matched_monitor_ids.push(monitor.id());
         update_monitor(&monitor, native_monitor, state)
       }
       // Add monitor if it doesn't exist in state.
@@ -95,6 +129,9 @@ pub fn handle_display_settings_changed(
     }?;
   }
 
+  pending_monitors
+    .retain(|monitor| !matched_monitor_ids.contains(&monitor.id()));
+
   // Remove any monitors that no longer exist and move their workspaces
   // to other monitors.
   //
@@ -111,26 +148,59 @@ pub fn handle_display_settings_changed(
   // Sort monitors by position.
   sort_monitors(&state.root_container)?;
 
+  update_window_placements(&prev_work_areas, state)?;
+
+  // Redraw full container tree.
+  state
+    .pending_sync
+    .queue_container_to_redraw(state.root_container.clone());
+
+  Ok(())
+}
+
+/// Marks every window for a DPI adjustment and updates its floating
+/// placement after the monitors have changed.
+///
+/// `prev_work_areas` holds the ID, bounds, and work area of each monitor
+/// prior to the change.
+fn update_window_placements(
+  prev_work_areas: &[(Uuid, Rect, Rect)],
+  state: &WmState,
+) -> anyhow::Result<()> {
   for window in state.windows() {
     // Display setting changes can spread windows out sporadically, so mark
     // all windows as needing a DPI adjustment (just in case).
     window.set_has_pending_dpi_adjustment(true);
 
-    // Need to update floating position of moved windows when a monitor is
-    // disconnected or if the primary display is changed. The primary
-    // display dictates the position of 0,0.
     let workspace = window.workspace().context("No workspace.")?;
-    window.set_floating_placement(
-      window
+    let monitor = workspace.monitor().context("No monitor.")?;
+    let monitor_rect = monitor.to_rect()?;
+
+    // Monitors whose bounds are unchanged can still have had their work
+    // area change (e.g. when the taskbar is resized or toggles
+    // auto-hide).
+    let prev_work_area = prev_work_areas
+      .iter()
+      .find(|(id, rect, _)| *id == monitor.id() && *rect == monitor_rect)
+      .map(|(_, _, work_area)| work_area);
+
+    let floating_placement = match prev_work_area {
+      Some(prev_work_area) => {
+        window.floating_placement().constrain_to_work_area(
+          prev_work_area,
+          monitor.native().working_rect()?,
+        )
+      }
+      // Need to update floating position of moved windows when a monitor
+      // is disconnected or if the primary display is changed. The primary
+      // display dictates the position of 0,0.
+      None => window
         .floating_placement()
         .translate_to_center(&workspace.to_rect()?),
-    );
-  }
+    };
 
-  // Redraw full container tree.
-  state
-    .pending_sync
-    .queue_container_to_redraw(state.root_container.clone());
+    window.set_floating_placement(floating_placement);
+  }
 
   Ok(())
 }
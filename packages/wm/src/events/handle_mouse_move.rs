@@ -2,8 +2,10 @@ use anyhow::Context;
 use wm_platform::{MouseMoveEvent, Platform};
 
 use crate::{
-  commands::container::set_focused_descendant, traits::CommonGetters,
-  user_config::UserConfig, wm_state::WmState,
+  commands::container::set_focused_descendant,
+  traits::{CommonGetters, PositionGetters},
+  user_config::UserConfig,
+  wm_state::WmState,
 };
 
 pub fn handle_mouse_move(
@@ -22,16 +24,29 @@ pub fn handle_mouse_move(
     return Ok(());
   }
 
-  let window_under_cursor = Platform::window_from_point(&event.point)
+  // Fall back to hit-testing the container tree if the window under the
+  // cursor can't be retrieved from the OS.
+  let window_under_cursor = match Platform::window_from_point(&event.point)
     .and_then(|window| Platform::root_ancestor(&window))
-    .map(|root| state.window_from_native(&root))?;
+  {
+    Ok(root) => state.window_from_native(&root),
+    Err(_) => state
+      .container_at_point(&event.point)
+      .and_then(|container| container.as_window_container().ok()),
+  };
 
   // Set focus to whichever window is currently under the cursor.
   if let Some(window) = window_under_cursor {
     let focused_container =
       state.focused_container().context("No focused container.")?;
 
-    if focused_container.id() != window.id() {
+    // Only switch focus once the cursor is far enough inside the window.
+    let is_past_margin = window.to_rect()?.contains_point_with_margin(
+      &event.point,
+      config.value.general.focus_follows_cursor_margin,
+    );
+
+    if focused_container.id() != window.id() && is_past_margin {
       set_focused_descendant(&window.as_container(), None);
       state.pending_sync.queue_focus_change();
     }
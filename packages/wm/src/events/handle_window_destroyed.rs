@@ -12,6 +12,11 @@ pub fn handle_window_destroyed(
   native_window: &NativeWindow,
   state: &mut WmState,
 ) -> anyhow::Result<()> {
+  // Forget the window if it was ignored, since its handle can be reused.
+  state
+    .ignored_windows
+    .retain(|window| window != native_window);
+
   let found_window = state.window_from_native(native_window);
 
   // Unmanage the window if it's currently managed.
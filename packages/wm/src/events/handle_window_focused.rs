@@ -70,8 +70,11 @@ pub fn handle_window_focused(
 
     // Handle focus events from windows on hidden workspaces. For example,
     // if Discord is forcefully shown by the OS when it's on a hidden
-    // workspace, switch focus to Discord's workspace.
-    if window.display_state() == DisplayState::Hidden {
+    // workspace, switch focus to Discord's workspace. Windows in inactive
+    // tabs are hidden despite their workspace being displayed.
+    if window.display_state() == DisplayState::Hidden
+      && !window.is_in_inactive_tab()
+    {
       info!("Focusing off-screen window: {window}");
 
       focus_workspace(
@@ -81,8 +84,17 @@ pub fn handle_window_focused(
       )?;
     }
 
+    // Focusing a window within an inactive tab switches the active tab of
+    // its tabbed split container.
+    if window.is_in_inactive_tab() {
+      state
+        .pending_sync
+        .queue_container_to_redraw(workspace.clone());
+    }
+
     // Update the WM's focus state.
     set_focused_descendant(&window.clone().into(), None);
+    state.push_mru_window(&window);
 
     // Run window rules for focus events.
     run_window_rules(
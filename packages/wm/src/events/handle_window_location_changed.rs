@@ -1,8 +1,8 @@
 use anyhow::Context;
 use tracing::info;
 use wm_common::{
-  try_warn, ActiveDrag, ActiveDragOperation, FloatingStateConfig,
-  FullscreenStateConfig, Rect, WindowState,
+  try_warn, ActiveDrag, ActiveDragOperation, FloatingPlacementConfig,
+  FloatingStateConfig, FullscreenStateConfig, Rect, WindowState,
 };
 use wm_platform::NativeWindow;
 
@@ -43,6 +43,17 @@ pub fn handle_window_location_changed(
       return Ok(());
     }
 
+    // Record where the window was moved to, so that it gets repositioned
+    // on the next redraw if it was moved by something other than the WM.
+    // The border position (which includes shadow borders) is what gets
+    // passed to `set_position`, so it's compared in the same space.
+    if let Some((synced_rect, _)) =
+      state.synced_rects.get_mut(&window.id())
+    {
+      let border_position = try_warn!(window.native().border_position());
+      synced_rect.clone_from(&border_position);
+    }
+
     let is_minimized = try_warn!(window.native().refresh_is_minimized());
 
     // Ignore events for minimized windows. Let them be handled by the
@@ -203,11 +214,18 @@ fn update_drag_state(
     if is_move {
       let parent = window.parent().context("No parent")?;
 
+      let floating_defaults =
+        &config.value.window_behavior.state_defaults.floating;
+
       let window = update_window_state(
         window.clone().into(),
         WindowState::Floating(FloatingStateConfig {
-          centered: false,
-          ..config.value.window_behavior.state_defaults.floating
+          placement: FloatingPlacementConfig {
+            centered: false,
+            preserve_geometry: false,
+            ..floating_defaults.placement
+          },
+          ..*floating_defaults
         }),
         state,
         config,
@@ -1,8 +1,8 @@
 use anyhow::Context;
 use tracing::info;
 use wm_common::{
-  try_warn, ActiveDragOperation, LengthValue, Point, Rect,
-  TilingDirection, WindowState,
+  try_warn, ActiveDragOperation, DropRegion, LengthValue, TilingDirection,
+  WindowState,
 };
 use wm_platform::{NativeWindow, Platform};
 
@@ -137,8 +137,9 @@ fn drop_as_tiling_window(
     .filter_map(|container| container.as_tiling_container().ok())
     .try_fold(None, |acc: Option<TilingContainer>, container| match acc {
       Some(acc) => {
-        let is_nearer = acc.to_rect()?.distance_to_point(&mouse_pos)
-          < container.to_rect()?.distance_to_point(&mouse_pos);
+        let is_nearer =
+          acc.to_rect()?.closest_distance_to_point(&mouse_pos)
+            < container.to_rect()?.closest_distance_to_point(&mouse_pos);
 
         anyhow::Ok(Some(if is_nearer { acc } else { container }))
       }
@@ -146,9 +147,12 @@ fn drop_as_tiling_window(
     })?
     .context("No nearest container.")?;
 
+  // Use a band of half the container's size, such that the window is
+  // always dropped on one of its edges.
   let tiling_direction = target_parent.tiling_direction();
-  let drop_position =
-    drop_position(&mouse_pos, &nearest_container.to_rect()?);
+  let drop_region = nearest_container
+    .to_rect()?
+    .drop_region_with_band(&mouse_pos, 0.5);
 
   let moved_window = update_window_state(
     moved_window.clone().into(),
@@ -160,12 +164,10 @@ fn drop_as_tiling_window(
   let should_split = nearest_container.is_tiling_window()
     && match tiling_direction {
       TilingDirection::Horizontal => {
-        drop_position == DropPosition::Top
-          || drop_position == DropPosition::Bottom
+        drop_region == DropRegion::Top || drop_region == DropRegion::Bottom
       }
       TilingDirection::Vertical => {
-        drop_position == DropPosition::Left
-          || drop_position == DropPosition::Right
+        drop_region == DropRegion::Left || drop_region == DropRegion::Right
       }
     };
 
@@ -181,8 +183,8 @@ fn drop_as_tiling_window(
       &[nearest_container],
     )?;
 
-    let target_index = match drop_position {
-      DropPosition::Top | DropPosition::Left => 0,
+    let target_index = match drop_region {
+      DropRegion::Top | DropRegion::Left => 0,
       _ => 1,
     };
 
@@ -193,8 +195,8 @@ fn drop_as_tiling_window(
       state,
     )?;
   } else {
-    let target_index = match drop_position {
-      DropPosition::Top | DropPosition::Left => nearest_container.index(),
+    let target_index = match drop_region {
+      DropRegion::Top | DropRegion::Left => nearest_container.index(),
       _ => nearest_container.index() + 1,
     };
 
@@ -210,37 +212,3 @@ fn drop_as_tiling_window(
 
   Ok(())
 }
-
-/// Represents where the window was dropped over another.
-#[derive(Debug, Clone, PartialEq)]
-enum DropPosition {
-  Top,
-  Bottom,
-  Left,
-  Right,
-}
-
-/// Gets the drop position for a window based on the mouse position.
-///
-/// This approach divides the window rect into an "X", creating four
-/// triangular quadrants, to determine which side the cursor is closest to.
-fn drop_position(mouse_pos: &Point, rect: &Rect) -> DropPosition {
-  let delta_x = mouse_pos.x - rect.center_point().x;
-  let delta_y = mouse_pos.y - rect.center_point().y;
-
-  if delta_x.abs() > delta_y.abs() {
-    // Window is in the left or right triangle.
-    if delta_x > 0 {
-      DropPosition::Right
-    } else {
-      DropPosition::Left
-    }
-  } else {
-    // Window is in the top or bottom triangle.
-    if delta_y > 0 {
-      DropPosition::Bottom
-    } else {
-      DropPosition::Top
-    }
-  }
-}
@@ -13,14 +13,15 @@ use tracing::{info, warn};
 use uuid::Uuid;
 use wm_common::{
   AppCommand, AppMetadataData, BindingModesData, ClientResponseData,
-  ClientResponseMessage, CommandData, EventSubscribeData,
-  EventSubscriptionMessage, FocusedData, MonitorsData, QueryCommand,
-  ServerMessage, SubscribableEvent, TilingDirectionData, WindowsData,
-  WmEvent, WorkspacesData, DEFAULT_IPC_PORT,
+  ClientResponseMessage, CommandData, ContainerRectData,
+  EventSubscribeData, EventSubscriptionMessage, FocusedData, MonitorsData,
+  QueryCommand, SelectionData, ServerMessage, SubscribableEvent,
+  TilingDirectionData, WindowsData, WmEvent, WorkspacesData,
+  DEFAULT_IPC_PORT,
 };
 
 use crate::{
-  traits::{CommonGetters, TilingDirectionGetters},
+  traits::{CommonGetters, PositionGetters, TilingDirectionGetters},
   user_config::UserConfig,
   wm::WindowManager,
 };
@@ -239,12 +240,42 @@ impl IpcServer {
             tiling_direction: direction_container.tiling_direction(),
           })
         }
+        QueryCommand::ContainerRect { container_id } => {
+          let container =
+            wm.state.container_by_id(container_id).with_context(|| {
+              format!("No container found with ID '{container_id}'.")
+            })?;
+
+          ClientResponseData::ContainerRect(ContainerRectData {
+            container_id,
+            rect: container.to_rect()?,
+          })
+        }
         QueryCommand::Paused => {
           ClientResponseData::Paused(wm.state.is_paused)
         }
+        QueryCommand::Selection => {
+          ClientResponseData::Selection(SelectionData {
+            selected: wm
+              .state
+              .selected_container()
+              .map(|selection| selection.to_dto())
+              .transpose()?,
+          })
+        }
       },
       AppCommand::Command {
         subject_container_id,
+        preview: true,
+        command,
+      } => ClientResponseData::CommandPreview(wm.preview_commands(
+        &vec![command],
+        subject_container_id,
+        config,
+      )?),
+      AppCommand::Command {
+        subject_container_id,
+        preview: false,
         command,
       } => {
         let subject_container_id = wm.process_commands(
@@ -279,8 +310,9 @@ impl IpcServer {
               }
               Ok((event_type, event)) = event_rx.recv() => {
                 // Check whether the event is one of the subscribed events.
-                if events.contains(&event_type)
-                  || events.contains(&SubscribableEvent::All)
+                if events
+                  .iter()
+                  .any(|subscribed| subscribed.matches(&event_type))
                 {
                   let res = Self::to_event_subscription_msg(
                     subscription_id,
@@ -351,39 +383,7 @@ impl IpcServer {
   }
 
   pub fn process_event(&mut self, event: WmEvent) -> anyhow::Result<()> {
-    let event_type = match event {
-      WmEvent::ApplicationExiting => SubscribableEvent::ApplicationExiting,
-      WmEvent::BindingModesChanged { .. } => {
-        SubscribableEvent::BindingModesChanged
-      }
-      WmEvent::FocusChanged { .. } => SubscribableEvent::FocusChanged,
-      WmEvent::FocusedContainerMoved { .. } => {
-        SubscribableEvent::FocusedContainerMoved
-      }
-      WmEvent::MonitorAdded { .. } => SubscribableEvent::MonitorAdded,
-      WmEvent::MonitorUpdated { .. } => SubscribableEvent::MonitorUpdated,
-      WmEvent::MonitorRemoved { .. } => SubscribableEvent::MonitorRemoved,
-      WmEvent::TilingDirectionChanged { .. } => {
-        SubscribableEvent::TilingDirectionChanged
-      }
-      WmEvent::UserConfigChanged { .. } => {
-        SubscribableEvent::UserConfigChanged
-      }
-      WmEvent::WindowManaged { .. } => SubscribableEvent::WindowManaged,
-      WmEvent::WindowUnmanaged { .. } => {
-        SubscribableEvent::WindowUnmanaged
-      }
-      WmEvent::WorkspaceActivated { .. } => {
-        SubscribableEvent::WorkspaceActivated
-      }
-      WmEvent::WorkspaceDeactivated { .. } => {
-        SubscribableEvent::WorkspaceDeactivated
-      }
-      WmEvent::WorkspaceUpdated { .. } => {
-        SubscribableEvent::WorkspaceUpdated
-      }
-      WmEvent::PauseChanged { .. } => SubscribableEvent::PauseChanged,
-    };
+    let event_type = event.event_type();
 
     self
       .event_tx
@@ -22,16 +22,23 @@ use wm_common::{AppCommand, InvokeCommand, Verbosity, WmEvent};
 use wm_platform::Platform;
 
 use crate::{
-  ipc_server::IpcServer, sys_tray::SystemTray, user_config::UserConfig,
+  command_log::{restore_command_log_file, CommandLog},
+  commands::general::platform_sync,
+  ipc_server::IpcServer,
+  sys_tray::SystemTray,
+  user_config::UserConfig,
   wm::WindowManager,
 };
 
+mod command_log;
 mod commands;
 mod events;
 mod ipc_server;
 mod models;
 mod pending_sync;
 mod sys_tray;
+#[cfg(test)]
+mod test_utils;
 mod traits;
 mod user_config;
 mod wm;
@@ -49,9 +56,13 @@ async fn main() -> anyhow::Result<()> {
   match app_command {
     AppCommand::Start {
       config_path,
+      command_log_path,
+      restore_path,
       verbosity,
     } => {
-      let res = start_wm(config_path, verbosity).await;
+      let res =
+        start_wm(config_path, command_log_path, restore_path, verbosity)
+          .await;
 
       // If unable to start the WM, the error is fatal and a message dialog
       // is shown.
@@ -68,6 +79,8 @@ async fn main() -> anyhow::Result<()> {
 
 async fn start_wm(
   config_path: Option<PathBuf>,
+  command_log_path: Option<PathBuf>,
+  restore_path: Option<PathBuf>,
   verbosity: Verbosity,
 ) -> anyhow::Result<()> {
   setup_logging(&verbosity)?;
@@ -85,6 +98,7 @@ async fn start_wm(
   let mut tray = SystemTray::new(&config.path)?;
 
   let mut wm = WindowManager::new(&mut config)?;
+  wm.command_log = command_log_path.map(CommandLog::new);
 
   let mut ipc_server = IpcServer::start().await?;
 
@@ -95,6 +109,12 @@ async fn start_wm(
   let startup_commands = config.value.general.startup_commands.clone();
   wm.process_commands(&startup_commands, None, &mut config)?;
 
+  // Restore the last recorded layouts from a command log.
+  if let Some(restore_path) = restore_path {
+    restore_command_log_file(&restore_path, &mut wm.state, &config)?;
+    platform_sync(&mut wm.state, &config)?;
+  }
+
   loop {
     let res = tokio::select! {
       Some(()) = tray.exit_rx.recv() => {
@@ -7,8 +7,9 @@ use ambassador::Delegate;
 use enum_as_inner::EnumAsInner;
 use uuid::Uuid;
 use wm_common::{
-  ActiveDrag, ContainerDto, Direction, DisplayState, GapsConfig, Rect,
-  RectDelta, TilingDirection, WindowRuleConfig, WindowState,
+  ActiveDrag, ContainerDto, Direction, DisplayState, GapsConfig,
+  OpacityValue, Rect, RectDelta, TilingDirection, WindowRuleConfig,
+  WindowState,
 };
 use wm_platform::NativeWindow;
 
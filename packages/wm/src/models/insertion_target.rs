@@ -1,3 +1,5 @@
+use uuid::Uuid;
+
 use crate::models::Container;
 
 #[derive(Debug, Clone)]
@@ -6,4 +8,9 @@ pub struct InsertionTarget {
   pub target_index: usize,
   pub prev_tiling_size: f32,
   pub prev_sibling_count: usize,
+
+  /// IDs and tiling sizes of the window and its siblings, in order,
+  /// before the window was removed. Used to restore the exact previous
+  /// layout if the siblings haven't changed in the meantime.
+  pub prev_tiling_sizes: Vec<(Uuid, f32)>,
 }
@@ -6,7 +6,7 @@ use std::{
 
 use anyhow::Context;
 use uuid::Uuid;
-use wm_common::{ContainerDto, MonitorDto, Rect};
+use wm_common::{ContainerDto, MonitorDto, MonitorIdentity, Rect, Struts};
 use wm_platform::NativeMonitor;
 
 use crate::{
@@ -21,12 +21,14 @@ use crate::{
 #[derive(Clone)]
 pub struct Monitor(Rc<RefCell<MonitorInner>>);
 
+#[derive(Clone)]
 struct MonitorInner {
   id: Uuid,
   parent: Option<Container>,
   children: VecDeque<Container>,
   child_focus_order: VecDeque<Uuid>,
   native: NativeMonitor,
+  struts: Struts,
 }
 
 impl Monitor {
@@ -37,6 +39,7 @@ impl Monitor {
       children: VecDeque::new(),
       child_focus_order: VecDeque::new(),
       native: native_monitor,
+      struts: Struts::default(),
     };
 
     Self(Rc::new(RefCell::new(monitor)))
@@ -50,6 +53,15 @@ impl Monitor {
     self.0.borrow_mut().native = native;
   }
 
+  /// Edges of the monitor reserved by third-party bars and docks.
+  pub fn struts(&self) -> Struts {
+    self.0.borrow().struts.clone()
+  }
+
+  pub fn set_struts(&self, struts: Struts) {
+    self.0.borrow_mut().struts = struts;
+  }
+
   pub fn displayed_workspace(&self) -> Option<Workspace> {
     self
       .child_focus_order()
@@ -81,6 +93,15 @@ impl Monitor {
     Ok(dpi != other_dpi)
   }
 
+  /// Gets the hardware ID and bounds of the monitor, which are used to
+  /// find it again after it's reconnected.
+  pub fn identity(&self) -> anyhow::Result<MonitorIdentity> {
+    Ok(MonitorIdentity {
+      hardware_id: self.native().hardware_id()?.cloned(),
+      rect: self.to_rect()?,
+    })
+  }
+
   pub fn to_dto(&self) -> anyhow::Result<ContainerDto> {
     let rect = self.to_rect()?;
     let children = self
@@ -106,6 +127,7 @@ impl Monitor {
       device_path: self.native().device_path()?.cloned(),
       hardware_id: self.native().hardware_id()?.cloned(),
       working_rect: self.native().working_rect()?.clone(),
+      struts: self.struts(),
     }))
   }
 }
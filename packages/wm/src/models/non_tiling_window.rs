@@ -7,8 +7,8 @@ use std::{
 use anyhow::Context;
 use uuid::Uuid;
 use wm_common::{
-  ActiveDrag, ContainerDto, DisplayState, GapsConfig, Rect, RectDelta,
-  WindowDto, WindowRuleConfig, WindowState,
+  ActiveDrag, ContainerDto, DisplayState, GapsConfig, OpacityValue, Rect,
+  RectDelta, WindowDto, WindowRuleConfig, WindowState,
 };
 use wm_platform::NativeWindow;
 
@@ -24,6 +24,7 @@ use crate::{
 #[derive(Clone)]
 pub struct NonTilingWindow(Rc<RefCell<NonTilingWindowInner>>);
 
+#[derive(Clone)]
 struct NonTilingWindowInner {
   id: Uuid,
   parent: Option<Container>,
@@ -40,6 +41,7 @@ struct NonTilingWindowInner {
   has_custom_floating_placement: bool,
   done_window_rules: Vec<WindowRuleConfig>,
   active_drag: Option<ActiveDrag>,
+  opacity: Option<OpacityValue>,
 }
 
 impl NonTilingWindow {
@@ -55,6 +57,7 @@ impl NonTilingWindow {
     has_custom_floating_placement: bool,
     done_window_rules: Vec<WindowRuleConfig>,
     active_drag: Option<ActiveDrag>,
+    opacity: Option<OpacityValue>,
   ) -> Self {
     let window = NonTilingWindowInner {
       id: id.unwrap_or_else(Uuid::new_v4),
@@ -72,6 +75,7 @@ impl NonTilingWindow {
       has_custom_floating_placement,
       done_window_rules,
       active_drag,
+      opacity,
     };
 
     Self(Rc::new(RefCell::new(window)))
@@ -99,6 +103,7 @@ impl NonTilingWindow {
       gaps_config,
       self.done_window_rules(),
       self.active_drag(),
+      self.opacity(),
     )
   }
 
@@ -124,6 +129,7 @@ impl NonTilingWindow {
       class_name: self.native().class_name()?,
       process_name: self.native().process_name()?,
       active_drag: self.active_drag(),
+      opacity: self.opacity(),
     }))
   }
 }
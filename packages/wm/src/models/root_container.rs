@@ -21,6 +21,7 @@ use crate::{
 #[derive(Clone)]
 pub struct RootContainer(Rc<RefCell<RootContainerInner>>);
 
+#[derive(Clone)]
 struct RootContainerInner {
   id: Uuid,
   parent: Option<Container>,
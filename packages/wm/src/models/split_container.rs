@@ -26,6 +26,7 @@ use crate::{
 #[derive(Clone)]
 pub struct SplitContainer(Rc<RefCell<SplitContainerInner>>);
 
+#[derive(Clone)]
 struct SplitContainerInner {
   id: Uuid,
   parent: Option<Container>,
@@ -34,6 +35,7 @@ struct SplitContainerInner {
   tiling_size: f32,
   tiling_direction: TilingDirection,
   gaps_config: GapsConfig,
+  is_tabbed: bool,
 }
 
 impl SplitContainer {
@@ -49,11 +51,37 @@ impl SplitContainer {
       tiling_size: 1.0,
       tiling_direction,
       gaps_config,
+      is_tabbed: false,
     };
 
     Self(Rc::new(RefCell::new(split)))
   }
 
+  /// Whether the children are shown as tabs, where only the active tab
+  /// is visible and takes up the whole split container.
+  pub fn is_tabbed(&self) -> bool {
+    self.0.borrow().is_tabbed
+  }
+
+  pub fn set_tabbed(&self, is_tabbed: bool) {
+    self.0.borrow_mut().is_tabbed = is_tabbed;
+  }
+
+  /// Gets the tab that's shown when tabbed, which is the most recently
+  /// focused tiling child.
+  ///
+  /// The active tab is derived from the focus order, such that it
+  /// persists while focus is elsewhere in the tree.
+  pub fn active_tab(&self) -> Option<TilingContainer> {
+    let child_focus_order = self.borrow_child_focus_order().clone();
+
+    child_focus_order
+      .iter()
+      .filter_map(|id| self.child_by_id(id))
+      .find_map(|child| child.as_tiling_container().ok())
+      .or_else(|| self.tiling_children().next())
+  }
+
   pub fn to_dto(&self) -> anyhow::Result<ContainerDto> {
     let rect = self.to_rect()?;
     let children = self
@@ -70,6 +98,7 @@ impl SplitContainer {
       has_focus: self.has_focus(None),
       tiling_size: self.tiling_size(),
       tiling_direction: self.tiling_direction(),
+      is_tabbed: self.is_tabbed(),
       width: rect.width(),
       height: rect.height(),
       x: rect.x(),
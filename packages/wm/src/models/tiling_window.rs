@@ -7,8 +7,8 @@ use std::{
 use anyhow::Context;
 use uuid::Uuid;
 use wm_common::{
-  ActiveDrag, ContainerDto, DisplayState, GapsConfig, Rect, RectDelta,
-  TilingDirection, WindowDto, WindowRuleConfig, WindowState,
+  ActiveDrag, ContainerDto, DisplayState, GapsConfig, OpacityValue, Rect,
+  RectDelta, WindowDto, WindowRuleConfig, WindowState,
 };
 use wm_platform::NativeWindow;
 
@@ -29,6 +29,7 @@ use crate::{
 #[derive(Clone)]
 pub struct TilingWindow(Rc<RefCell<TilingWindowInner>>);
 
+#[derive(Clone)]
 struct TilingWindowInner {
   id: Uuid,
   parent: Option<Container>,
@@ -46,6 +47,7 @@ struct TilingWindowInner {
   gaps_config: GapsConfig,
   done_window_rules: Vec<WindowRuleConfig>,
   active_drag: Option<ActiveDrag>,
+  opacity: Option<OpacityValue>,
 }
 
 impl TilingWindow {
@@ -60,6 +62,7 @@ impl TilingWindow {
     gaps_config: GapsConfig,
     done_window_rules: Vec<WindowRuleConfig>,
     active_drag: Option<ActiveDrag>,
+    opacity: Option<OpacityValue>,
   ) -> Self {
     let window = TilingWindowInner {
       id: id.unwrap_or_else(Uuid::new_v4),
@@ -78,6 +81,7 @@ impl TilingWindow {
       gaps_config,
       done_window_rules,
       active_drag,
+      opacity,
     };
 
     Self(Rc::new(RefCell::new(window)))
@@ -99,6 +103,7 @@ impl TilingWindow {
       self.has_custom_floating_placement(),
       self.done_window_rules(),
       self.active_drag(),
+      self.opacity(),
     )
   }
 
@@ -124,6 +129,7 @@ impl TilingWindow {
       class_name: self.native().class_name()?,
       process_name: self.native().process_name()?,
       active_drag: self.active_drag(),
+      opacity: self.opacity(),
     }))
   }
 }
@@ -7,8 +7,8 @@ use std::{
 use anyhow::Context;
 use uuid::Uuid;
 use wm_common::{
-  ContainerDto, GapsConfig, Rect, TilingDirection, WorkspaceConfig,
-  WorkspaceDto,
+  ContainerDto, GapsConfig, LayoutIssue, Rect, TilingDirection,
+  WorkspaceConfig, WorkspaceDto,
 };
 
 use crate::{
@@ -23,7 +23,7 @@ use crate::{
 #[derive(Clone)]
 pub struct Workspace(Rc<RefCell<WorkspaceInner>>);
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 struct WorkspaceInner {
   id: Uuid,
   parent: Option<Container>,
@@ -75,6 +75,28 @@ impl Workspace {
     self.0.borrow_mut().gaps_config = gaps_config;
   }
 
+  /// Resets the tiling sizes of all containers in the workspace, such
+  /// that siblings at every level of the tree are evenly sized.
+  pub fn balance_all(&self) {
+    self.equalize_children(true);
+  }
+
+  /// Checks the layout of the workspace's tiling windows for windows that
+  /// overlap or extend beyond the workspace.
+  ///
+  /// Windows in inactive tabs are skipped, since they share the rect of
+  /// the active tab.
+  pub fn validate_layout(&self) -> anyhow::Result<Vec<LayoutIssue>> {
+    let window_rects = self
+      .descendants()
+      .filter_map(|descendant| descendant.as_tiling_window().cloned())
+      .filter(|window| !window.is_in_inactive_tab())
+      .map(|window| anyhow::Ok((window.id(), window.to_rect()?)))
+      .try_collect::<Vec<_>>()?;
+
+    Ok(LayoutIssue::find_all(&window_rects, &self.to_rect()?))
+  }
+
   pub fn to_dto(&self) -> anyhow::Result<ContainerDto> {
     let rect = self.to_rect()?;
     let config = self.config();
@@ -113,10 +135,8 @@ impl PositionGetters for Workspace {
       self.monitor().context("Workspace has no parent monitor.")?;
 
     let gaps_config = &self.0.borrow().gaps_config;
-    let scale_factor = match &gaps_config.scale_with_dpi {
-      true => monitor.native().scale_factor()?,
-      false => 1.,
-    };
+    let scale_factor =
+      gaps_config.scale_factor(monitor.native().scale_factor()?);
 
     // Get delta between monitor bounds and its working area.
     let working_delta = monitor
@@ -136,11 +156,19 @@ impl PositionGetters for Workspace {
       &gaps_config.outer_gap
     };
 
+    let monitor_rect = monitor.to_rect()?;
+
     Ok(
       monitor
-        .to_rect()?
-        // Scale the gaps if `scale_with_dpi` is enabled.
-        .apply_inverse_delta(gaps, Some(scale_factor))
+        .struts()
+        .apply(&monitor_rect)
+        // Percentage gaps are relative to the monitor's size. Scale the
+        // gaps if `scale_with_dpi` is enabled.
+        .apply_inverse_delta_relative_to(
+          gaps,
+          &monitor_rect,
+          Some(scale_factor),
+        )
         .apply_delta(&working_delta, None),
     )
   }
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use uuid::Uuid;
+use wm_common::{LayoutCache, Rect};
 
 use crate::{
   models::{Container, Workspace},
@@ -30,6 +31,11 @@ pub struct PendingSync {
   /// Whether to jump the cursor to the focused container (if enabled in
   /// user config).
   needs_cursor_jump: bool,
+
+  /// Layout rects of direction containers, as used by
+  /// `WmState::cached_rect`. Invalidated whenever containers are queued
+  /// for redraw, since the layout has then changed.
+  layout_cache: LayoutCache<Vec<Rect>>,
 }
 
 impl PendingSync {
@@ -49,6 +55,7 @@ impl PendingSync {
     self.needs_focused_effect_update = false;
     self.needs_all_effects_update = false;
     self.needs_cursor_jump = false;
+    self.layout_cache.invalidate();
     self
   }
 
@@ -58,6 +65,7 @@ impl PendingSync {
   {
     let container: Container = container.into();
     self.containers_to_redraw.insert(container.id(), container);
+    self.layout_cache.invalidate();
     self
   }
 
@@ -74,6 +82,7 @@ impl PendingSync {
       self.containers_to_redraw.insert(container.id(), container);
     }
 
+    self.layout_cache.invalidate();
     self
   }
 
@@ -132,6 +141,10 @@ impl PendingSync {
     self.needs_cursor_jump
   }
 
+  pub fn layout_cache(&self) -> &LayoutCache<Vec<Rect>> {
+    &self.layout_cache
+  }
+
   pub fn containers_to_redraw(&self) -> &HashMap<Uuid, Container> {
     &self.containers_to_redraw
   }
@@ -0,0 +1,166 @@
+//! Fixtures for building a container tree in unit tests. Monitors and
+//! windows are backed by mocked native handles, so the tree can be built
+//! and laid out without querying the OS.
+
+use std::sync::atomic::{AtomicIsize, Ordering};
+
+use tokio::sync::mpsc;
+use wm_common::{GapsConfig, Rect, RectDelta, TilingDirection, WmEvent};
+use wm_platform::{NativeMonitor, NativeWindow};
+
+use crate::{
+  commands::{container::attach_container, monitor::add_monitor},
+  models::{Container, Monitor, SplitContainer, TilingWindow, Workspace},
+  traits::WindowGetters,
+  user_config::UserConfig,
+  wm_state::WmState,
+};
+
+/// Counter for handing out unique native handles.
+static NEXT_HANDLE: AtomicIsize = AtomicIsize::new(1);
+
+fn next_handle() -> isize {
+  NEXT_HANDLE.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Creates an empty `WmState` whose event channels are discarded.
+pub fn test_state() -> WmState {
+  let (event_tx, _) = mpsc::unbounded_channel();
+  let (exit_tx, _) = mpsc::unbounded_channel();
+  WmState::new(event_tx, exit_tx)
+}
+
+/// Creates an initialized `WmState`, along with a receiver for the events
+/// that it emits.
+pub fn test_state_with_events(
+) -> (WmState, mpsc::UnboundedReceiver<WmEvent>) {
+  let (event_tx, event_rx) = mpsc::unbounded_channel();
+  let (exit_tx, _) = mpsc::unbounded_channel();
+
+  let mut state = WmState::new(event_tx, exit_tx);
+  state.mark_initialized();
+  (state, event_rx)
+}
+
+/// Creates a `UserConfig` from the sample config with all gaps removed.
+pub fn test_config() -> UserConfig {
+  let mut config = UserConfig::sample().unwrap();
+  config.value.gaps = GapsConfig::default();
+  config
+}
+
+/// Adds a monitor with the given rect via `add_monitor`, which also
+/// activates a workspace on it.
+pub fn add_test_monitor(
+  rect: Rect,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> Monitor {
+  add_native_monitor(rect, None, state, config)
+}
+
+/// Adds a monitor with the given rect and hardware ID via `add_monitor`.
+pub fn add_test_monitor_with_hardware_id(
+  rect: Rect,
+  hardware_id: &str,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> Monitor {
+  add_native_monitor(rect, Some(hardware_id.to_string()), state, config)
+}
+
+fn add_native_monitor(
+  rect: Rect,
+  hardware_id: Option<String>,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> Monitor {
+  let handle = next_handle();
+  let native =
+    NativeMonitor::new_mock(handle, rect.clone(), rect, hardware_id);
+  add_monitor(native, state, config).unwrap();
+
+  state
+    .monitors()
+    .into_iter()
+    .find(|monitor| monitor.native().handle == handle)
+    .unwrap()
+}
+
+/// Gets the displayed workspace of the given monitor.
+pub fn displayed_workspace(monitor: &Monitor) -> Workspace {
+  monitor.displayed_workspace().unwrap()
+}
+
+/// Creates a mocked native window with the given title and process name.
+pub fn test_native_window(
+  title: &str,
+  process_name: &str,
+) -> NativeWindow {
+  NativeWindow::new_mock(
+    next_handle(),
+    title,
+    process_name,
+    "test",
+    Rect::from_xy(0, 0, 100, 100),
+  )
+}
+
+/// Creates a tiling window and appends it to the given parent.
+pub fn add_test_window(
+  parent: &Container,
+  config: &UserConfig,
+) -> TilingWindow {
+  add_test_window_with_title("", parent, config)
+}
+
+/// Creates a tiling window with the given title and appends it to the
+/// given parent.
+pub fn add_test_window_with_title(
+  title: &str,
+  parent: &Container,
+  config: &UserConfig,
+) -> TilingWindow {
+  let window = test_window(title, config);
+  attach_container(&window.clone().into(), parent, None).unwrap();
+  window
+}
+
+/// Creates a detached tiling window with the given title.
+pub fn test_window(title: &str, config: &UserConfig) -> TilingWindow {
+  TilingWindow::new(
+    None,
+    test_native_window(title, "test"),
+    None,
+    RectDelta::zero(),
+    Rect::from_xy(0, 0, 100, 100),
+    false,
+    config.value.gaps.clone(),
+    Vec::new(),
+    None,
+    None,
+  )
+}
+
+/// Gets the tiling window with the given title.
+pub fn window_by_title(state: &WmState, title: &str) -> TilingWindow {
+  state
+    .windows()
+    .into_iter()
+    .filter_map(|window| window.as_tiling_window().cloned())
+    .find(|window| window.native().title().unwrap() == title)
+    .unwrap()
+}
+
+/// Creates a split container and appends it to the given parent.
+pub fn add_test_split(
+  parent: &Container,
+  tiling_direction: TilingDirection,
+  config: &UserConfig,
+) -> SplitContainer {
+  let split =
+    SplitContainer::new(tiling_direction, config.value.gaps.clone());
+
+  attach_container(&split.clone().into(), parent, None).unwrap();
+  split
+}
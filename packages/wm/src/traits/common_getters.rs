@@ -8,7 +8,7 @@ use uuid::Uuid;
 use wm_common::ContainerDto;
 
 use crate::models::{
-  Container, DirectionContainer, Monitor, TilingContainer,
+  Container, DirectionContainer, Monitor, SplitContainer, TilingContainer,
   WindowContainer, Workspace,
 };
 
@@ -27,6 +27,12 @@ pub trait CommonGetters {
 
   fn to_dto(&self) -> anyhow::Result<ContainerDto>;
 
+  /// Copies the container and all its descendants into a new, detached
+  /// tree that doesn't share any state with the original.
+  ///
+  /// Container IDs are kept the same in the copy.
+  fn deep_clone(&self) -> Container;
+
   fn borrow_parent(&self) -> Ref<'_, Option<Container>>;
 
   fn borrow_parent_mut(&self) -> RefMut<'_, Option<Container>>;
@@ -274,6 +280,19 @@ pub trait CommonGetters {
       .chain(end_ancestor.clone())
       .all(|ancestor| ancestor.focus_index() == 0)
   }
+
+  /// Whether this container is within an inactive tab of a tabbed split
+  /// container, in which case it should be hidden.
+  fn is_in_inactive_tab(&self) -> bool {
+    self.self_and_ancestors().any(|ancestor| {
+      ancestor
+        .parent()
+        .and_then(|parent| parent.as_split().cloned())
+        .filter(SplitContainer::is_tabbed)
+        .and_then(|split_parent| split_parent.active_tab())
+        .is_some_and(|active_tab| active_tab.id() != ancestor.id())
+    })
+  }
 }
 
 /// An iterator over ancestors of a given container.
@@ -345,6 +364,23 @@ macro_rules! impl_common_getters {
         self.to_dto()
       }
 
+      fn deep_clone(&self) -> Container {
+        let mut inner = self.0.borrow().clone();
+        inner.parent = None;
+        inner.children = VecDeque::new();
+
+        let copy =
+          $struct_name(Rc::new(RefCell::new(inner))).as_container();
+
+        for child in self.children() {
+          let child_copy = child.deep_clone();
+          *child_copy.borrow_parent_mut() = Some(copy.clone());
+          copy.borrow_children_mut().push_back(child_copy);
+        }
+
+        copy
+      }
+
       fn borrow_parent(&self) -> Ref<'_, Option<Container>> {
         Ref::map(self.0.borrow(), |inner| &inner.parent)
       }
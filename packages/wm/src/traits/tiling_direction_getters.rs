@@ -1,15 +1,128 @@
 use ambassador::delegatable_trait;
-use wm_common::{Direction, TilingDirection};
+use wm_common::{
+  clamp_tiling_sizes, tabbed_layout, Direction, Rect, TilingDirection,
+};
 
-use super::CommonGetters;
-use crate::models::{TilingContainer, TilingWindow};
+use super::{CommonGetters, PositionGetters, TilingSizeGetters};
+use crate::models::{SplitContainer, TilingContainer, TilingWindow};
 
 #[delegatable_trait]
-pub trait TilingDirectionGetters: CommonGetters {
+pub trait TilingDirectionGetters: CommonGetters + PositionGetters {
   fn tiling_direction(&self) -> TilingDirection;
 
   fn set_tiling_direction(&self, tiling_direction: TilingDirection);
 
+  /// Gets the rects of all tiling children, in the same order as
+  /// `tiling_children()`.
+  ///
+  /// Computes the layout of all children in a single pass, so that
+  /// getting the rect of each child doesn't require walking its previous
+  /// siblings.
+  fn layout_rects(&self) -> anyhow::Result<Vec<Rect>> {
+    self.layout_rects_within(&self.to_rect()?)
+  }
+
+  /// Gets the rects of all tiling children when the container is laid
+  /// out at the given rect.
+  ///
+  /// This allows the container's own rect to come from elsewhere (e.g.
+  /// `WmState::cached_rect`).
+  fn layout_rects_within(&self, rect: &Rect) -> anyhow::Result<Vec<Rect>> {
+    let tiling_children = self.tiling_children().collect::<Vec<_>>();
+
+    let Some(first_child) = tiling_children.first() else {
+      return Ok(Vec::new());
+    };
+
+    let tiling_direction = self.tiling_direction();
+
+    let (horizontal_gap, vertical_gap) = first_child.inner_gaps()?;
+    let inner_gap = match tiling_direction {
+      TilingDirection::Vertical => vertical_gap,
+      TilingDirection::Horizontal => horizontal_gap,
+    };
+
+    // Workspaces can opt into an alternative layout for their direct
+    // children.
+    let tiling_layout = self
+      .workspace()
+      .filter(|workspace| workspace.id() == self.id())
+      .map(|workspace| workspace.config().tiling_layout)
+      .unwrap_or_default();
+
+    let child_ids = tiling_children
+      .iter()
+      .map(CommonGetters::id)
+      .collect::<Vec<_>>();
+
+    // Tabs of tabbed split containers all take up the whole rect.
+    // Inactive tabs are hidden when redrawn (see
+    // `is_in_inactive_tab`).
+    let is_tabbed = self
+      .as_container()
+      .as_split()
+      .is_some_and(SplitContainer::is_tabbed);
+
+    if is_tabbed {
+      return Ok(
+        tabbed_layout(rect, &child_ids)
+          .into_iter()
+          .map(|(_, rect)| rect)
+          .collect(),
+      );
+    }
+
+    if let Some(window_rects) = tiling_layout.window_rects(
+      rect,
+      &child_ids,
+      horizontal_gap,
+      vertical_gap,
+    ) {
+      return Ok(window_rects.into_iter().map(|(_, rect)| rect).collect());
+    }
+
+    let tiling_sizes = tiling_children
+      .iter()
+      .map(TilingSizeGetters::tiling_size)
+      .collect::<Vec<_>>();
+
+    // Prevent children from being laid out smaller than the minimum
+    // tiling size, regardless of how their sizes were set.
+    let rect_length = match tiling_direction {
+      TilingDirection::Horizontal => rect.width(),
+      TilingDirection::Vertical => rect.height(),
+    };
+
+    let min_size = first_child
+      .gaps_config()
+      .min_tiling_size
+      .to_percentage(rect_length.max(1));
+
+    let tiling_sizes = clamp_tiling_sizes(&tiling_sizes, min_size);
+
+    Ok(rect.split_weighted(&tiling_sizes, &tiling_direction, inner_gap))
+  }
+
+  /// Resets the tiling size of all tiling children so that they're
+  /// evenly sized. Nested split containers are also equalized if
+  /// `recursive` is true.
+  fn equalize_children(&self, recursive: bool) {
+    let tiling_children = self.tiling_children().collect::<Vec<_>>();
+
+    #[allow(clippy::cast_precision_loss)]
+    let tiling_size = 1. / tiling_children.len() as f32;
+
+    for child in tiling_children {
+      child.set_tiling_size(tiling_size);
+
+      if let TilingContainer::Split(split_child) = child {
+        if recursive {
+          split_child.equalize_children(recursive);
+        }
+      }
+    }
+  }
+
   /// Traverses down a container in search of a descendant in the given
   /// direction. For example, for `Direction::Right`, get the right-most
   /// container.
@@ -34,10 +147,16 @@ pub trait TilingDirectionGetters: CommonGetters {
     &self,
     direction: &Direction,
   ) -> Option<TilingContainer> {
-    // When the tiling direction is the inverse of the direction, return
-    // the last focused tiling child.
-    if self.tiling_direction()
-      != TilingDirection::from_direction(direction)
+    // When the tiling direction is the inverse of the direction, or the
+    // children are tabs, return the last focused tiling child.
+    let is_tabbed = self
+      .as_container()
+      .as_split()
+      .is_some_and(SplitContainer::is_tabbed);
+
+    if is_tabbed
+      || self.tiling_direction()
+        != TilingDirection::from_direction(direction)
     {
       return self
         .child_focus_order()
@@ -69,3 +188,172 @@ macro_rules! impl_tiling_direction_getters {
     }
   };
 }
+
+#[cfg(test)]
+mod tests {
+  use wm_common::{LengthValue, Rect, TilingDirection};
+
+  use super::*;
+  use crate::{
+    test_utils::{
+      add_test_monitor, add_test_split, add_test_window,
+      displayed_workspace, test_config, test_state,
+    },
+    wm_state::WmState,
+  };
+
+  #[test]
+  fn test_layout_rects_matches_child_rects() {
+    let mut state = test_state();
+    let config = test_config();
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1200, 600),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    let windows = (0..3)
+      .map(|_| add_test_window(&workspace.clone().into(), &config))
+      .collect::<Vec<_>>();
+
+    let rects = workspace.layout_rects().unwrap();
+
+    assert_eq!(
+      rects,
+      vec![
+        Rect::from_xy(0, 0, 400, 600),
+        Rect::from_xy(400, 0, 400, 600),
+        Rect::from_xy(800, 0, 400, 600),
+      ]
+    );
+
+    for (window, rect) in windows.iter().zip(&rects) {
+      assert_eq!(&window.to_rect().unwrap(), rect);
+    }
+  }
+
+  #[test]
+  fn test_layout_rects_follows_tiling_sizes() {
+    let mut state = test_state();
+    let config = test_config();
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1200, 600),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    let window = add_test_window(&workspace.clone().into(), &config);
+    let split = add_test_split(
+      &workspace.clone().into(),
+      TilingDirection::Vertical,
+      &config,
+    );
+    let top = add_test_window(&split.clone().into(), &config);
+    let bottom = add_test_window(&split.clone().into(), &config);
+
+    window.set_tiling_size(0.75);
+    split.set_tiling_size(0.25);
+    top.set_tiling_size(0.5);
+    bottom.set_tiling_size(0.5);
+
+    assert_eq!(window.to_rect().unwrap(), Rect::from_xy(0, 0, 900, 600));
+    assert_eq!(
+      split.layout_rects().unwrap(),
+      vec![
+        Rect::from_xy(900, 0, 300, 300),
+        Rect::from_xy(900, 300, 300, 300),
+      ]
+    );
+    assert_eq!(
+      bottom.to_rect().unwrap(),
+      Rect::from_xy(900, 300, 300, 300)
+    );
+  }
+
+  #[test]
+  fn test_layout_rects_enforces_min_tiling_size() {
+    let mut state = test_state();
+    let mut config = test_config();
+    config.value.gaps.min_tiling_size = LengthValue::from_px(100);
+
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1000, 600),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    let windows = (0..3)
+      .map(|_| add_test_window(&workspace.clone().into(), &config))
+      .collect::<Vec<_>>();
+
+    // Sizes set directly (e.g. from a restored layout) bypass the
+    // clamping done when resizing.
+    windows[0].set_tiling_size(0.02);
+    windows[1].set_tiling_size(0.49);
+    windows[2].set_tiling_size(0.49);
+
+    assert_eq!(
+      workspace.layout_rects().unwrap(),
+      vec![
+        Rect::from_xy(0, 0, 100, 600),
+        Rect::from_xy(100, 0, 450, 600),
+        Rect::from_xy(550, 0, 450, 600),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_cached_rect_invalidated_on_redraw() {
+    let mut state = test_state();
+    let config = test_config();
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1200, 600),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+
+    let window = add_test_window(&workspace.clone().into(), &config);
+    let split = add_test_split(
+      &workspace.clone().into(),
+      TilingDirection::Vertical,
+      &config,
+    );
+    let top = add_test_window(&split.clone().into(), &config);
+    let bottom = add_test_window(&split.clone().into(), &config);
+
+    let cached_rect = |container: &TilingWindow, state: &WmState| {
+      state.cached_rect(&container.clone().into()).unwrap()
+    };
+
+    for child in [&window, &top, &bottom] {
+      assert_eq!(cached_rect(child, &state), child.to_rect().unwrap());
+    }
+
+    assert_eq!(
+      split.layout_rects().unwrap(),
+      vec![cached_rect(&top, &state), cached_rect(&bottom, &state)]
+    );
+
+    // Layout rects stay cached until a redraw is queued.
+    window.set_tiling_size(0.75);
+    split.set_tiling_size(0.25);
+    assert_eq!(
+      cached_rect(&bottom, &state),
+      Rect::from_xy(600, 300, 600, 300)
+    );
+
+    state.pending_sync.queue_container_to_redraw(workspace);
+    assert_eq!(
+      cached_rect(&bottom, &state),
+      Rect::from_xy(900, 300, 300, 300)
+    );
+    assert_eq!(
+      split.layout_rects().unwrap()[1],
+      cached_rect(&bottom, &state)
+    );
+  }
+}
@@ -22,22 +22,13 @@ pub trait TilingSizeGetters: CommonGetters {
   /// Gets the horizontal and vertical gaps between windows in pixels.
   fn inner_gaps(&self) -> anyhow::Result<(i32, i32)> {
     let monitor = self.monitor().context("No monitor.")?;
-    let monitor_rect = monitor.to_rect()?;
-    let gaps_config = self.gaps_config();
+    let workspace_gaps =
+      self.workspace().and_then(|workspace| workspace.config().gaps);
 
-    let scale_factor = if gaps_config.scale_with_dpi {
-      monitor.native().scale_factor()?
-    } else {
-      1.
-    };
-
-    Ok((
-      gaps_config
-        .inner_gap
-        .to_px(monitor_rect.height(), Some(scale_factor)),
-      gaps_config
-        .inner_gap
-        .to_px(monitor_rect.width(), Some(scale_factor)),
+    Ok(self.gaps_config().inner_gaps(
+      workspace_gaps.as_ref(),
+      &monitor.to_rect()?,
+      monitor.native().scale_factor()?,
     ))
   }
 
@@ -2,7 +2,7 @@ use std::cell::Ref;
 
 use ambassador::delegatable_trait;
 use wm_common::{
-  ActiveDrag, DisplayState, LengthValue, Rect, RectDelta,
+  ActiveDrag, DisplayState, LengthValue, OpacityValue, Rect, RectDelta,
   WindowRuleConfig, WindowState,
 };
 use wm_platform::NativeWindow;
@@ -118,6 +118,12 @@ pub trait WindowGetters {
   fn active_drag(&self) -> Option<ActiveDrag>;
 
   fn set_active_drag(&self, active_drag: Option<ActiveDrag>);
+
+  /// Opacity set for the window via the `set-transparency` command. Takes
+  /// precedence over the opacity from window effects.
+  fn opacity(&self) -> Option<OpacityValue>;
+
+  fn set_opacity(&self, opacity: Option<OpacityValue>);
 }
 
 /// Implements the `WindowGetters` trait for a given struct.
@@ -215,6 +221,14 @@ macro_rules! impl_window_getters {
       fn set_active_drag(&self, active_drag: Option<ActiveDrag>) {
         self.0.borrow_mut().active_drag = active_drag;
       }
+
+      fn opacity(&self) -> Option<OpacityValue> {
+        self.0.borrow().opacity.clone()
+      }
+
+      fn set_opacity(&self, opacity: Option<OpacityValue>) {
+        self.0.borrow_mut().opacity = opacity;
+      }
     }
   };
 }
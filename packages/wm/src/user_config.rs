@@ -5,6 +5,7 @@ use wm_common::{
   InvokeCommand, MatchType, ParsedConfig, WindowMatchConfig,
   WindowRuleConfig, WindowRuleEvent, WorkspaceConfig,
 };
+use wm_platform::NativeWindow;
 
 use crate::{
   models::{Monitor, WindowContainer, Workspace},
@@ -57,6 +58,21 @@ impl UserConfig {
     })
   }
 
+  /// Creates an instance of `UserConfig` from the sample config without
+  /// touching the filesystem.
+  #[cfg(test)]
+  pub fn sample() -> anyhow::Result<Self> {
+    let config_value: ParsedConfig = serde_yaml::from_str(SAMPLE_CONFIG)?;
+    let window_rules_by_event = Self::window_rules_by_event(&config_value);
+
+    Ok(Self {
+      path: PathBuf::new(),
+      value: config_value,
+      value_str: SAMPLE_CONFIG.to_string(),
+      window_rules_by_event,
+    })
+  }
+
   /// Reads and validates the user config from the given path.
   ///
   /// Creates a new config file from sample if it doesn't exist.
@@ -115,8 +131,12 @@ impl UserConfig {
     // Default float rules.
     window_rules.push(WindowRuleConfig {
       commands: vec![InvokeCommand::SetFloating {
-        centered: Some(floating_defaults.centered),
+        centered: Some(floating_defaults.placement.centered),
         shown_on_top: Some(floating_defaults.shown_on_top),
+        sticky: Some(floating_defaults.sticky),
+        preserve_geometry: Some(
+          floating_defaults.placement.preserve_geometry,
+        ),
         x_pos: None,
         y_pos: None,
         width: None,
@@ -240,24 +260,7 @@ impl UserConfig {
         }
 
         // Check if the window matches the rule.
-        rule.match_window.iter().any(|match_config| {
-          let is_process_match = match_config
-            .window_process
-            .as_ref()
-            .is_none_or(|match_type| match_type.is_match(&window_process));
-
-          let is_class_match = match_config
-            .window_class
-            .as_ref()
-            .is_none_or(|match_type| match_type.is_match(&window_class));
-
-          let is_title_match = match_config
-            .window_title
-            .as_ref()
-            .is_none_or(|match_type| match_type.is_match(&window_title));
-
-          is_process_match && is_class_match && is_title_match
-        })
+        rule.is_match(&window_process, &window_class, &window_title)
       })
       .cloned()
       .collect::<Vec<_>>();
@@ -265,6 +268,28 @@ impl UserConfig {
     Ok(pending_window_rules)
   }
 
+  /// Whether the window matches a window rule that ignores it when it's
+  /// managed.
+  pub fn is_ignored_window(
+    &self,
+    native_window: &NativeWindow,
+  ) -> anyhow::Result<bool> {
+    let Some(manage_rules) =
+      self.window_rules_by_event.get(&WindowRuleEvent::Manage)
+    else {
+      return Ok(false);
+    };
+
+    let window_title = native_window.title()?;
+    let window_class = native_window.class_name()?;
+    let window_process = native_window.process_name()?;
+
+    Ok(manage_rules.iter().any(|rule| {
+      rule.is_ignore_rule()
+        && rule.is_match(&window_process, &window_class, &window_title)
+    }))
+  }
+
   pub fn inactive_workspace_configs(
     &self,
     active_workspaces: &[Workspace],
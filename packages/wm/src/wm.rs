@@ -1,30 +1,44 @@
+use std::time::Duration;
+
 use anyhow::{bail, Context};
 use tokio::sync::mpsc::{self};
 use tracing::warn;
 use uuid::Uuid;
 use wm_common::{
+  CommandPreviewData, ContainerRectData, FloatingPlacementConfig,
   FloatingStateConfig, FullscreenStateConfig, InvokeCommand, LengthValue,
-  RectDelta, TitleBarVisibility, WindowState, WmEvent,
+  RectDelta, Struts, TitleBarVisibility, WindowState, WmEvent,
 };
 use wm_platform::PlatformEvent;
 
 use crate::{
+  command_log::CommandLog,
   commands::{
     container::{
-      focus_container_by_id, focus_in_direction, set_tiling_direction,
-      toggle_tiling_direction,
+      equalize_sizes, focus_child, focus_container_by_id,
+      focus_in_direction, focus_mru, focus_parent, focus_tab,
+      grow_container_to_fill, set_tiling_direction,
+      toggle_parent_direction, toggle_tabbed, toggle_tiling_direction,
     },
     general::{
       cycle_focus, disable_binding_mode, enable_binding_mode,
       platform_sync, reload_config, shell_exec, toggle_pause,
     },
-    monitor::focus_monitor,
+    monitor::{focus_monitor, set_monitor_struts},
     window::{
-      ignore_window, move_window_in_direction, move_window_to_workspace,
-      resize_window, set_window_position, set_window_size,
-      update_window_state, WindowPositionTarget,
+      adjust_floating_rect, bind_window_to_monitor, cycle_snap,
+      fade_window_transparency, ignore_window, move_window_in_direction,
+      move_window_into, move_window_to_monitor, move_window_to_workspace,
+      resize_window, resize_window_edge, set_window_position,
+      set_window_rect, set_window_size, swap_windows, update_window_state,
+      WindowPositionTarget,
+    },
+    workspace::{
+      adjust_master_stack, balance_workspace, focus_workspace,
+      gather_windows, move_workspace_in_direction,
+      move_workspace_to_monitor, restore_workspace_layout,
+      save_workspace_layout, validate_layout,
     },
-    workspace::{focus_workspace, move_workspace_in_direction},
   },
   events::{
     handle_display_settings_changed, handle_mouse_move,
@@ -35,7 +49,7 @@ use crate::{
     handle_window_title_changed,
   },
   models::{Container, WorkspaceTarget},
-  traits::{CommonGetters, WindowGetters},
+  traits::{CommonGetters, PositionGetters, WindowGetters},
   user_config::UserConfig,
   wm_state::WmState,
 };
@@ -44,6 +58,10 @@ pub struct WindowManager {
   pub event_rx: mpsc::UnboundedReceiver<WmEvent>,
   pub exit_rx: mpsc::UnboundedReceiver<()>,
   pub state: WmState,
+
+  /// Log that mutating commands are recorded to, if enabled via
+  /// `--command-log`.
+  pub command_log: Option<CommandLog>,
 }
 
 impl WindowManager {
@@ -58,6 +76,7 @@ impl WindowManager {
       event_rx,
       exit_rx,
       state,
+      command_log: None,
     })
   }
 
@@ -129,15 +148,8 @@ impl WindowManager {
   ) -> anyhow::Result<Uuid> {
     let state = &mut self.state;
 
-    // Get the container to run WM commands with.
-    let subject_container = match subject_container_id {
-      Some(id) => state.container_by_id(id).with_context(|| {
-        format!("No container found with the given ID '{id}'.")
-      })?,
-      None => state
-        .focused_container()
-        .context("No subject container for command.")?,
-    };
+    let subject_container =
+      WindowManager::subject_container(subject_container_id, state)?;
 
     let new_subject_container_id = WindowManager::run_commands(
       commands,
@@ -150,9 +162,82 @@ impl WindowManager {
       platform_sync(state, config)?;
     }
 
+    if let Some(command_log) = &self.command_log {
+      if let Err(err) = command_log.record(commands, state) {
+        warn!("Failed to record commands: {:?}", err);
+      }
+    }
+
     Ok(new_subject_container_id)
   }
 
+  /// Runs WM commands against a copy of the container tree, and gets the
+  /// rects that windows would be moved to. Nothing is applied to the OS,
+  /// and the live container tree is left unchanged.
+  pub fn preview_commands(
+    &self,
+    commands: &Vec<InvokeCommand>,
+    subject_container_id: Option<Uuid>,
+    config: &mut UserConfig,
+  ) -> anyhow::Result<CommandPreviewData> {
+    if let Some(command) =
+      commands.iter().find(|command| !command.is_previewable())
+    {
+      bail!("Command can't be previewed: {:?}", command);
+    }
+
+    let live_rects = WindowManager::window_rects(&self.state)?;
+
+    self.state.preview(|state| {
+      let subject_container =
+        WindowManager::subject_container(subject_container_id, state)?;
+
+      let new_subject_container_id = WindowManager::run_commands(
+        commands,
+        subject_container,
+        state,
+        config,
+      )?;
+
+      Ok(CommandPreviewData::new(
+        new_subject_container_id,
+        &live_rects,
+        WindowManager::window_rects(state)?,
+      ))
+    })
+  }
+
+  /// Gets the container to run WM commands with. Defaults to the focused
+  /// container if no ID is given.
+  fn subject_container(
+    subject_container_id: Option<Uuid>,
+    state: &WmState,
+  ) -> anyhow::Result<Container> {
+    match subject_container_id {
+      Some(id) => state.container_by_id(id).with_context(|| {
+        format!("No container found with the given ID '{id}'.")
+      }),
+      None => state
+        .focused_container()
+        .context("No subject container for command."),
+    }
+  }
+
+  fn window_rects(
+    state: &WmState,
+  ) -> anyhow::Result<Vec<ContainerRectData>> {
+    state
+      .windows()
+      .into_iter()
+      .map(|window| {
+        Ok(ContainerRectData {
+          container_id: window.id(),
+          rect: window.to_rect()?,
+        })
+      })
+      .try_collect()
+  }
+
   pub fn run_commands(
     commands: &Vec<InvokeCommand>,
     subject_container: Container,
@@ -221,6 +306,49 @@ impl WindowManager {
           _ => Ok(()),
         }
       }
+      InvokeCommand::AdjustFloatingRect(args) => {
+        match subject_container.as_window_container() {
+          Ok(window) => {
+            let args = args.clone();
+            let delta = RectDelta::new(
+              args.left.unwrap_or(LengthValue::from_px(0)),
+              args.top.unwrap_or(LengthValue::from_px(0)),
+              args.right.unwrap_or(LengthValue::from_px(0)),
+              args.bottom.unwrap_or(LengthValue::from_px(0)),
+            );
+
+            adjust_floating_rect(window, &delta, state)
+          }
+          _ => Ok(()),
+        }
+      }
+      InvokeCommand::AdjustMasterStack {
+        master_fraction,
+        master_count,
+      } => {
+        let workspace =
+          subject_container.workspace().context("No workspace.")?;
+
+        adjust_master_stack(
+          &workspace,
+          *master_fraction,
+          *master_count,
+          state,
+        );
+
+        Ok(())
+      }
+      InvokeCommand::BalanceWorkspace => {
+        balance_workspace(&subject_container, state)
+      }
+      InvokeCommand::BindToMonitor { monitor } => {
+        match subject_container.as_window_container() {
+          Ok(window) => {
+            bind_window_to_monitor(window, *monitor, state, config)
+          }
+          _ => Ok(()),
+        }
+      }
       InvokeCommand::Close => {
         match subject_container.as_window_container() {
           Ok(window) => {
@@ -234,9 +362,35 @@ impl WindowManager {
           _ => Ok(()),
         }
       }
+      InvokeCommand::CycleSnap { direction } => {
+        match subject_container.as_window_container() {
+          Ok(window) => cycle_snap(window, direction, state, config),
+          _ => Ok(()),
+        }
+      }
+      InvokeCommand::EqualizeSizes { recursive } => {
+        equalize_sizes(&subject_container, *recursive, state)
+      }
+      InvokeCommand::FadeTransparency(args) => {
+        if let Ok(window) = subject_container.as_window_container() {
+          fade_window_transparency(
+            &window,
+            &args.opacity,
+            Duration::from_millis(args.duration),
+            config,
+          );
+        }
+
+        Ok(())
+      }
       InvokeCommand::Focus(args) => {
         if let Some(direction) = &args.direction {
-          focus_in_direction(&subject_container, direction, state)?;
+          focus_in_direction(
+            &subject_container,
+            direction,
+            state,
+            config,
+          )?;
         }
 
         if let Some(direction) = &args.workspace_in_direction {
@@ -259,8 +413,8 @@ impl WindowManager {
           )?;
         }
 
-        if let Some(monitor_index) = &args.monitor {
-          focus_monitor(*monitor_index, state, config)?;
+        if let Some(position) = &args.monitor {
+          focus_monitor(*position, state, config)?;
         }
 
         if args.next_active_workspace {
@@ -299,6 +453,30 @@ impl WindowManager {
           )?;
         }
 
+        if args.next_tab {
+          focus_tab(&subject_container, false, state);
+        }
+
+        if args.prev_tab {
+          focus_tab(&subject_container, true, state);
+        }
+
+        if args.parent {
+          focus_parent(state)?;
+        }
+
+        if args.child {
+          focus_child(state)?;
+        }
+
+        Ok(())
+      }
+      InvokeCommand::FocusMru => focus_mru(&subject_container, state),
+      InvokeCommand::GatherWindows => {
+        gather_windows(&subject_container, state, config)
+      }
+      InvokeCommand::GrowToFill => {
+        grow_container_to_fill(&subject_container, state);
         Ok(())
       }
       InvokeCommand::Ignore => {
@@ -405,26 +583,87 @@ impl WindowManager {
           _ => Ok(()),
         }
       }
+      InvokeCommand::MoveInto {
+        target_id,
+        direction,
+      } => {
+        let target_window = state
+          .container_by_id(*target_id)
+          .and_then(|container| container.as_tiling_window().cloned())
+          .with_context(|| {
+            format!("No tiling window found with ID {target_id}.")
+          })?;
+
+        match subject_container.as_tiling_window() {
+          Some(window) => move_window_into(
+            window,
+            &target_window,
+            direction,
+            state,
+            config,
+          ),
+          _ => Ok(()),
+        }
+      }
+      InvokeCommand::MoveToMonitor { monitor } => {
+        match subject_container.as_window_container() {
+          Ok(window) => {
+            move_window_to_monitor(window, *monitor, state, config)
+          }
+          _ => Ok(()),
+        }
+      }
       InvokeCommand::MoveWorkspace { direction } => {
         let workspace =
           subject_container.workspace().context("No workspace.")?;
 
         move_workspace_in_direction(&workspace, direction, state, config)
       }
+      InvokeCommand::MoveWorkspaceToMonitor { monitor } => {
+        let workspace =
+          subject_container.workspace().context("No workspace.")?;
+
+        let target_monitor = state
+          .monitor_at_position(*monitor)?
+          .with_context(|| format!("No monitor at position {monitor}."))?;
+
+        move_workspace_to_monitor(
+          &workspace,
+          &target_monitor,
+          state,
+          config,
+        )
+      }
       InvokeCommand::Position(args) => {
         match subject_container.as_window_container() {
           Ok(window) => {
-            if args.centered {
+            if let Some(anchor) = args.quadrant {
+              set_window_position(
+                window,
+                &WindowPositionTarget::Quadrant(anchor),
+                state,
+                config,
+              )
+            } else if let Some(corner) = args.corner {
+              set_window_position(
+                window,
+                &WindowPositionTarget::Corner(corner),
+                state,
+                config,
+              )
+            } else if args.centered {
               set_window_position(
                 window,
                 &WindowPositionTarget::Centered,
                 state,
+                config,
               )
             } else {
               set_window_position(
                 window,
                 &WindowPositionTarget::Coordinates(args.x_pos, args.y_pos),
                 state,
+                config,
               )
             }
           }
@@ -442,9 +681,31 @@ impl WindowManager {
           _ => Ok(()),
         }
       }
+      InvokeCommand::ResizeEdge { direction, amount } => {
+        match subject_container.as_window_container() {
+          Ok(window) => {
+            resize_window_edge(&window, direction, amount, state)
+          }
+          _ => Ok(()),
+        }
+      }
+      InvokeCommand::RestoreWorkspaceLayout { path } => {
+        let workspace =
+          subject_container.workspace().context("No workspace.")?;
+
+        restore_workspace_layout(&workspace, path, state, config)
+      }
+      InvokeCommand::SaveWorkspaceLayout { path } => {
+        let workspace =
+          subject_container.workspace().context("No workspace.")?;
+
+        save_workspace_layout(&workspace, path)
+      }
       InvokeCommand::SetFloating {
         centered,
         shown_on_top,
+        sticky,
+        preserve_geometry,
         x_pos,
         y_pos,
         width,
@@ -453,14 +714,22 @@ impl WindowManager {
         Ok(window) => {
           let floating_defaults =
             &config.value.window_behavior.state_defaults.floating;
-          let centered = centered.unwrap_or(floating_defaults.centered);
+          let centered =
+            centered.unwrap_or(floating_defaults.placement.centered);
 
           let window = update_window_state(
             window.clone(),
             WindowState::Floating(FloatingStateConfig {
-              centered,
               shown_on_top: shown_on_top
                 .unwrap_or(floating_defaults.shown_on_top),
+              sticky: sticky.unwrap_or(floating_defaults.sticky),
+              placement: FloatingPlacementConfig {
+                centered,
+                preserve_geometry: preserve_geometry.unwrap_or(
+                  floating_defaults.placement.preserve_geometry,
+                ),
+                ..floating_defaults.placement
+              },
             }),
             state,
             config,
@@ -483,12 +752,14 @@ impl WindowManager {
                 window,
                 &WindowPositionTarget::Centered,
                 state,
+                config,
               )?;
             } else if x_pos.is_some() || y_pos.is_some() {
               set_window_position(
                 window,
                 &WindowPositionTarget::Coordinates(*x_pos, *y_pos),
                 state,
+                config,
               )?;
             }
           }
@@ -536,6 +807,35 @@ impl WindowManager {
           _ => Ok(()),
         }
       }
+      InvokeCommand::SetStruts {
+        monitor,
+        left,
+        top,
+        right,
+        bottom,
+      } => {
+        let monitor = match monitor {
+          Some(position) => {
+            state.monitor_at_position(*position)?.with_context(|| {
+              format!("No monitor at position {position}.")
+            })?
+          }
+          None => subject_container.monitor().context("No monitor.")?,
+        };
+
+        let current_struts = monitor.struts();
+
+        set_monitor_struts(
+          &monitor,
+          Struts {
+            left: left.unwrap_or(current_struts.left),
+            top: top.unwrap_or(current_struts.top),
+            right: right.unwrap_or(current_struts.right),
+            bottom: bottom.unwrap_or(current_struts.bottom),
+          },
+          state,
+        )
+      }
       InvokeCommand::SetTiling => {
         match subject_container.as_window_container() {
           Ok(window) => {
@@ -565,12 +865,26 @@ impl WindowManager {
       InvokeCommand::SetTransparency(args) => {
         match subject_container.as_window_container() {
           Ok(window) => {
-            if let Some(opacity) = &args.opacity {
-              _ = window.native().set_transparency(opacity);
-            }
+            let mut opacity = args
+              .opacity
+              .clone()
+              .or_else(|| window.opacity())
+              .unwrap_or_default();
 
             if let Some(opacity_delta) = &args.opacity_delta {
-              _ = window.native().adjust_transparency(opacity_delta);
+              opacity = opacity.apply_delta(opacity_delta);
+            }
+
+            window.set_opacity(Some(opacity));
+
+            // Apply the opacity right away, dimmed if the window isn't
+            // focused.
+            if let Some(opacity) = config
+              .value
+              .window_effects
+              .opacity(window.has_focus(None), window.opacity().as_ref())
+            {
+              _ = window.native().set_transparency(&opacity);
             }
 
             Ok(())
@@ -578,6 +892,12 @@ impl WindowManager {
           _ => Ok(()),
         }
       }
+      InvokeCommand::SetWindowRect { rect } => {
+        match subject_container.as_window_container() {
+          Ok(window) => set_window_rect(window, rect, state, config),
+          _ => Ok(()),
+        }
+      }
       InvokeCommand::ShellExec {
         hide_window,
         command,
@@ -593,19 +913,41 @@ impl WindowManager {
           _ => Ok(()),
         }
       }
+      InvokeCommand::SwapWindows { target_id } => {
+        let target_window = state
+          .container_by_id(*target_id)
+          .and_then(|container| container.as_tiling_window().cloned())
+          .with_context(|| {
+            format!("No tiling window found with ID {target_id}.")
+          })?;
+
+        match subject_container.as_tiling_window() {
+          Some(window) => swap_windows(window, &target_window, state),
+          _ => Ok(()),
+        }
+      }
       InvokeCommand::ToggleFloating {
         centered,
         shown_on_top,
+        sticky,
+        preserve_geometry,
       } => match subject_container.as_window_container() {
         Ok(window) => {
           let floating_defaults =
             &config.value.window_behavior.state_defaults.floating;
 
-          let centered = centered.unwrap_or(floating_defaults.centered);
+          let centered =
+            centered.unwrap_or(floating_defaults.placement.centered);
           let target_state = WindowState::Floating(FloatingStateConfig {
-            centered,
             shown_on_top: shown_on_top
               .unwrap_or(floating_defaults.shown_on_top),
+            sticky: sticky.unwrap_or(floating_defaults.sticky),
+            placement: FloatingPlacementConfig {
+              centered,
+              preserve_geometry: preserve_geometry
+                .unwrap_or(floating_defaults.placement.preserve_geometry),
+              ..floating_defaults.placement
+            },
           });
 
           let window = update_window_state(
@@ -620,6 +962,7 @@ impl WindowManager {
               window,
               &WindowPositionTarget::Centered,
               state,
+              config,
             )?;
           }
 
@@ -669,6 +1012,9 @@ impl WindowManager {
           _ => Ok(()),
         }
       }
+      InvokeCommand::ToggleTabbed => {
+        toggle_tabbed(&subject_container, state, config)
+      }
       InvokeCommand::ToggleTiling => {
         match subject_container.as_window_container() {
           Ok(window) => {
@@ -687,6 +1033,9 @@ impl WindowManager {
       InvokeCommand::ToggleTilingDirection => {
         toggle_tiling_direction(subject_container, state, config)
       }
+      InvokeCommand::ToggleParentDirection => {
+        toggle_parent_direction(&subject_container, state)
+      }
       InvokeCommand::SetTilingDirection { tiling_direction } => {
         set_tiling_direction(
           subject_container,
@@ -695,6 +1044,12 @@ impl WindowManager {
           tiling_direction,
         )
       }
+      InvokeCommand::ValidateLayout { repair } => {
+        let workspace =
+          subject_container.workspace().context("No workspace.")?;
+
+        validate_layout(&workspace, *repair, state)
+      }
       InvokeCommand::WmCycleFocus {
         omit_floating,
         omit_fullscreen,
@@ -731,3 +1086,151 @@ impl WindowManager {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use std::str::FromStr;
+
+  use clap::Parser;
+  use wm_common::{InvokeResizeCommand, Rect};
+
+  use super::*;
+  use crate::test_utils::{
+    add_test_monitor, add_test_window, displayed_workspace, test_config,
+    test_state, test_state_with_events,
+  };
+
+  fn run_command(
+    command: &str,
+    subject_container: Container,
+    state: &mut WmState,
+    config: &mut UserConfig,
+  ) {
+    let command = InvokeCommand::try_parse_from(
+      std::iter::once("").chain(command.split_whitespace()),
+    )
+    .unwrap();
+
+    WindowManager::run_commands(
+      &vec![command],
+      subject_container,
+      state,
+      config,
+    )
+    .unwrap();
+  }
+
+  fn window_rects(wm: &WindowManager) -> Vec<(Uuid, Rect)> {
+    WindowManager::window_rects(&wm.state)
+      .unwrap()
+      .into_iter()
+      .map(|data| (data.container_id, data.rect))
+      .collect()
+  }
+
+  #[test]
+  fn test_preview_leaves_live_layout_unchanged() {
+    let mut config = test_config();
+    let (mut state, event_rx) = test_state_with_events();
+    let (_, exit_rx) = mpsc::unbounded_channel();
+
+    let monitor = add_test_monitor(
+      Rect::from_xy(0, 0, 1000, 500),
+      &mut state,
+      &config,
+    );
+    let workspace = displayed_workspace(&monitor);
+    let window_1 = add_test_window(&workspace.clone().into(), &config);
+    let window_2 = add_test_window(&workspace.into(), &config);
+
+    let mut wm = WindowManager {
+      event_rx,
+      exit_rx,
+      state,
+      command_log: None,
+    };
+
+    // Drain events from building the tree.
+    while wm.event_rx.try_recv().is_ok() {}
+
+    let live_rects = window_rects(&wm);
+
+    let preview = wm
+      .preview_commands(
+        &vec![InvokeCommand::Resize(InvokeResizeCommand {
+          width: Some(LengthValue::from_str("+25%").unwrap()),
+          height: None,
+        })],
+        Some(window_1.id()),
+        &mut config,
+      )
+      .unwrap();
+
+    let mut changed_rects = preview
+      .changed_rects
+      .into_iter()
+      .map(|data| (data.container_id, data.rect))
+      .collect::<Vec<_>>();
+    changed_rects.sort_by_key(|(_, rect)| rect.x());
+
+    assert_eq!(
+      changed_rects,
+      vec![
+        (window_1.id(), Rect::from_xy(0, 0, 750, 500)),
+        (window_2.id(), Rect::from_xy(750, 0, 250, 500)),
+      ]
+    );
+
+    assert_eq!(window_rects(&wm), live_rects);
+    assert_eq!(window_1.to_rect().unwrap(), Rect::from_xy(0, 0, 500, 500));
+    assert!(wm.state.pending_sync.containers_to_redraw().is_empty());
+    assert!(wm.event_rx.try_recv().is_err());
+  }
+
+  #[test]
+  fn test_monitor_commands_count_monitors_from_the_left() {
+    let mut state = test_state();
+    let mut config = test_config();
+
+    // Monitors are added right-to-left, so their order in the tree is the
+    // reverse of their position.
+    let right = add_test_monitor(
+      Rect::from_xy(1920, 0, 1920, 1080),
+      &mut state,
+      &config,
+    );
+    let left = add_test_monitor(
+      Rect::from_xy(0, 0, 1920, 1080),
+      &mut state,
+      &config,
+    );
+
+    let window =
+      add_test_window(&displayed_workspace(&right).into(), &config);
+
+    run_command(
+      "move-to-monitor --monitor 0",
+      window.clone().into(),
+      &mut state,
+      &mut config,
+    );
+    assert_eq!(window.monitor().unwrap().id(), left.id());
+
+    run_command(
+      "bind-to-monitor --monitor 1",
+      window.clone().into(),
+      &mut state,
+      &mut config,
+    );
+    assert_eq!(window.monitor().unwrap().id(), right.id());
+
+    let workspace = window.workspace().unwrap();
+    run_command(
+      "move-workspace-to-monitor --monitor 0",
+      window.clone().into(),
+      &mut state,
+      &mut config,
+    );
+    assert_eq!(workspace.monitor().unwrap().id(), left.id());
+  }
+}
@@ -1,11 +1,13 @@
-use std::time::Instant;
+use std::{collections::HashMap, time::Instant};
 
 use anyhow::Context;
 use tokio::sync::mpsc::{self};
 use tracing::warn;
 use uuid::Uuid;
 use wm_common::{
-  BindingModeConfig, Direction, Point, WindowState, WmEvent,
+  hit_test, monitors_by_position, BindingModeConfig, Direction,
+  MonitorBindings, MruStack, Point, Rect, SnapCycle, Struts, WindowState,
+  WmEvent,
 };
 use wm_platform::{NativeMonitor, NativeWindow, Platform};
 
@@ -15,11 +17,13 @@ use crate::{
     monitor::add_monitor, window::manage_window,
   },
   models::{
-    Container, Monitor, RootContainer, WindowContainer, Workspace,
-    WorkspaceTarget,
+    Container, DirectionContainer, Monitor, RootContainer, SplitContainer,
+    WindowContainer, Workspace, WorkspaceTarget,
   },
   pending_sync::PendingSync,
-  traits::{CommonGetters, PositionGetters, WindowGetters},
+  traits::{
+    CommonGetters, PositionGetters, TilingDirectionGetters, WindowGetters,
+  },
   user_config::UserConfig,
 };
 
@@ -61,6 +65,41 @@ pub struct WmState {
   /// Whether the OS focused window is the same as the WM focused window.
   pub is_focus_synced: bool,
 
+  /// Rects and states that windows were last positioned with via the OS.
+  ///
+  /// Used to skip redundant position updates, and to emit
+  /// `ContainerRectChanged` events when a window's rect differs from the
+  /// one it was last synced with.
+  pub synced_rects: HashMap<Uuid, (Rect, WindowState)>,
+
+  /// Focused window and overlay rect that were last emitted via a
+  /// `FocusRingChanged` event. Used to only emit the event on changes.
+  pub focus_ring: Option<(Uuid, Rect)>,
+
+  /// Snap slots that floating windows were last moved to via the
+  /// `cycle-snap` command.
+  pub snap_cycles: HashMap<Uuid, SnapCycle>,
+
+  /// Monitors that windows are bound to via the `bind-to-monitor`
+  /// command.
+  pub monitor_bindings: MonitorBindings,
+
+  /// Struts that were last set on each monitor, keyed by the monitor's
+  /// hardware ID. Used to restore the struts when a monitor reconnects.
+  pub monitor_struts: HashMap<String, Struts>,
+
+  /// Focus history of windows within each workspace, keyed by workspace
+  /// name. Used by the `focus-mru` command.
+  pub mru_stacks: HashMap<String, MruStack>,
+
+  /// ID of the split container selected via `focus --parent`. Resize
+  /// commands apply to the selected container instead of the focused
+  /// window.
+  ///
+  /// Use `WmState::selected_container` to get the container, which
+  /// ignores the selection once focus moves outside of it.
+  pub selection: Option<Uuid>,
+
   /// Whether the initial state has been populated.
   has_initialized: bool,
 
@@ -86,6 +125,13 @@ impl WmState {
       ignored_windows: Vec::new(),
       is_paused: false,
       is_focus_synced: false,
+      synced_rects: HashMap::new(),
+      focus_ring: None,
+      snap_cycles: HashMap::new(),
+      monitor_bindings: MonitorBindings::default(),
+      monitor_struts: HashMap::new(),
+      mru_stacks: HashMap::new(),
+      selection: None,
       has_initialized: false,
       event_tx,
       exit_tx,
@@ -150,10 +196,41 @@ impl WmState {
     Ok(())
   }
 
+  /// Marks the initial state as populated, without syncing it to the
+  /// OS.
+  #[cfg(test)]
+  pub fn mark_initialized(&mut self) {
+    self.has_initialized = true;
+  }
+
   pub fn monitors(&self) -> Vec<Monitor> {
     self.root_container.monitors()
   }
 
+  /// Gets the monitor at the given position, where monitors are ordered
+  /// from left-to-right (and top-to-bottom for monitors with the same
+  /// left coordinate).
+  ///
+  /// Unlike indexing into `WmState::monitors`, this doesn't depend on the
+  /// order that monitors were added in.
+  pub fn monitor_at_position(
+    &self,
+    position: usize,
+  ) -> anyhow::Result<Option<Monitor>> {
+    let monitors = self.monitors();
+
+    let rects = monitors
+      .iter()
+      .map(PositionGetters::to_rect)
+      .try_collect::<Vec<_>>()?;
+
+    Ok(
+      monitors_by_position(&rects)
+        .get(position)
+        .map(|&index| monitors[index].clone()),
+    )
+  }
+
   pub fn workspaces(&self) -> Vec<Workspace> {
     self
       .monitors()
@@ -223,19 +300,9 @@ impl WmState {
 
     let closest_monitor = monitors_with_rect
       .into_iter()
-      .filter(|(_, rect)| match direction {
-        Direction::Right => {
-          rect.x() > origin_rect.x() && rect.has_overlap_y(&origin_rect)
-        }
-        Direction::Left => {
-          rect.x() < origin_rect.x() && rect.has_overlap_y(&origin_rect)
-        }
-        Direction::Down => {
-          rect.y() > origin_rect.y() && rect.has_overlap_x(&origin_rect)
-        }
-        Direction::Up => {
-          rect.y() < origin_rect.y() && rect.has_overlap_x(&origin_rect)
-        }
+      // Monitors that are diagonal to the origin monitor are skipped.
+      .filter(|(_, rect)| {
+        origin_rect.relative_position(rect) == [direction.clone()]
       })
       .min_by(|(_, rect_a), (_, rect_b)| match direction {
         Direction::Right => rect_a.x().cmp(&rect_b.x()),
@@ -486,12 +553,148 @@ impl WmState {
     }
   }
 
+  /// Runs the callback against a copy of the state, such that the live
+  /// state is left unchanged. Any pending syncs and events from the
+  /// callback are discarded, such that nothing is applied to the OS.
+  pub fn preview<T>(
+    &self,
+    callback: impl FnOnce(&mut Self) -> anyhow::Result<T>,
+  ) -> anyhow::Result<T> {
+    // Events from the copy are sent to channels that are dropped along
+    // with it.
+    let (event_tx, _event_rx) = mpsc::unbounded_channel();
+    let (exit_tx, _exit_rx) = mpsc::unbounded_channel();
+
+    let mut state_copy = self.deep_clone(event_tx, exit_tx)?;
+    callback(&mut state_copy)
+  }
+
+  /// Copies the state along with its container tree, where the copy
+  /// emits events via the given senders.
+  fn deep_clone(
+    &self,
+    event_tx: mpsc::UnboundedSender<WmEvent>,
+    exit_tx: mpsc::UnboundedSender<()>,
+  ) -> anyhow::Result<Self> {
+    let root_container = self
+      .root_container
+      .deep_clone()
+      .as_root()
+      .cloned()
+      .context("Copy of root container is not a root.")?;
+
+    // Point to the window's copy within the copied tree.
+    let prev_effects_window =
+      self.prev_effects_window.as_ref().and_then(|window| {
+        root_container
+          .self_and_descendants()
+          .find(|container| container.id() == window.id())
+          .and_then(|container| container.try_into().ok())
+      });
+
+    Ok(Self {
+      root_container,
+      pending_sync: PendingSync::default(),
+      recent_workspace_name: self.recent_workspace_name.clone(),
+      prev_effects_window,
+      unmanaged_or_minimized_timestamp: self
+        .unmanaged_or_minimized_timestamp,
+      binding_modes: self.binding_modes.clone(),
+      ignored_windows: self.ignored_windows.clone(),
+      is_paused: self.is_paused,
+      is_focus_synced: self.is_focus_synced,
+      synced_rects: self.synced_rects.clone(),
+      focus_ring: self.focus_ring.clone(),
+      snap_cycles: self.snap_cycles.clone(),
+      monitor_bindings: self.monitor_bindings.clone(),
+      monitor_struts: self.monitor_struts.clone(),
+      mru_stacks: self.mru_stacks.clone(),
+      selection: self.selection,
+      has_initialized: self.has_initialized,
+      event_tx,
+      exit_tx,
+    })
+  }
+
+  /// Gets the split container selected via `focus --parent`.
+  ///
+  /// Returns `None` if nothing is selected, or if the focused container
+  /// is no longer within the selection.
+  pub fn selected_container(&self) -> Option<SplitContainer> {
+    let selection =
+      self.container_by_id(self.selection?)?.as_split().cloned()?;
+
+    self
+      .focused_container()?
+      .ancestors()
+      .any(|ancestor| ancestor.id() == selection.id())
+      .then_some(selection)
+  }
+
+  /// Marks a window as the most recently used within its workspace, for
+  /// use with the `focus-mru` command.
+  pub fn push_mru_window(&mut self, window: &WindowContainer) {
+    if let Some(workspace) = window.workspace() {
+      self
+        .mru_stacks
+        .entry(workspace.config().name)
+        .or_default()
+        .push(window.id());
+    }
+  }
+
   /// Starts graceful shutdown via an MSPC channel.
   pub fn emit_exit(&self) -> anyhow::Result<()> {
     self.exit_tx.send(())?;
     Ok(())
   }
 
+  /// Gets the rect of a container, same as `to_rect`.
+  ///
+  /// The layout rects of its ancestors are cached in `pending_sync`, such
+  /// that each direction container is only laid out once when getting
+  /// the rects of many containers (e.g. while redrawing). The cache is
+  /// invalidated whenever containers are queued for redraw.
+  pub fn cached_rect(
+    &self,
+    container: &Container,
+  ) -> anyhow::Result<Rect> {
+    let Ok(tiling_container) = container.as_tiling_container() else {
+      return container.to_rect();
+    };
+
+    let parent = tiling_container
+      .parent()
+      .and_then(|parent| parent.as_direction_container().ok())
+      .context("Parent does not have a tiling direction.")?;
+
+    let index = parent
+      .tiling_children()
+      .position(|sibling| sibling.id() == container.id())
+      .context("Container is not a tiling child of its parent.")?;
+
+    self
+      .cached_layout_rects(&parent)?
+      .into_iter()
+      .nth(index)
+      .context("No layout rect for container.")
+  }
+
+  /// Gets the layout rects of a direction container's tiling children,
+  /// computing and caching them if they aren't already cached.
+  fn cached_layout_rects(
+    &self,
+    container: &DirectionContainer,
+  ) -> anyhow::Result<Vec<Rect>> {
+    self.pending_sync.layout_cache().get_or_try_compute(
+      container.id(),
+      || {
+        let rect = self.cached_rect(&container.clone().into())?;
+        container.layout_rects_within(&rect)
+      },
+    )
+  }
+
   pub fn container_by_id(&self, id: Uuid) -> Option<Container> {
     self
       .root_container
@@ -557,14 +760,37 @@ impl WmState {
     origin_container
       .descendants()
       .filter(|descendant| {
-        descendant
-          .to_rect()
-          .map(|rect| rect.contains_point(point))
-          .unwrap_or(false)
+        !descendant.is_in_inactive_tab()
+          && descendant
+            .to_rect()
+            .is_ok_and(|rect| rect.contains_point(point))
       })
       .collect()
   }
 
+  /// Returns the deepest container at the given point on a displayed
+  /// workspace, which is usually a window.
+  ///
+  /// Overlapping windows are resolved by their z-order (see
+  /// `z_ordered_children`).
+  pub fn container_at_point(&self, point: &Point) -> Option<Container> {
+    let workspace = self.monitor_at_point(point)?.displayed_workspace()?;
+
+    hit_test(
+      [workspace.into()],
+      point,
+      &|container: &Container| {
+        // Inactive tabs share the rect of the active tab, but are hidden.
+        if container.is_in_inactive_tab() {
+          None
+        } else {
+          container.to_rect().ok()
+        }
+      },
+      &z_ordered_children,
+    )
+  }
+
   /// Returns the monitor that contains the given point.
   pub fn monitor_at_point(&self, point: &Point) -> Option<Monitor> {
     self
@@ -580,6 +806,43 @@ impl WmState {
   }
 }
 
+/// Gets the children of a container in z-order (topmost first).
+///
+/// Non-tiling windows are shown above tiling windows. Of these, windows
+/// that are shown on top come first, followed by the rest in order of
+/// last focus. Minimized windows are excluded.
+fn z_ordered_children(container: &Container) -> Vec<Container> {
+  let focus_order = container.borrow_child_focus_order().clone();
+
+  let mut non_tiling_windows = container
+    .children()
+    .into_iter()
+    .filter_map(|child| child.as_non_tiling_window().cloned())
+    .filter(|window| window.state() != WindowState::Minimized)
+    .collect::<Vec<_>>();
+
+  non_tiling_windows.sort_by_key(|window| {
+    let is_shown_on_top = match window.state() {
+      WindowState::Floating(config) => config.shown_on_top,
+      WindowState::Fullscreen(config) => config.shown_on_top,
+      _ => false,
+    };
+
+    let focus_index = focus_order
+      .iter()
+      .position(|id| *id == window.id())
+      .unwrap_or(usize::MAX);
+
+    (!is_shown_on_top, focus_index)
+  });
+
+  non_tiling_windows
+    .into_iter()
+    .map(Container::from)
+    .chain(container.tiling_children().map(Container::from))
+    .collect()
+}
+
 impl Drop for WmState {
   fn drop(&mut self) {
     let managed_windows = self